@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod attackers_tests {
+    use crate::engine::{
+        board::{
+            bitboard::bitboard::Bitboard,
+            board::{Board, Turn},
+            square::square::Square,
+        },
+        movement::movement::Movement,
+    };
+
+    fn get_square(name: &str) -> Square {
+        return name.parse().unwrap();
+    }
+
+    fn get_squares(names: &[&str]) -> Bitboard {
+        let mut squares = Bitboard::EMPTY;
+        for name in names {
+            squares = squares.with(get_square(name));
+        }
+        return squares;
+    }
+
+    #[test]
+    fn attackers_to_start_position() {
+        let board = Board::new();
+        let ocupancy = board.getOcupancy();
+        assert_eq!(
+            board.attackers_to(get_square("e3"), ocupancy),
+            get_squares(&["d2", "f2"])
+        );
+        assert_eq!(
+            board.attackers_to(get_square("f3"), ocupancy),
+            get_squares(&["e2", "g2", "g1"])
+        );
+        assert_eq!(
+            board.attackers_to(get_square("f6"), ocupancy),
+            get_squares(&["e7", "g7", "g8"])
+        );
+        assert!(board.attackers_to(get_square("e4"), ocupancy).is_empty());
+    }
+
+    #[test]
+    fn attackers_to_sees_through_removed_pieces() {
+        let board = Board::new_from_fenn_notation("4k3/8/8/8/8/8/R7/R3K3 w - - 0 1");
+        let ocupancy = board.getOcupancy();
+        assert_eq!(
+            board.attackers_to(get_square("a8"), ocupancy),
+            get_squares(&["a2"])
+        );
+        assert_eq!(
+            board.attackers_to(get_square("a8"), ocupancy.without(get_square("a2"))),
+            get_squares(&["a1", "a2"])
+        );
+    }
+
+    #[test]
+    fn is_square_attacked_by_color() {
+        let board = Board::new_from_fenn_notation("4k3/8/8/3p4/3P4/8/8/4K3 w - - 0 1");
+        assert!(board.is_square_attacked(get_square("c4"), Turn::Black));
+        assert!(board.is_square_attacked(get_square("e4"), Turn::Black));
+        assert!(!board.is_square_attacked(get_square("c6"), Turn::Black));
+        assert!(board.is_square_attacked(get_square("e5"), Turn::White));
+        assert!(!board.is_square_attacked(get_square("e3"), Turn::White));
+        assert!(board.is_square_attacked(get_square("d7"), Turn::Black));
+        assert!(!board.is_square_attacked(get_square("d7"), Turn::White));
+    }
+
+    #[test]
+    fn movement_attack_helpers_agree() {
+        //Each king only attacks around itself
+        let board = Board::new_from_fenn_notation("8/8/8/8/8/8/k7/7K w - - 0 1");
+        assert!(Movement::check_for_captures_at(
+            &Turn::White,
+            board,
+            get_square("b1").bitboard()
+        ));
+        assert!(!Movement::check_for_captures_at(
+            &Turn::Black,
+            board,
+            get_square("b1").bitboard()
+        ));
+        assert!(Movement::check_for_captures_at(
+            &Turn::Black,
+            board,
+            get_square("g2").bitboard()
+        ));
+        assert!(!Movement::check_for_check(Turn::White, board));
+
+        let board = Board::new();
+        let white_attacks = Movement::get_color_attacks(Turn::White, board);
+        for file in 0..8 {
+            assert!(white_attacks.contains(Square::new(file, 2)));
+            assert!(!white_attacks.contains(Square::new(file, 3)));
+        }
+        assert_eq!(
+            white_attacks,
+            Movement::extract_all_attacks_for_color(board, Turn::White)
+        );
+    }
+}
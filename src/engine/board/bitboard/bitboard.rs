@@ -0,0 +1,166 @@
+use std::{
+    fmt,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not},
+};
+
+use crate::engine::board::square::square::Square;
+
+pub const FILE_A: Bitboard = Bitboard(0x8080808080808080);
+pub const FILE_H: Bitboard = Bitboard(0x101010101010101);
+pub const RANK_1: Bitboard = Bitboard(0xff);
+pub const RANK_8: Bitboard = Bitboard(0xff00000000000000);
+
+//Set of squares, one bit per square index
+#[derive(PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub struct Bitboard(pub u64);
+
+impl Bitboard {
+    pub const EMPTY: Bitboard = Bitboard(0);
+    pub const FULL: Bitboard = Bitboard(!0);
+
+    pub fn count(self) -> u32 {
+        return self.0.count_ones();
+    }
+
+    pub fn is_empty(self) -> bool {
+        return self.0 == 0;
+    }
+
+    pub fn contains(self, square: Square) -> bool {
+        return self.0 & square.bitboard().0 != 0;
+    }
+
+    pub fn with(self, square: Square) -> Bitboard {
+        return self | square.bitboard();
+    }
+
+    pub fn without(self, square: Square) -> Bitboard {
+        return self & !square.bitboard();
+    }
+
+    //Lowest square, the iteration order
+    pub fn first(self) -> Option<Square> {
+        if self.is_empty() {
+            return None;
+        }
+        return Some(Square::from_index(self.0.trailing_zeros() as usize));
+    }
+
+    //Shifts one square, anything crossing the edge of the board is dropped
+    pub fn north(self) -> Bitboard {
+        return Bitboard(self.0 << 8);
+    }
+
+    pub fn south(self) -> Bitboard {
+        return Bitboard(self.0 >> 8);
+    }
+
+    //Towards the h file
+    pub fn east(self) -> Bitboard {
+        return Bitboard(self.0 >> 1) & !FILE_A;
+    }
+
+    //Towards the a file
+    pub fn west(self) -> Bitboard {
+        return Bitboard(self.0 << 1) & !FILE_H;
+    }
+
+    //Rank 1 swaps with rank 8, one byte per rank
+    pub fn flipped(self) -> Bitboard {
+        return Bitboard(self.0.swap_bytes());
+    }
+
+    //The a file swaps with the h file
+    pub fn mirrored(self) -> Bitboard {
+        return Bitboard(self.0.reverse_bits().swap_bytes());
+    }
+}
+
+impl Iterator for Bitboard {
+    type Item = Square;
+
+    fn next(&mut self) -> Option<Square> {
+        let square = self.first()?;
+        self.0 &= self.0 - 1;
+        return Some(square);
+    }
+}
+
+impl From<u64> for Bitboard {
+    fn from(bits: u64) -> Bitboard {
+        return Bitboard(bits);
+    }
+}
+
+impl From<Square> for Bitboard {
+    fn from(square: Square) -> Bitboard {
+        return square.bitboard();
+    }
+}
+
+impl BitAnd for Bitboard {
+    type Output = Bitboard;
+
+    fn bitand(self, other: Bitboard) -> Bitboard {
+        return Bitboard(self.0 & other.0);
+    }
+}
+
+impl BitOr for Bitboard {
+    type Output = Bitboard;
+
+    fn bitor(self, other: Bitboard) -> Bitboard {
+        return Bitboard(self.0 | other.0);
+    }
+}
+
+impl BitXor for Bitboard {
+    type Output = Bitboard;
+
+    fn bitxor(self, other: Bitboard) -> Bitboard {
+        return Bitboard(self.0 ^ other.0);
+    }
+}
+
+impl Not for Bitboard {
+    type Output = Bitboard;
+
+    fn not(self) -> Bitboard {
+        return Bitboard(!self.0);
+    }
+}
+
+impl BitAndAssign for Bitboard {
+    fn bitand_assign(&mut self, other: Bitboard) {
+        self.0 &= other.0;
+    }
+}
+
+impl BitOrAssign for Bitboard {
+    fn bitor_assign(&mut self, other: Bitboard) {
+        self.0 |= other.0;
+    }
+}
+
+impl BitXorAssign for Bitboard {
+    fn bitxor_assign(&mut self, other: Bitboard) {
+        self.0 ^= other.0;
+    }
+}
+
+//Eighth rank first, a file on the left
+impl fmt::Debug for Bitboard {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(fmt, "Bitboard({:#x})", self.0)?;
+        for rank in (0..8).rev() {
+            let row: Vec<&str> = (0..8)
+                .map(|file| match self.contains(Square::new(file, rank)) {
+                    true => "x",
+                    false => ".",
+                })
+                .collect();
+            writeln!(fmt, "{} {}", rank + 1, row.join(" "))?;
+        }
+        write!(fmt, "  a b c d e f g h")
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod bitboard_tests {
+    use crate::engine::board::{
+        bitboard::bitboard::{Bitboard, FILE_A, FILE_H, RANK_1, RANK_8},
+        square::square::Square,
+    };
+
+    fn get_bitboard(squares: &[&str]) -> Bitboard {
+        let mut bitboard = Bitboard::EMPTY;
+        for square in squares {
+            bitboard = bitboard.with(square.parse::<Square>().unwrap());
+        }
+        return bitboard;
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = get_bitboard(&["a1", "e4", "h8"]);
+        let b = get_bitboard(&["e4", "d5"]);
+        assert_eq!(a & b, get_bitboard(&["e4"]));
+        assert_eq!(a | b, get_bitboard(&["a1", "e4", "h8", "d5"]));
+        assert_eq!(a ^ b, get_bitboard(&["a1", "h8", "d5"]));
+        assert_eq!(!Bitboard::FULL, Bitboard::EMPTY);
+        assert_eq!(a.count(), 3);
+        assert!(a.contains("h8".parse().unwrap()));
+        assert!(!a.contains("d5".parse().unwrap()));
+        assert_eq!(
+            a.without("e4".parse().unwrap()),
+            get_bitboard(&["a1", "h8"])
+        );
+        assert!(Bitboard::EMPTY.is_empty());
+        assert_eq!(Bitboard::EMPTY.first(), None);
+    }
+
+    #[test]
+    fn iterates_from_lowest_square() {
+        let squares: Vec<String> = get_bitboard(&["a8", "a1", "h1", "e4"])
+            .map(|square| square.to_string())
+            .collect();
+        assert_eq!(squares, vec!["h1", "a1", "e4", "a8"]);
+        assert_eq!(Bitboard::FULL.count(), 64);
+        assert_eq!(Bitboard::FULL.into_iter().count(), 64);
+    }
+
+    #[test]
+    fn shifts_drop_squares_leaving_the_board() {
+        let edges = get_bitboard(&["a1", "h1", "a8", "h8", "e4"]);
+        assert_eq!(edges.north(), get_bitboard(&["a2", "h2", "e5"]));
+        assert_eq!(edges.south(), get_bitboard(&["a7", "h7", "e3"]));
+        assert_eq!(edges.east(), get_bitboard(&["b1", "b8", "f4"]));
+        assert_eq!(edges.west(), get_bitboard(&["g1", "g8", "d4"]));
+
+        assert_eq!(FILE_H.east(), Bitboard::EMPTY);
+        assert_eq!(FILE_A.west(), Bitboard::EMPTY);
+        assert_eq!(RANK_8.north(), Bitboard::EMPTY);
+        assert_eq!(RANK_1.south(), Bitboard::EMPTY);
+    }
+
+    #[test]
+    fn flip_and_mirror() {
+        let squares = get_bitboard(&["a1", "b2", "e4", "h8"]);
+        assert_eq!(squares.flipped(), get_bitboard(&["a8", "b7", "e5", "h1"]));
+        assert_eq!(squares.mirrored(), get_bitboard(&["h1", "g2", "d4", "a8"]));
+        assert_eq!(squares.flipped().flipped(), squares);
+        assert_eq!(squares.mirrored().mirrored(), squares);
+        assert_eq!(RANK_1.flipped(), RANK_8);
+        assert_eq!(FILE_A.mirrored(), FILE_H);
+    }
+
+    #[test]
+    fn debug_prints_the_board() {
+        let bitboard = get_bitboard(&["a1", "h8"]);
+        let expected = "Bitboard(0x100000000000080)\n\
+                        8 . . . . . . . x\n\
+                        7 . . . . . . . .\n\
+                        6 . . . . . . . .\n\
+                        5 . . . . . . . .\n\
+                        4 . . . . . . . .\n\
+                        3 . . . . . . . .\n\
+                        2 . . . . . . . .\n\
+                        1 x . . . . . . .\n  a b c d e f g h";
+        assert_eq!(format!("{:?}", bitboard), expected);
+    }
+}
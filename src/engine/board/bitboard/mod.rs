@@ -0,0 +1,2 @@
+pub mod bitboard;
+mod bitboard_test;
@@ -1,22 +1,39 @@
-use crate::engine::{movement::movement::Movement, printer::printer::Printer};
 use crate::engine::{
     movement::{
-        bishop::bishop::Bishop, king::king::King, knight::knight::Knight, movement::Movement,
-        pawn::pawn::Pawn, queen::queen::Queen, rook::rook::Rook,
+        attacks::attacks::Attacks, bishop::bishop::Bishop, king::king::King,
+        knight::knight::Knight, magic::magic::Magic, movement::Movement, pawn::pawn::Pawn,
+        queen::queen::Queen, rook::rook::Rook,
     },
     printer::printer::Printer,
 };
 
 use super::{
-    fenn::fenn::FEN,
-    position::position::{CastleOptions, LegalMove, Position},
+    bitboard::bitboard::{Bitboard, RANK_1, RANK_8},
+    chess960::chess960::Chess960,
+    fenn::fenn::{FenError, FEN},
+    moves::moves::{Move, MoveFlag},
+    position::position::{CastleOptions, LegalMove, PromotionOptions},
+    san::san::{SanError, SAN},
+    see::see::SEE,
+    square::square::Square,
+    validation::validation::{PositionIssue, Validation},
+    zobrist::zobrist::Zobrist,
 };
 
-#[derive(PartialEq, Eq, Copy, Clone)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
 pub enum Turn {
     White,
     Black,
 }
+
+impl std::fmt::Display for Turn {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Turn::White => write!(fmt, "white"),
+            Turn::Black => write!(fmt, "black"),
+        }
+    }
+}
 impl std::fmt::Display for LegalMove {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         write!(
@@ -27,7 +44,17 @@ impl std::fmt::Display for LegalMove {
     }
 }
 
-#[derive(Clone, Copy)]
+//h1 is a light square
+const LIGHT_SQUARES: Bitboard = Bitboard(0xaa55aa55aa55aa55);
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum BoardStatus {
+    Ongoing,
+    Checkmate,
+    Stalemate,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum PieceType {
     WhiteKing,
     WhiteQueen,
@@ -55,7 +82,7 @@ impl PieceType {
     fn delete(&mut self) {}
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Clone, Copy)]
 pub struct Board {
     /*     pub w_rooks_array_pos: Vec<i8>,
     pub w_knights_array_pos: Vec<i8>,
@@ -70,19 +97,19 @@ pub struct Board {
     pub b_queen_array_pos: Vec<i8>,
     pub b_king_array_pos: Vec<i8>,
     pub b_pawns_array_pos: Vec<i8>, */
-    pub w_rooks: u64,
-    pub w_knights: u64,
-    pub w_bishops: u64,
-    pub w_queen: u64,
-    pub w_king: u64,
-    pub w_pawns: u64,
-
-    pub b_rooks: u64,
-    pub b_knights: u64,
-    pub b_bishops: u64,
-    pub b_queen: u64,
-    pub b_king: u64,
-    pub b_pawns: u64,
+    pub w_rooks: Bitboard,
+    pub w_knights: Bitboard,
+    pub w_bishops: Bitboard,
+    pub w_queen: Bitboard,
+    pub w_king: Bitboard,
+    pub w_pawns: Bitboard,
+
+    pub b_rooks: Bitboard,
+    pub b_knights: Bitboard,
+    pub b_bishops: Bitboard,
+    pub b_queen: Bitboard,
+    pub b_king: Bitboard,
+    pub b_pawns: Bitboard,
 
     pub has_w_king_side_castle: bool,
     pub has_w_queen_side_castle: bool,
@@ -92,21 +119,88 @@ pub struct Board {
     pub has_b_queen_side_castle: bool,
     pub b_king_has_moved: bool,
 
-    pub w_en_passant: u64,
-    pub b_en_passant: u64,
+    //Files of the rooks the castling rights belong to, 0 is the a file
+    pub w_king_side_rook_file: u8,
+    pub w_queen_side_rook_file: u8,
+    pub b_king_side_rook_file: u8,
+    pub b_queen_side_rook_file: u8,
+
+    //Castling moves are written king takes rook in UCI
+    pub chess960: bool,
+
+    pub w_en_passant: Bitboard,
+    pub b_en_passant: Bitboard,
+
+    pub full_move_count: u16,
+    pub half_move_count: u16,
+
+    pub turn: Turn,
+
+    pub zobrist_key: u64,
+
+    //Piece on every square, kept in sync with the bitboards
+    mailbox: [Option<PieceType>; 64],
+}
+
+//Everything make_move changes that can not be read back from the board afterwards
+#[derive(Clone, Copy)]
+pub struct UndoMove {
+    pub legal: Move,
+    pub playing_as: Turn,
+
+    pub moved: Option<PieceType>,
+    pub captured: Option<PieceType>,
+    pub captured_square: Square,
 
-    pub full_move_count: i8,
-    pub half_move_count: i8,
+    pub has_w_king_side_castle: bool,
+    pub has_w_queen_side_castle: bool,
+    pub w_king_has_moved: bool,
 
-    pub black_attacks: u64,
-    pub white_attacks: u64,
+    pub has_b_king_side_castle: bool,
+    pub has_b_queen_side_castle: bool,
+    pub b_king_has_moved: bool,
+
+    pub w_en_passant: Bitboard,
+    pub b_en_passant: Bitboard,
+
+    pub full_move_count: u16,
+    pub half_move_count: u16,
 
     pub turn: Turn,
+
+    pub zobrist_key: u64,
+}
+
+//Which part of the legal moves a generator call produces
+#[derive(Clone, Copy, PartialEq)]
+enum MoveStage {
+    All,
+    Captures,
+    Quiets,
+    Evasions,
+}
+
+//Checkers, pinned pieces and the squares that answer a check, computed once per position
+#[derive(Clone, Copy)]
+struct MoveMasks {
+    checkers: Bitboard,
+    evasion_mask: Bitboard,
+    pinned: Bitboard,
+    pin_rays: [Bitboard; 64],
+}
+
+impl MoveMasks {
+    fn get_mask(&self, square: Square) -> Bitboard {
+        if self.pinned.contains(square) {
+            return self.evasion_mask & self.pin_rays[square.index()];
+        }
+        return self.evasion_mask;
+    }
 }
 
 impl Board {
     pub fn new() -> Board {
-        return FEN::get_board_from_fenn_str(
+        return Board::new_from_fenn_notation(
             "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
         );
     }
@@ -126,22 +220,22 @@ impl Board {
             b_queen_array_pos: Vec::new(),
             b_king_array_pos: Vec::new(),
             b_pawns_array_pos: Vec::new(), */
-            w_rooks: 0,
-            w_knights: 0,
-            w_bishops: 0,
-            w_queen: 0,
-            w_king: 0,
-            w_pawns: 0,
-
-            b_rooks: 0,
-            b_knights: 0,
-            b_bishops: 0,
-            b_queen: 0,
-            b_king: 0,
-            b_pawns: 0,
-
-            w_en_passant: 0,
-            b_en_passant: 0,
+            w_rooks: Bitboard::EMPTY,
+            w_knights: Bitboard::EMPTY,
+            w_bishops: Bitboard::EMPTY,
+            w_queen: Bitboard::EMPTY,
+            w_king: Bitboard::EMPTY,
+            w_pawns: Bitboard::EMPTY,
+
+            b_rooks: Bitboard::EMPTY,
+            b_knights: Bitboard::EMPTY,
+            b_bishops: Bitboard::EMPTY,
+            b_queen: Bitboard::EMPTY,
+            b_king: Bitboard::EMPTY,
+            b_pawns: Bitboard::EMPTY,
+
+            w_en_passant: Bitboard::EMPTY,
+            b_en_passant: Bitboard::EMPTY,
 
             has_w_king_side_castle: false,
             has_w_queen_side_castle: false,
@@ -151,37 +245,158 @@ impl Board {
             has_b_queen_side_castle: false,
             b_king_has_moved: false,
 
-            full_move_count: 0,
-            half_move_count: 0,
+            w_king_side_rook_file: 7,
+            w_queen_side_rook_file: 0,
+            b_king_side_rook_file: 7,
+            b_queen_side_rook_file: 0,
 
-            black_attacks: 0,
-            white_attacks: 0,
+            chess960: false,
+
+            full_move_count: 1,
+            half_move_count: 0,
 
             turn: Turn::White,
+
+            zobrist_key: 0,
+
+            mailbox: [None; 64],
         };
     }
 
-    pub fn new_from_fenn_notation(board_fenn: &'static str) -> Board {
+    //For positions known to be valid, user supplied positions should go through from_fen
+    pub fn new_from_fenn_notation(board_fenn: &str) -> Board {
+        match FEN::get_board_from_fenn_str(board_fenn) {
+            Ok(board) => return board,
+            Err(error) => panic!("Invalid FEN '{}': {}", board_fenn, error),
+        }
+    }
+
+    //Chess960 start position picked from the seed, the same seed gives the same position
+    pub fn new_960(seed: u64) -> Board {
+        let number = Chess960::get_number_from_seed(seed);
+        let mut board = match Chess960::get_start_fen(number) {
+            Some(fen) => Board::new_from_fenn_notation(&fen),
+            None => panic!("Chess960 position {} out of range", number),
+        };
+        board.chess960 = true;
+        return board;
+    }
+
+    pub fn from_fen(board_fenn: &str) -> Result<Board, FenError> {
         return FEN::get_board_from_fenn_str(board_fenn);
     }
 
+    //Every reason the position could not come from a legal game of chess
+    pub fn validate(&self) -> Result<(), Vec<PositionIssue>> {
+        return Validation::validate(self);
+    }
+
+    //Colours swapped and ranks mirrored, the same position seen from the other side
+    pub fn flipped(&self) -> Board {
+        let mut board = *self;
+        board.w_pawns = self.b_pawns.flipped();
+        board.w_knights = self.b_knights.flipped();
+        board.w_bishops = self.b_bishops.flipped();
+        board.w_rooks = self.b_rooks.flipped();
+        board.w_queen = self.b_queen.flipped();
+        board.w_king = self.b_king.flipped();
+        board.b_pawns = self.w_pawns.flipped();
+        board.b_knights = self.w_knights.flipped();
+        board.b_bishops = self.w_bishops.flipped();
+        board.b_rooks = self.w_rooks.flipped();
+        board.b_queen = self.w_queen.flipped();
+        board.b_king = self.w_king.flipped();
+
+        board.has_w_king_side_castle = self.has_b_king_side_castle;
+        board.has_w_queen_side_castle = self.has_b_queen_side_castle;
+        board.w_king_has_moved = self.b_king_has_moved;
+        board.w_king_side_rook_file = self.b_king_side_rook_file;
+        board.w_queen_side_rook_file = self.b_queen_side_rook_file;
+        board.has_b_king_side_castle = self.has_w_king_side_castle;
+        board.has_b_queen_side_castle = self.has_w_queen_side_castle;
+        board.b_king_has_moved = self.w_king_has_moved;
+        board.b_king_side_rook_file = self.w_king_side_rook_file;
+        board.b_queen_side_rook_file = self.w_queen_side_rook_file;
+
+        board.w_en_passant = self.b_en_passant.flipped();
+        board.b_en_passant = self.w_en_passant.flipped();
+        board.turn = Movement::get_oposite_color(self.turn);
+
+        board.sync_mailbox();
+        board.zobrist_key = Zobrist::get_key(&board);
+        return board;
+    }
+
+    //Files mirrored, each castling right follows its rook to the other side of the king
+    pub fn mirrored(&self) -> Board {
+        let mut board = *self;
+        board.w_pawns = self.w_pawns.mirrored();
+        board.w_knights = self.w_knights.mirrored();
+        board.w_bishops = self.w_bishops.mirrored();
+        board.w_rooks = self.w_rooks.mirrored();
+        board.w_queen = self.w_queen.mirrored();
+        board.w_king = self.w_king.mirrored();
+        board.b_pawns = self.b_pawns.mirrored();
+        board.b_knights = self.b_knights.mirrored();
+        board.b_bishops = self.b_bishops.mirrored();
+        board.b_rooks = self.b_rooks.mirrored();
+        board.b_queen = self.b_queen.mirrored();
+        board.b_king = self.b_king.mirrored();
+
+        board.has_w_king_side_castle = self.has_w_queen_side_castle;
+        board.has_w_queen_side_castle = self.has_w_king_side_castle;
+        board.w_king_side_rook_file = 7 - self.w_queen_side_rook_file;
+        board.w_queen_side_rook_file = 7 - self.w_king_side_rook_file;
+        board.has_b_king_side_castle = self.has_b_queen_side_castle;
+        board.has_b_queen_side_castle = self.has_b_king_side_castle;
+        board.b_king_side_rook_file = 7 - self.b_queen_side_rook_file;
+        board.b_queen_side_rook_file = 7 - self.b_king_side_rook_file;
+
+        //The king ends up on the d file, so the rights can only be played as Chess960 castles.
+        //A Chess960 game with standard looking castling stays one
+        board.chess960 =
+            board.has_chess960_castling() || (self.chess960 && !self.has_chess960_castling());
+
+        board.w_en_passant = self.w_en_passant.mirrored();
+        board.b_en_passant = self.b_en_passant.mirrored();
+
+        board.sync_mailbox();
+        board.zobrist_key = Zobrist::get_key(&board);
+        return board;
+    }
+
+    pub fn to_fen(&self) -> String {
+        return FEN::get_fenn_str_from_board(self);
+    }
+
+    pub fn to_shredder_fen(&self) -> String {
+        return FEN::get_shredder_fenn_str_from_board(self);
+    }
+
     pub fn get_turn(&self) -> Turn {
         return self.turn;
     }
 
     pub fn set_turn(&mut self, turn: Turn) {
+        if self.turn != turn {
+            self.zobrist_key ^= Zobrist::get_side_key();
+        }
         self.turn = turn;
     }
 
-    pub fn getOcupancy(&self) -> u64 {
+    pub fn get_zobrist_key(&self) -> u64 {
+        return self.zobrist_key;
+    }
+
+    pub fn getOcupancy(&self) -> Bitboard {
         return self.getWhiteBitboard() | self.getBlackBitboard();
     }
 
-    pub fn getNotOcupancy(&self) -> u64 {
+    pub fn getNotOcupancy(&self) -> Bitboard {
         return !self.getOcupancy();
     }
 
-    pub fn getWhiteBitboard(&self) -> u64 {
+    pub fn getWhiteBitboard(&self) -> Bitboard {
         return self.w_rooks
             | self.w_knights
             | self.w_bishops
@@ -190,7 +405,7 @@ impl Board {
             | self.w_pawns;
     }
 
-    pub fn getBlackBitboard(&self) -> u64 {
+    pub fn getBlackBitboard(&self) -> Bitboard {
         return self.b_rooks
             | self.b_knights
             | self.b_bishops
@@ -199,690 +414,987 @@ impl Board {
             | self.b_pawns;
     }
 
-    fn try_take(&mut self, destin_board: u64) -> bool {
-        let board_ocupancy = self.getOcupancy();
-        if self.getWhiteBitboard() & destin_board > 0 {
-            if self.w_rooks & destin_board > 0 {
-                if destin_board < 0x10 {
-                    self.has_w_king_side_castle = true;
-                } else {
-                    self.has_w_queen_side_castle = true;
-                }
-                self.w_rooks = self.w_rooks & !destin_board;
-            }
-            if self.w_bishops & destin_board > 0 {
-                self.w_bishops = self.w_bishops & !destin_board;
-            }
-            if self.w_knights & destin_board > 0 {
-                self.w_knights = self.w_knights & !destin_board;
-            }
-            if self.w_pawns & destin_board > 0 {
-                self.w_pawns = self.w_pawns & !destin_board;
-            }
-            if self.w_queen & destin_board > 0 {
-                self.w_queen = self.w_queen & !destin_board;
-            }
+    fn try_take(&mut self, square: Square) -> bool {
+        let captured = match self.piece_at(square) {
+            //Kings are never taken
+            None | Some(PieceType::WhiteKing) | Some(PieceType::BlackKing) => return false,
+            Some(captured) => captured,
+        };
+        self.zobrist_key ^= Zobrist::get_piece_key(captured, square.bitboard());
+        match captured {
+            PieceType::WhiteRook => self.remove_rook_castle_right(square, Turn::White),
+            PieceType::BlackRook => self.remove_rook_castle_right(square, Turn::Black),
+            _ => {}
         }
+        self.remove_piece(square);
+        return true;
+    }
 
-        if self.getBlackBitboard() & destin_board > 0 {
-            if self.b_rooks & destin_board > 0 {
-                if destin_board < 0x10 {
-                    self.has_b_king_side_castle = true;
-                } else {
-                    self.has_b_queen_side_castle = true;
-                }
-                self.b_rooks = self.b_rooks & !destin_board;
-            }
-            if self.b_bishops & destin_board > 0 {
-                self.b_bishops = self.b_bishops & !destin_board;
+    //The right is lost once the rook it belongs to leaves its square
+    fn remove_rook_castle_right(&mut self, square: Square, color: Turn) {
+        if self.get_castle_rook_square(color, CastleOptions::KingSide) == Some(square) {
+            match color {
+                Turn::White => self.has_w_king_side_castle = true,
+                Turn::Black => self.has_b_king_side_castle = true,
             }
-            if self.b_knights & destin_board > 0 {
-                self.b_knights = self.b_knights & !destin_board;
+        }
+        if self.get_castle_rook_square(color, CastleOptions::QueenSide) == Some(square) {
+            match color {
+                Turn::White => self.has_w_queen_side_castle = true,
+                Turn::Black => self.has_b_queen_side_castle = true,
             }
-            if self.b_pawns & destin_board > 0 {
-                self.b_pawns = self.b_pawns & !destin_board;
+        }
+    }
+
+    fn try_castle(&mut self, castle: CastleOptions, playing_as: Turn) {
+        if !self.can_castle(playing_as, castle) {
+            return;
+        }
+        let (king_square, rook_square) = match (
+            self.get_king_square(playing_as),
+            self.get_castle_rook_square(playing_as, castle),
+        ) {
+            (Some(king_square), Some(rook_square)) => (king_square, rook_square),
+            _ => return,
+        };
+        let (king_to, rook_to) = Board::get_castle_targets(playing_as, castle);
+        let (king, rook) = match playing_as {
+            Turn::White => (PieceType::WhiteKing, PieceType::WhiteRook),
+            Turn::Black => (PieceType::BlackKing, PieceType::BlackRook),
+        };
+
+        //Both pieces are lifted first, in Chess960 either may land on the other's square
+        self.remove_piece(king_square);
+        self.remove_piece(rook_square);
+        self.put_piece(king, king_to);
+        self.put_piece(rook, rook_to);
+        self.zobrist_key ^= Zobrist::get_piece_key(king, king_square.bitboard())
+            ^ Zobrist::get_piece_key(king, king_to.bitboard())
+            ^ Zobrist::get_piece_key(rook, rook_square.bitboard())
+            ^ Zobrist::get_piece_key(rook, rook_to.bitboard());
+
+        match playing_as {
+            Turn::White => {
+                self.has_w_king_side_castle = true;
+                self.has_w_queen_side_castle = true;
+                self.w_king_has_moved = true;
             }
-            if self.b_queen & destin_board > 0 {
-                self.b_queen = self.b_queen & !destin_board;
+            Turn::Black => {
+                self.has_b_king_side_castle = true;
+                self.has_b_queen_side_castle = true;
+                self.b_king_has_moved = true;
             }
         }
+    }
 
-        if board_ocupancy != self.getOcupancy() {
-            self.half_move_count = 0;
+    //King and rook squares after castling, the same as in standard chess
+    pub fn get_castle_targets(color: Turn, castle: CastleOptions) -> (Square, Square) {
+        let rank = match color {
+            Turn::White => 0,
+            Turn::Black => 7,
+        };
+        match castle {
+            CastleOptions::QueenSide => return (Square::new(2, rank), Square::new(3, rank)),
+            _ => return (Square::new(6, rank), Square::new(5, rank)),
         }
+    }
 
-        return board_ocupancy != self.getOcupancy();
+    //Square of the rook a castling right belongs to, None once the right is lost
+    pub fn get_castle_rook_square(&self, color: Turn, castle: CastleOptions) -> Option<Square> {
+        let (lost, file, rank) = match (color, castle) {
+            (_, CastleOptions::None) => return None,
+            (Turn::White, CastleOptions::KingSide) => (
+                self.has_w_king_side_castle || self.w_king_has_moved,
+                self.w_king_side_rook_file,
+                0,
+            ),
+            (Turn::White, CastleOptions::QueenSide) => (
+                self.has_w_queen_side_castle || self.w_king_has_moved,
+                self.w_queen_side_rook_file,
+                0,
+            ),
+            (Turn::Black, CastleOptions::KingSide) => (
+                self.has_b_king_side_castle || self.b_king_has_moved,
+                self.b_king_side_rook_file,
+                7,
+            ),
+            (Turn::Black, CastleOptions::QueenSide) => (
+                self.has_b_queen_side_castle || self.b_king_has_moved,
+                self.b_queen_side_rook_file,
+                7,
+            ),
+        };
+        if lost {
+            return None;
+        }
+        return Some(Square::new(file, rank));
     }
 
-    fn try_castle(&mut self, movve: LegalMove, playing_as: Turn) {
-        match playing_as {
-            Turn::White => match movve.castle {
-                CastleOptions::KingSide => {
-                    if self.can_castle_king_side(playing_as) {
-                        self.w_king = 0x2;
-                        self.w_rooks = self.w_rooks & !0x1 | 0x4;
-                        self.has_w_king_side_castle = true;
-                        self.w_king_has_moved = true;
-                    }
-                }
-                CastleOptions::QueenSide => {
-                    if self.can_castle_queen_side(playing_as) {
-                        self.w_king = 0x20;
-                        self.w_rooks = self.w_rooks & !0x80 | 0x10;
-                        self.has_w_queen_side_castle = true;
-                        self.w_king_has_moved = true;
-                    }
-                }
-                CastleOptions::None => {}
-            },
-            Turn::Black => match movve.castle {
-                CastleOptions::KingSide => {
-                    if self.can_castle_king_side(playing_as) {
-                        self.b_king = 0x200000000000000;
-                        self.b_rooks = self.b_rooks & !0x100000000000000 | 0x400000000000000;
-                        self.has_b_king_side_castle = true;
-                        self.b_king_has_moved = true;
+    //Castling from other squares than e1, a1 and h1 only exists in Chess960
+    pub fn has_chess960_castling(&self) -> bool {
+        for color in [Turn::White, Turn::Black] {
+            let king_file = self
+                .get_king_square(color)
+                .map(|king_square| king_square.file());
+            for (castle, standard_file) in
+                [(CastleOptions::KingSide, 7), (CastleOptions::QueenSide, 0)]
+            {
+                if let Some(rook_square) = self.get_castle_rook_square(color, castle) {
+                    if rook_square.file() != standard_file || king_file != Some(4) {
+                        return true;
                     }
                 }
-                CastleOptions::QueenSide => {
-                    if self.can_castle_queen_side(playing_as) {
-                        self.b_king = 0x2000000000000000;
-                        self.b_rooks = self.b_rooks & !0x8000000000000000 | 0x1000000000000000;
-                        self.has_b_queen_side_castle = true;
-                        self.b_king_has_moved = true;
-                    }
-                }
-                CastleOptions::None => {}
-            },
+            }
         }
+        return false;
     }
 
-    fn get_piece_bitboard_from_position(movve: Position) -> u64 {
-        let mut piece_bitboard: u64 = (1 as u64) << movve.colum;
-        if movve.row != 0 {
-            piece_bitboard = piece_bitboard << movve.row * 8
+    //A move without a chosen piece promotes to a queen
+    fn pawn_do_promote(&mut self, to: Square, color: Turn, promotion: PromotionOptions) {
+        if RANK_8.contains(to) && matches!(color, Turn::White)
+            || RANK_1.contains(to) && matches!(color, Turn::Black)
+        {
+            let (pawn, promoted) = match color {
+                Turn::White => (
+                    PieceType::WhitePawn,
+                    match promotion {
+                        PromotionOptions::Rook => PieceType::WhiteRook,
+                        PromotionOptions::Bishop => PieceType::WhiteBishop,
+                        PromotionOptions::Knight => PieceType::WhiteKnight,
+                        PromotionOptions::Queen | PromotionOptions::None => PieceType::WhiteQueen,
+                    },
+                ),
+                Turn::Black => (
+                    PieceType::BlackPawn,
+                    match promotion {
+                        PromotionOptions::Rook => PieceType::BlackRook,
+                        PromotionOptions::Bishop => PieceType::BlackBishop,
+                        PromotionOptions::Knight => PieceType::BlackKnight,
+                        PromotionOptions::Queen | PromotionOptions::None => PieceType::BlackQueen,
+                    },
+                ),
+            };
+            self.put_piece(promoted, to);
+            self.zobrist_key ^= Zobrist::get_piece_key(pawn, to.bitboard())
+                ^ Zobrist::get_piece_key(promoted, to.bitboard());
         }
+    }
 
-        return piece_bitboard;
+    fn do_try_move(&mut self, from: Square, to: Square, piece_type: PieceType) {
+        self.zobrist_key ^= Zobrist::get_piece_key(piece_type, from.bitboard() | to.bitboard());
+        match piece_type {
+            PieceType::WhiteKing => self.w_king_has_moved = true,
+            PieceType::BlackKing => self.b_king_has_moved = true,
+            PieceType::WhiteRook => self.remove_rook_castle_right(from, Turn::White),
+            PieceType::BlackRook => self.remove_rook_castle_right(from, Turn::Black),
+            _ => {}
+        }
+        //The pawn taken en passant stands next to the one taking it
+        match piece_type {
+            PieceType::WhitePawnEnPassant | PieceType::BlackPawnEnPassant => {
+                self.try_take(Square::new(to.file(), from.rank()))
+            }
+            _ => self.try_take(to),
+        };
+        self.move_piece(from, to);
     }
 
-    fn pawn_do_promote(&mut self, destin_board: u64, color: Turn) {
-        if destin_board > 0x80000000000000 && matches!(color, Turn::White)
-            || destin_board < 0x80000000000100 && matches!(color, Turn::Black)
-        {
-            match color {
-                Turn::White => {
-                    self.w_pawns = self.w_pawns & !destin_board;
-                    self.w_queen |= destin_board
-                }
+    pub fn do_move(&mut self, movve: Move, playing_as: Turn) {
+        let pawns = self.w_pawns | self.b_pawns;
+        let pieces_count = self.getOcupancy().count();
 
-                Turn::Black => {
-                    self.b_pawns = self.b_pawns & !destin_board;
-                    self.b_queen |= destin_board
-                }
-            }
+        self.zobrist_key ^= Zobrist::get_castling_key(self) ^ Zobrist::get_en_passant_key(self);
+        self.do_move_pieces(movve, playing_as);
+        self.zobrist_key ^= Zobrist::get_castling_key(self) ^ Zobrist::get_en_passant_key(self);
+
+        //Pawn moves and captures reset the fifty-move clock
+        if pawns != self.w_pawns | self.b_pawns || pieces_count != self.getOcupancy().count() {
+            self.half_move_count = 0;
+        } else {
+            self.half_move_count = self.half_move_count.saturating_add(1);
+        }
+        if playing_as == Turn::Black {
+            self.full_move_count = self.full_move_count.saturating_add(1);
         }
+
+        debug_assert_eq!(
+            self.zobrist_key,
+            Zobrist::get_key(self),
+            "incremental zobrist key diverged after {}",
+            movve
+        );
+        debug_assert!(
+            self.is_mailbox_in_sync(),
+            "mailbox diverged after {}",
+            movve
+        );
     }
 
-    fn do_try_move(&mut self, piece_board: u64, destin_board: u64, piece_type: PieceType) {
-        match piece_type {
-            PieceType::WhiteQueen => {
-                self.try_take(destin_board);
-                self.w_queen = self.w_queen & !piece_board;
-                self.w_queen = self.w_queen | destin_board;
-            }
-            PieceType::WhiteKing => {
-                self.try_take(destin_board);
-                self.w_king = self.w_king & !piece_board;
-                self.w_king = self.w_king | destin_board;
+    fn do_move_pieces(&mut self, movve: Move, playing_as: Turn) {
+        self.w_en_passant = Bitboard::EMPTY;
+        self.b_en_passant = Bitboard::EMPTY;
 
-                self.w_king_has_moved = true;
-            }
-            PieceType::WhiteBishop => {
-                self.try_take(destin_board);
-                self.w_bishops = self.w_bishops & !piece_board;
-                self.w_bishops = self.w_bishops | destin_board;
-            }
-            PieceType::WhiteRook => {
-                if piece_board == 0x1 {
-                    self.has_w_king_side_castle = true;
-                }
-                if piece_board == 0x80 {
-                    self.has_w_queen_side_castle = true;
-                }
-                self.try_take(destin_board);
+        if movve.is_castle() {
+            self.try_castle(movve.get_castle(), playing_as);
+            return;
+        }
 
-                self.w_rooks = self.w_rooks & !piece_board;
-                self.w_rooks = self.w_rooks | destin_board;
-            }
-            PieceType::WhiteKnight => {
-                self.try_take(destin_board);
-                self.w_knights = self.w_knights & !piece_board;
-                self.w_knights = self.w_knights | destin_board;
+        let (from, to) = (movve.get_from(), movve.get_to());
+        let own_pieces = match playing_as {
+            Turn::White => self.getWhiteBitboard(),
+            Turn::Black => self.getBlackBitboard(),
+        };
+        if !own_pieces.contains(from) {
+            return;
+        }
+        let piece_type = match self.piece_at(from) {
+            Some(piece_type) => piece_type,
+            None => return,
+        };
+
+        if movve.is_en_passant() {
+            let en_passant_type = match playing_as {
+                Turn::White => PieceType::WhitePawnEnPassant,
+                Turn::Black => PieceType::BlackPawnEnPassant,
+            };
+            self.do_try_move(from, to, en_passant_type);
+            return;
+        }
+
+        self.do_try_move(from, to, piece_type);
+        if movve.is_promotion() {
+            self.pawn_do_promote(to, playing_as, movve.get_promotion());
+        }
+        if movve.is_double_push() {
+            match playing_as {
+                Turn::White => self.w_en_passant = to.bitboard(),
+                Turn::Black => self.b_en_passant = to.bitboard(),
             }
-            PieceType::WhitePawn => {
-                self.try_take(destin_board);
-                self.w_pawns = self.w_pawns & !piece_board;
-                self.w_pawns = self.w_pawns | destin_board;
-                self.pawn_do_promote(destin_board, Turn::White);
+        }
+    }
+
+    //Plays the move and hands the side to move over, the record restores it with unmake_move
+    pub fn make_move(&mut self, movve: Move, playing_as: Turn) -> UndoMove {
+        let mut undo = UndoMove {
+            legal: movve,
+            playing_as: playing_as,
+            moved: None,
+            captured: None,
+            captured_square: movve.get_to(),
+            has_w_king_side_castle: self.has_w_king_side_castle,
+            has_w_queen_side_castle: self.has_w_queen_side_castle,
+            w_king_has_moved: self.w_king_has_moved,
+            has_b_king_side_castle: self.has_b_king_side_castle,
+            has_b_queen_side_castle: self.has_b_queen_side_castle,
+            b_king_has_moved: self.b_king_has_moved,
+            w_en_passant: self.w_en_passant,
+            b_en_passant: self.b_en_passant,
+            full_move_count: self.full_move_count,
+            half_move_count: self.half_move_count,
+            turn: self.turn,
+            zobrist_key: self.zobrist_key,
+        };
+
+        if !movve.is_castle() {
+            let (from, to) = (movve.get_from(), movve.get_to());
+            let own_pieces = match playing_as {
+                Turn::White => self.getWhiteBitboard(),
+                Turn::Black => self.getBlackBitboard(),
+            };
+            if own_pieces.contains(from) {
+                undo.moved = self.piece_at(from);
             }
-            PieceType::WhitePawnEnPassant => {
-                self.try_take(destin_board >> 8);
-                self.w_pawns = self.w_pawns & !piece_board;
-                self.w_pawns = self.w_pawns | destin_board;
+
+            if movve.is_en_passant() {
+                undo.captured_square = Square::new(to.file(), from.rank());
             }
-            PieceType::BlackQueen => {
-                self.try_take(destin_board);
-                self.b_queen = self.b_queen & !piece_board;
-                self.b_queen = self.b_queen | destin_board;
+            if movve.is_capture() {
+                undo.captured = self.piece_at(undo.captured_square);
             }
-            PieceType::BlackKing => {
-                self.try_take(destin_board);
-                self.b_king = self.b_king & !piece_board;
-                self.b_king = self.b_king | destin_board;
+        }
 
-                self.b_king_has_moved = true;
-            }
-            PieceType::BlackBishop => {
-                self.try_take(destin_board);
-                self.b_bishops = self.b_bishops & !piece_board;
-                self.b_bishops = self.b_bishops | destin_board;
+        //A castle that can not be played leaves the pieces untouched
+        if movve.is_castle() && self.can_castle(playing_as, movve.get_castle()) {
+            undo.moved = match playing_as {
+                Turn::White => Some(PieceType::WhiteKing),
+                Turn::Black => Some(PieceType::BlackKing),
+            };
+        }
+        self.do_move(movve, playing_as);
+        self.set_turn(Movement::get_oposite_color(playing_as));
+        return undo;
+    }
+
+    pub fn unmake_move(&mut self, undo: UndoMove) {
+        let movve = undo.legal;
+        match (movve.get_castle(), undo.moved) {
+            (_, None) => {}
+            (castle, Some(king)) if castle != CastleOptions::None => {
+                //The move keeps the king and rook squares from before castling
+                let (king_to, rook_to) = Board::get_castle_targets(undo.playing_as, castle);
+                let rook = match undo.playing_as {
+                    Turn::White => PieceType::WhiteRook,
+                    Turn::Black => PieceType::BlackRook,
+                };
+                self.remove_piece(king_to);
+                self.remove_piece(rook_to);
+                self.put_piece(king, movve.get_from());
+                self.put_piece(rook, movve.get_to());
             }
-            PieceType::BlackRook => {
-                if piece_board == 0x100000000000000 {
-                    self.has_b_king_side_castle = true;
-                }
-                if piece_board == 0x8000000000000000 {
-                    self.has_b_queen_side_castle = true;
+            (_, Some(moved)) => {
+                //The piece on the destination differs from the moved one after a promotion
+                self.remove_piece(movve.get_to());
+                self.put_piece(moved, movve.get_from());
+
+                if let Some(captured) = undo.captured {
+                    self.put_piece(captured, undo.captured_square);
                 }
-                self.try_take(destin_board);
-                self.b_rooks = self.b_rooks & !piece_board;
-                self.b_rooks = self.b_rooks | destin_board;
-            }
-            PieceType::BlackKnight => {
-                self.try_take(destin_board);
-                self.b_knights = self.b_knights & !piece_board;
-                self.b_knights = self.b_knights | destin_board;
-            }
-            PieceType::BlackPawn => {
-                self.try_take(destin_board);
-                self.b_pawns = self.b_pawns & !piece_board;
-                self.b_pawns = self.b_pawns | destin_board;
-                self.pawn_do_promote(destin_board, Turn::White);
-            }
-            PieceType::BlackPawnEnPassant => {
-                self.try_take(destin_board << 8);
-                self.b_pawns = self.b_pawns & !piece_board;
-                self.b_pawns = self.b_pawns | destin_board;
             }
         }
+
+        self.has_w_king_side_castle = undo.has_w_king_side_castle;
+        self.has_w_queen_side_castle = undo.has_w_queen_side_castle;
+        self.w_king_has_moved = undo.w_king_has_moved;
+        self.has_b_king_side_castle = undo.has_b_king_side_castle;
+        self.has_b_queen_side_castle = undo.has_b_queen_side_castle;
+        self.b_king_has_moved = undo.b_king_has_moved;
+        self.w_en_passant = undo.w_en_passant;
+        self.b_en_passant = undo.b_en_passant;
+        self.full_move_count = undo.full_move_count;
+        self.half_move_count = undo.half_move_count;
+        self.turn = undo.turn;
+        self.zobrist_key = undo.zobrist_key;
     }
 
-    pub fn do_move(&mut self, movve: LegalMove, playing_as: Turn) {
-        match movve.castle {
-            CastleOptions::KingSide => self.try_castle(movve, playing_as),
-            CastleOptions::QueenSide => self.try_castle(movve, playing_as),
-            CastleOptions::None => {
-                //Move is not castle
-                let piece_bitboard: u64 = Board::get_piece_bitboard_from_position(movve.from);
-                let destin_bitboard: u64 = Board::get_piece_bitboard_from_position(movve.to);
-
-                match playing_as {
-                    Turn::Black => {
-                        self.b_en_passant = 0;
-                        //Piece is Rook
-                        if self.b_rooks & piece_bitboard > 0 {
-                            self.do_try_move(piece_bitboard, destin_bitboard, PieceType::BlackRook);
-                        }
-
-                        //Piece is Knight
-                        if self.b_knights & piece_bitboard > 0 {
-                            self.do_try_move(
-                                piece_bitboard,
-                                destin_bitboard,
-                                PieceType::BlackKnight,
-                            );
-                        }
-
-                        //Piece is Bishop
-                        if self.b_bishops & piece_bitboard > 0 {
-                            self.do_try_move(
-                                piece_bitboard,
-                                destin_bitboard,
-                                PieceType::BlackBishop,
-                            );
-                        }
-
-                        //Piece is King
-                        if self.b_king & piece_bitboard > 0 {
-                            self.do_try_move(piece_bitboard, destin_bitboard, PieceType::BlackKing);
-                        }
-                        //Piece is Queen
-                        if self.b_queen & piece_bitboard > 0 {
-                            self.do_try_move(
-                                piece_bitboard,
-                                destin_bitboard,
-                                PieceType::BlackQueen,
-                            );
-                        }
-                        //Piece is Pawn
-                        if self.b_pawns & piece_bitboard > 0 {
-                            if piece_bitboard >> 16 == destin_bitboard {
-                                self.b_en_passant = destin_bitboard;
-                            }
-                            self.do_try_move(piece_bitboard, destin_bitboard, PieceType::BlackPawn);
-                        }
-                    }
-                    Turn::White => {
-                        self.w_en_passant = 0;
-                        //Piece is Rook
-                        if self.w_rooks & piece_bitboard > 0 {
-                            self.do_try_move(piece_bitboard, destin_bitboard, PieceType::WhiteRook);
-                        }
-
-                        //Piece is Knight
-                        if self.w_knights & piece_bitboard > 0 {
-                            self.do_try_move(
-                                piece_bitboard,
-                                destin_bitboard,
-                                PieceType::WhiteKnight,
-                            );
-                        }
-
-                        //Piece is Bishop
-                        if self.w_bishops & piece_bitboard > 0 {
-                            self.do_try_move(
-                                piece_bitboard,
-                                destin_bitboard,
-                                PieceType::WhiteBishop,
-                            );
-                        }
-
-                        //Piece is King
-                        if self.w_king & piece_bitboard > 0 {
-                            self.do_try_move(piece_bitboard, destin_bitboard, PieceType::WhiteKing);
-                        }
-                        //Piece is Queen
-                        if self.w_queen & piece_bitboard > 0 {
-                            self.do_try_move(
-                                piece_bitboard,
-                                destin_bitboard,
-                                PieceType::WhiteQueen,
-                            );
-                        }
-                        //Piece is Pawn
-                        if self.w_pawns & piece_bitboard > 0 {
-                            if piece_bitboard << 16 == destin_bitboard {
-                                self.w_en_passant = destin_bitboard;
-                            }
-                            self.do_try_move(piece_bitboard, destin_bitboard, PieceType::WhitePawn);
-                        }
-                    }
-                }
-            }
+    pub fn piece_at(&self, square: Square) -> Option<PieceType> {
+        return self.mailbox[square.index()];
+    }
+
+    //Places a piece on the bitboards and the mailbox, replacing whatever was there
+    pub fn put_piece(&mut self, piece_type: PieceType, square: Square) {
+        let piece_type = match piece_type {
+            PieceType::WhitePawnEnPassant => PieceType::WhitePawn,
+            PieceType::BlackPawnEnPassant => PieceType::BlackPawn,
+            piece_type => piece_type,
+        };
+        self.remove_piece(square);
+        *self.get_piece_bitboard_mut(piece_type) |= square.bitboard();
+        self.mailbox[square.index()] = Some(piece_type);
+    }
+
+    pub fn remove_piece(&mut self, square: Square) -> Option<PieceType> {
+        let removed = self.mailbox[square.index()].take();
+        if let Some(piece_type) = removed {
+            *self.get_piece_bitboard_mut(piece_type) &= !square.bitboard();
         }
+        return removed;
     }
 
-    pub fn can_castle_king_side(&self, playing_as: Turn) -> bool {
-        match playing_as {
-            Turn::White => {
-                let attacts = Movement::extract_all_attacks_for_color(*self, Turn::Black);
+    fn move_piece(&mut self, from: Square, to: Square) {
+        if let Some(piece_type) = self.remove_piece(from) {
+            self.put_piece(piece_type, to);
+        }
+    }
 
-                return !(self.has_w_king_side_castle || self.w_king_has_moved)
-                    && self.w_king & 0x8 > 0
-                    && self.w_rooks & 0x1 > 0
-                    && self.getOcupancy() & 0x6 == 0
-                    && attacts & 0xe == 0;
+    //For code that sets the piece bitboards directly instead of using put_piece
+    pub fn sync_mailbox(&mut self) {
+        self.mailbox = [None; 64];
+        for (bitboard, piece_type) in self.get_piece_bitboards() {
+            for square in bitboard {
+                self.mailbox[square.index()] = Some(piece_type);
             }
-            Turn::Black => {
-                let attacts = Movement::extract_all_attacks_for_color(*self, Turn::White);
+        }
+    }
 
-                return !(self.has_b_king_side_castle || self.b_king_has_moved)
-                    && self.b_king & 0x800000000000000 > 0
-                    && self.b_rooks & 0x100000000000000 > 0
-                    && self.getOcupancy() & 0x600000000000000 == 0
-                    && attacts & 0xe00000000000000 == 0;
-            }
+    fn is_mailbox_in_sync(&self) -> bool {
+        let mut synced = *self;
+        synced.sync_mailbox();
+        return synced.mailbox == self.mailbox;
+    }
+
+    pub fn get_piece_bitboards(&self) -> [(Bitboard, PieceType); 12] {
+        return [
+            (self.w_pawns, PieceType::WhitePawn),
+            (self.w_knights, PieceType::WhiteKnight),
+            (self.w_bishops, PieceType::WhiteBishop),
+            (self.w_rooks, PieceType::WhiteRook),
+            (self.w_queen, PieceType::WhiteQueen),
+            (self.w_king, PieceType::WhiteKing),
+            (self.b_pawns, PieceType::BlackPawn),
+            (self.b_knights, PieceType::BlackKnight),
+            (self.b_bishops, PieceType::BlackBishop),
+            (self.b_rooks, PieceType::BlackRook),
+            (self.b_queen, PieceType::BlackQueen),
+            (self.b_king, PieceType::BlackKing),
+        ];
+    }
+
+    fn get_piece_bitboard_mut(&mut self, piece_type: PieceType) -> &mut Bitboard {
+        match piece_type {
+            PieceType::WhiteKing => return &mut self.w_king,
+            PieceType::WhiteQueen => return &mut self.w_queen,
+            PieceType::WhiteRook => return &mut self.w_rooks,
+            PieceType::WhiteBishop => return &mut self.w_bishops,
+            PieceType::WhiteKnight => return &mut self.w_knights,
+            PieceType::WhitePawn | PieceType::WhitePawnEnPassant => return &mut self.w_pawns,
+            PieceType::BlackKing => return &mut self.b_king,
+            PieceType::BlackQueen => return &mut self.b_queen,
+            PieceType::BlackRook => return &mut self.b_rooks,
+            PieceType::BlackBishop => return &mut self.b_bishops,
+            PieceType::BlackKnight => return &mut self.b_knights,
+            PieceType::BlackPawn | PieceType::BlackPawnEnPassant => return &mut self.b_pawns,
         }
     }
 
+    pub fn can_castle_king_side(&self, playing_as: Turn) -> bool {
+        return self.can_castle(playing_as, CastleOptions::KingSide);
+    }
+
     pub fn can_castle_queen_side(&self, playing_as: Turn) -> bool {
-        match playing_as {
-            Turn::White => {
-                let attacts = Movement::extract_all_attacks_for_color(*self, Turn::Black);
+        return self.can_castle(playing_as, CastleOptions::QueenSide);
+    }
 
-                return !(self.has_w_queen_side_castle || self.w_king_has_moved)
-                    && self.w_king & 0x8 > 0
-                    && self.w_rooks & 0x80 > 0
-                    && self.getOcupancy() & 0x70 == 0
-                    && attacts & 0x78 == 0;
-            }
-            Turn::Black => {
-                let attacts = Movement::extract_all_attacks_for_color(*self, Turn::White);
+    //Works from the actual king and rook squares, so it covers Chess960 as well
+    pub fn can_castle(&self, playing_as: Turn, castle: CastleOptions) -> bool {
+        let rook_square = match self.get_castle_rook_square(playing_as, castle) {
+            Some(rook_square) => rook_square,
+            None => return false,
+        };
+        let (king, rook) = match playing_as {
+            Turn::White => (PieceType::WhiteKing, PieceType::WhiteRook),
+            Turn::Black => (PieceType::BlackKing, PieceType::BlackRook),
+        };
+        let king_square = match self.get_king_square(playing_as) {
+            Some(king_square) if king_square.rank() == rook_square.rank() => king_square,
+            _ => return false,
+        };
+        if self.piece_at(king_square) != Some(king) || self.piece_at(rook_square) != Some(rook) {
+            return false;
+        }
 
-                return !(self.has_b_queen_side_castle || self.b_king_has_moved)
-                    && self.b_king & 0x800000000000000 > 0
-                    && self.b_rooks & 0x8000000000000000 > 0
-                    && self.getOcupancy() & 0x7000000000000000 == 0
-                    && attacts & 0x7800000000000000 == 0;
-            }
+        //Everything either piece passes over or lands on must be empty, apart from the two of them
+        let (king_to, rook_to) = Board::get_castle_targets(playing_as, castle);
+        let ocupancy = self.getOcupancy().without(king_square).without(rook_square);
+        let king_path = Magic::get_between(king_square, king_to)
+            .with(king_square)
+            .with(king_to);
+        let rook_path = Magic::get_between(rook_square, rook_to).with(rook_to);
+        if !((king_path | rook_path) & ocupancy).is_empty() {
+            return false;
         }
+
+        //The castling rook does not shield the king once it has moved
+        let enemy = Movement::get_oposite_color(playing_as);
+        return king_path
+            .into_iter()
+            .all(|square| self.get_attackers(square, enemy, ocupancy).is_empty());
     }
 
     pub fn print_board_self(&self, board_name: &str) {
         Printer::print_board(board_name, *self);
     }
 
-    pub fn get_moves(&self, color: Turn) -> Vec<LegalMove> {
-        let mut board_copy = self.clone();
-        let mut legal_moves = board_copy.extract_all_legal_moves_for_color(color);
-        legal_moves.dedup();
-        return legal_moves;
+    //Whether the side to move is mated, stalemated or can still play
+    pub fn status(&self) -> BoardStatus {
+        let turn = self.get_turn();
+        if !self.get_moves(turn).is_empty() {
+            return BoardStatus::Ongoing;
+        }
+        if self.is_in_check(turn) {
+            return BoardStatus::Checkmate;
+        }
+        return BoardStatus::Stalemate;
     }
 
-    fn is_move_legal(self, legal_move: LegalMove, playing_as: Turn) -> bool {
-        let mut board_check = self;
-        board_check.do_move(legal_move, playing_as);
-        match playing_as {
-            Turn::Black => self.b_king & self.white_attacks == 0,
-            Turn::White => self.w_king & self.black_attacks == 0,
+    //Dead positions by material, K v K, K+minor v K and bishops all on one square colour
+    pub fn is_insufficient_material(&self) -> bool {
+        let heavy_and_pawns =
+            self.w_pawns | self.b_pawns | self.w_rooks | self.b_rooks | self.w_queen | self.b_queen;
+        if !heavy_and_pawns.is_empty() {
+            return false;
         }
-    }
 
-    pub fn legalize_moves(&mut self, moves: Vec<LegalMove>, playing_as: Turn) -> Vec<LegalMove> {
-        let mut legal_moves: Vec<LegalMove> = Vec::new();
-        let board_clone = self.clone();
-        match playing_as {
-            Turn::White => {
-                self.white_attacks = Movement::get_color_attacks(Turn::White, board_clone)
-            }
-            Turn::Black => {
-                self.black_attacks = Movement::get_color_attacks(Turn::Black, board_clone)
-            }
+        let white_minors = (self.w_knights | self.w_bishops).count();
+        let black_minors = (self.b_knights | self.b_bishops).count();
+        if white_minors + black_minors <= 1 {
+            return true;
         }
-        for movve in moves {
-            if self.is_move_legal(movve, playing_as) {
-                legal_moves.push(movve);
+
+        let knights = self.w_knights | self.b_knights;
+        let bishops = self.w_bishops | self.b_bishops;
+        return knights.is_empty()
+            && ((bishops & LIGHT_SQUARES).is_empty() || (bishops & !LIGHT_SQUARES).is_empty());
+    }
+
+    pub fn is_in_check(&self, color: Turn) -> bool {
+        match self.get_king_square(color) {
+            Some(king_square) => {
+                return self.is_square_attacked(king_square, Movement::get_oposite_color(color))
             }
+            None => return false,
         }
+    }
+
+    //Pieces of both colours attacking a square, sliders are blocked by the given occupancy
+    pub fn attackers_to(&self, square: Square, occupancy: Bitboard) -> Bitboard {
+        return self.get_attackers(square, Turn::White, occupancy)
+            | self.get_attackers(square, Turn::Black, occupancy);
+    }
+
+    pub fn is_square_attacked(&self, square: Square, by_color: Turn) -> bool {
+        let ocupancy = self.getOcupancy();
+        return !self.get_attackers(square, by_color, ocupancy).is_empty();
+    }
 
+    //Standard algebraic notation of a legal move in this position, with check and mate marks
+    pub fn move_to_san(&self, movve: Move) -> String {
+        return SAN::move_to_san(self, movve);
+    }
+
+    pub fn parse_san(&self, san: &str) -> Result<Move, SanError> {
+        return SAN::parse_san(self, san);
+    }
+
+    //Material won by the move once the exchange on its target square is played out, in centipawns
+    pub fn see(&self, movve: Move) -> i32 {
+        return SEE::see(self, movve);
+    }
+
+    pub fn see_ge(&self, movve: Move, threshold: i32) -> bool {
+        return self.see(movve) >= threshold;
+    }
+
+    pub fn get_moves(&self, color: Turn) -> Vec<Move> {
+        let mut legal_moves = self.generate_moves(color, MoveStage::All);
+        legal_moves.dedup();
         return legal_moves;
     }
 
-    pub fn extract_all_legal_moves_for_color(&mut self, color: Turn) -> Vec<LegalMove> {
-        let mut moves: Vec<LegalMove> = Vec::new();
-        let mut castling: Vec<LegalMove> = Vec::new();
+    //Counts the leaf nodes of the legal move tree, used to validate move generation
+    pub fn perft(&self, depth: u8) -> u64 {
+        let mut board = *self;
+        return board.perft_in_place(depth);
+    }
 
-        self.white_attacks = Movement::get_color_attacks(Turn::White, *self);
-        self.black_attacks = Movement::get_color_attacks(Turn::Black, *self);
+    fn perft_in_place(&mut self, depth: u8) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+        let turn = self.get_turn();
+        let legal_moves = self.get_moves(turn);
+        if depth == 1 {
+            return legal_moves.len() as u64;
+        }
 
-        if self.can_castle_king_side(color) {
-            castling.push(LegalMove::king_side_castle(color));
+        let mut nodes = 0;
+        for legal_move in legal_moves {
+            let undo = self.make_move(legal_move, turn);
+            nodes += self.perft_in_place(depth - 1);
+            self.unmake_move(undo);
         }
+        return nodes;
+    }
 
-        if self.can_castle_queen_side(color) {
-            castling.push(LegalMove::queen_side_castle(color));
+    //Perft split by root move
+    pub fn divide(&self, depth: u8) -> Vec<(Move, u64)> {
+        let mut divided: Vec<(Move, u64)> = Vec::new();
+        if depth == 0 {
+            return divided;
         }
+        let mut board = *self;
+        let turn = board.get_turn();
+        for legal_move in board.get_moves(turn) {
+            let undo = board.make_move(legal_move, turn);
+            divided.push((legal_move, board.perft_in_place(depth - 1)));
+            board.unmake_move(undo);
+        }
+        return divided;
+    }
 
-        moves.extend(castling);
-        match color {
-            Turn::White => {
-                moves.extend(self.extract_pieces_moves_from_bitboard(
-                    self.w_pawns,
-                    color,
-                    PieceType::WhitePawn,
-                ));
-                moves.extend(self.extract_pieces_moves_from_bitboard(
-                    self.w_pawns,
-                    color,
-                    PieceType::WhitePawnEnPassant,
-                ));
+    pub fn extract_all_legal_moves_for_color(&mut self, color: Turn) -> Vec<Move> {
+        return self.generate_moves(color, MoveStage::All);
+    }
 
-                moves.extend(self.extract_pieces_moves_from_bitboard(
-                    self.w_rooks,
-                    color,
-                    PieceType::WhiteRook,
-                ));
+    //Captures, en passant and every promotion, what a quiescence search looks at
+    pub fn get_captures(&self, color: Turn) -> Vec<Move> {
+        return self.generate_moves(color, MoveStage::Captures);
+    }
 
-                moves.extend(self.extract_pieces_moves_from_bitboard(
-                    self.w_bishops,
-                    color,
-                    PieceType::WhiteBishop,
-                ));
-                moves.extend(self.extract_pieces_moves_from_bitboard(
-                    self.w_knights,
-                    color,
-                    PieceType::WhiteKnight,
-                ));
-                moves.extend(self.extract_pieces_moves_from_bitboard(
-                    self.w_queen,
-                    color,
-                    PieceType::WhiteQueen,
-                ));
+    //Moves to empty squares without promotion, castles included
+    pub fn get_quiets(&self, color: Turn) -> Vec<Move> {
+        return self.generate_moves(color, MoveStage::Quiets);
+    }
 
-                moves.extend(self.extract_pieces_moves_from_bitboard(
-                    self.w_king,
-                    color,
-                    PieceType::WhiteKing,
-                ));
-            }
-            Turn::Black => {
-                moves.extend(self.extract_pieces_moves_from_bitboard(
-                    self.b_pawns,
-                    color,
-                    PieceType::BlackPawn,
-                ));
+    //Every legal move while in check, nothing otherwise
+    pub fn get_evasions(&self, color: Turn) -> Vec<Move> {
+        return self.generate_moves(color, MoveStage::Evasions);
+    }
 
-                moves.extend(self.extract_pieces_moves_from_bitboard(
-                    self.b_pawns,
-                    color,
-                    PieceType::BlackPawnEnPassant,
-                ));
+    fn generate_moves(&self, color: Turn, stage: MoveStage) -> Vec<Move> {
+        let mut moves: Vec<Move> = Vec::new();
+        let masks = self.get_move_masks(color);
+        let in_check = !masks.checkers.is_empty();
+        if stage == MoveStage::Evasions && !in_check {
+            return moves;
+        }
 
-                moves.extend(self.extract_pieces_moves_from_bitboard(
-                    self.b_rooks,
-                    color,
-                    PieceType::BlackRook,
-                ));
+        //Stages only differ in the target squares they keep
+        let (enemy_bits, empty) = match color {
+            Turn::White => (self.getBlackBitboard(), self.getNotOcupancy()),
+            Turn::Black => (self.getWhiteBitboard(), self.getNotOcupancy()),
+        };
+        let promotion_ranks = RANK_1 | RANK_8;
+        let (targets, pawn_targets) = match stage {
+            MoveStage::All | MoveStage::Evasions => (Bitboard::FULL, Bitboard::FULL),
+            MoveStage::Captures => (enemy_bits, enemy_bits | promotion_ranks),
+            MoveStage::Quiets => (empty, empty & !promotion_ranks),
+        };
 
+        //In double check only the king can move
+        if masks.checkers.count() < 2 {
+            if !in_check && (stage == MoveStage::All || stage == MoveStage::Quiets) {
+                if self.can_castle_king_side(color) {
+                    moves.push(Move::king_side_castle(self, color));
+                }
+
+                if self.can_castle_queen_side(color) {
+                    moves.push(Move::queen_side_castle(self, color));
+                }
+            }
+
+            let pieces = match color {
+                Turn::White => [
+                    (self.w_pawns, PieceType::WhitePawn, pawn_targets),
+                    (self.w_rooks, PieceType::WhiteRook, targets),
+                    (self.w_bishops, PieceType::WhiteBishop, targets),
+                    (self.w_knights, PieceType::WhiteKnight, targets),
+                    (self.w_queen, PieceType::WhiteQueen, targets),
+                ],
+                Turn::Black => [
+                    (self.b_pawns, PieceType::BlackPawn, pawn_targets),
+                    (self.b_rooks, PieceType::BlackRook, targets),
+                    (self.b_bishops, PieceType::BlackBishop, targets),
+                    (self.b_knights, PieceType::BlackKnight, targets),
+                    (self.b_queen, PieceType::BlackQueen, targets),
+                ],
+            };
+            for (pieces_bits, piece_type, piece_targets) in pieces {
                 moves.extend(self.extract_pieces_moves_from_bitboard(
-                    self.b_bishops,
-                    color,
-                    PieceType::BlackBishop,
-                ));
-                moves.extend(self.extract_pieces_moves_from_bitboard(
-                    self.b_knights,
-                    color,
-                    PieceType::BlackKnight,
-                ));
-                moves.extend(self.extract_pieces_moves_from_bitboard(
-                    self.b_queen,
+                    pieces_bits,
                     color,
-                    PieceType::BlackQueen,
+                    piece_type,
+                    &masks,
+                    piece_targets,
                 ));
+            }
 
+            if stage != MoveStage::Quiets {
+                let (pawns_bits, en_passant_type) = match color {
+                    Turn::White => (self.w_pawns, PieceType::WhitePawnEnPassant),
+                    Turn::Black => (self.b_pawns, PieceType::BlackPawnEnPassant),
+                };
                 moves.extend(self.extract_pieces_moves_from_bitboard(
-                    self.b_king,
+                    pawns_bits,
                     color,
-                    PieceType::BlackKing,
+                    en_passant_type,
+                    &masks,
+                    Bitboard::FULL,
                 ));
             }
         }
+
+        if let Some(king_square) = self.get_king_square(color) {
+            moves.extend(self.get_king_moves(king_square, color, targets));
+        }
         return moves;
     }
 
-    pub fn extract_pieces_moves_from_bitboard(
-        self,
-        mut pieces_bits: u64,
-        color: Turn,
-        piece_type: PieceType,
-    ) -> Vec<LegalMove> {
-        if pieces_bits == 0 {
-            return Vec::new();
-        } else {
-            let mut legal_moves: Vec<LegalMove> = Vec::new();
+    pub fn get_king_square(&self, color: Turn) -> Option<Square> {
+        match color {
+            Turn::White => return self.w_king.first(),
+            Turn::Black => return self.b_king.first(),
+        }
+    }
+
+    //Pieces of a colour attacking a square, sliders are blocked by the given occupancy
+    fn get_attackers(&self, square: Square, by: Turn, occupancy: Bitboard) -> Bitboard {
+        let (pawns, knights, bishops, rooks, queen, king) = match by {
+            Turn::White => (
+                self.w_pawns,
+                self.w_knights,
+                self.w_bishops,
+                self.w_rooks,
+                self.w_queen,
+                self.w_king,
+            ),
+            Turn::Black => (
+                self.b_pawns,
+                self.b_knights,
+                self.b_bishops,
+                self.b_rooks,
+                self.b_queen,
+                self.b_king,
+            ),
+        };
 
-            while pieces_bits > 0 {
-                let piece_pos = Movement::msb_pos(pieces_bits);
-                let piece_bits = (1 as u64) << piece_pos;
+        //A pawn attacks the square if a pawn of the other colour there would attack it
+        let mut attackers =
+            Attacks::get_pawn_attacks(square, Movement::get_oposite_color(by)) & pawns;
+        attackers |= Attacks::get_knight_attacks(square) & knights;
+        attackers |= Attacks::get_king_attacks(square) & king;
+        attackers |= Magic::get_bishop_attacks(square, occupancy) & (bishops | queen);
+        attackers |= Magic::get_rook_attacks(square, occupancy) & (rooks | queen);
+        return attackers;
+    }
 
-                let result = self.extract_piece_moves_from_bitboard(piece_bits, color, piece_type);
-                for elem in result {
-                    legal_moves.push(elem)
+    fn get_move_masks(&self, color: Turn) -> MoveMasks {
+        let mut masks = MoveMasks {
+            checkers: Bitboard::EMPTY,
+            evasion_mask: Bitboard::FULL,
+            pinned: Bitboard::EMPTY,
+            pin_rays: [Bitboard::FULL; 64],
+        };
+        let king_square = match self.get_king_square(color) {
+            Some(king_square) => king_square,
+            None => return masks,
+        };
+
+        let enemy = Movement::get_oposite_color(color);
+        let ocupancy = self.getOcupancy();
+        let (ally_bits, enemy_bits, enemy_rooks, enemy_bishops) = match color {
+            Turn::White => (
+                self.getWhiteBitboard(),
+                self.getBlackBitboard(),
+                self.b_rooks | self.b_queen,
+                self.b_bishops | self.b_queen,
+            ),
+            Turn::Black => (
+                self.getBlackBitboard(),
+                self.getWhiteBitboard(),
+                self.w_rooks | self.w_queen,
+                self.w_bishops | self.w_queen,
+            ),
+        };
+
+        masks.checkers = self.get_attackers(king_square, enemy, ocupancy);
+        match masks.checkers.first() {
+            None => {}
+            Some(checker_square) if masks.checkers.count() == 1 => {
+                masks.evasion_mask =
+                    Magic::get_between(king_square, checker_square) | masks.checkers;
+            }
+            Some(_) => masks.evasion_mask = Bitboard::EMPTY,
+        }
+
+        //Sliders that would see the king through our pieces, one piece in between is pinned
+        let snipers = (Magic::get_rook_attacks(king_square, enemy_bits) & enemy_rooks)
+            | (Magic::get_bishop_attacks(king_square, enemy_bits) & enemy_bishops);
+        for sniper_square in snipers {
+            let between = Magic::get_between(king_square, sniper_square);
+            let blockers = between & ocupancy;
+            if let Some(pinned_square) = blockers.first() {
+                if blockers.count() == 1 && ally_bits.contains(pinned_square) {
+                    masks.pinned |= blockers;
+                    masks.pin_rays[pinned_square.index()] = between.with(sniper_square);
                 }
-                pieces_bits = pieces_bits & !piece_bits;
             }
+        }
+        return masks;
+    }
 
-            return legal_moves;
+    fn extract_pieces_moves_from_bitboard(
+        self,
+        pieces_bits: Bitboard,
+        color: Turn,
+        piece_type: PieceType,
+        masks: &MoveMasks,
+        targets: Bitboard,
+    ) -> Vec<Move> {
+        let mut legal_moves: Vec<Move> = Vec::new();
+        for square in pieces_bits {
+            legal_moves.extend(self.extract_piece_moves_from_bitboard(
+                square,
+                color,
+                piece_type,
+                masks.get_mask(square) & targets,
+            ));
         }
+        return legal_moves;
     }
 
+    //The legal mask keeps pieces on their pin ray and makes them answer a check
     fn extract_piece_moves_from_bitboard(
         self,
-        piece_bits: u64,
+        square: Square,
         color: Turn,
         piece_type: PieceType,
-    ) -> Vec<LegalMove> {
+        legal_mask: Bitboard,
+    ) -> Vec<Move> {
         match piece_type {
-            PieceType::WhiteBishop => {
-                return self.get_bishop_moves(piece_bits, color);
-            }
-            PieceType::WhiteKnight => return self.get_knigth_moves(piece_bits, color),
-            PieceType::WhiteRook => return self.get_rook_moves(piece_bits, color),
-            PieceType::WhiteQueen => return self.get_queen_moves(piece_bits, color),
-            PieceType::WhitePawn => return self.get_pawn_moves(piece_bits, color),
+            PieceType::WhiteBishop => return self.get_bishop_moves(square, color, legal_mask),
+            PieceType::WhiteKnight => return self.get_knigth_moves(square, color, legal_mask),
+            PieceType::WhiteRook => return self.get_rook_moves(square, color, legal_mask),
+            PieceType::WhiteQueen => return self.get_queen_moves(square, color, legal_mask),
+            PieceType::WhitePawn => return self.get_pawn_moves(square, color, legal_mask),
             PieceType::WhitePawnEnPassant => {
-                return self.get_pawn_moves_enpassant(piece_bits, self.b_en_passant, color)
+                return self.get_pawn_moves_enpassant(square, self.b_en_passant, color)
             }
-            PieceType::WhiteKing => return self.get_king_moves(piece_bits, color),
-            PieceType::BlackBishop => return self.get_bishop_moves(piece_bits, color),
-            PieceType::BlackKnight => return self.get_knigth_moves(piece_bits, color),
-            PieceType::BlackRook => return self.get_rook_moves(piece_bits, color),
-            PieceType::BlackQueen => return self.get_queen_moves(piece_bits, color),
-            PieceType::BlackPawn => return self.get_pawn_moves(piece_bits, color),
+            PieceType::WhiteKing => return self.get_king_moves(square, color, legal_mask),
+            PieceType::BlackBishop => return self.get_bishop_moves(square, color, legal_mask),
+            PieceType::BlackKnight => return self.get_knigth_moves(square, color, legal_mask),
+            PieceType::BlackRook => return self.get_rook_moves(square, color, legal_mask),
+            PieceType::BlackQueen => return self.get_queen_moves(square, color, legal_mask),
+            PieceType::BlackPawn => return self.get_pawn_moves(square, color, legal_mask),
             PieceType::BlackPawnEnPassant => {
-                return self.get_pawn_moves_enpassant(piece_bits, self.w_en_passant, color)
+                return self.get_pawn_moves_enpassant(square, self.w_en_passant, color)
             }
-            PieceType::BlackKing => return self.get_king_moves(piece_bits, color),
+            PieceType::BlackKing => return self.get_king_moves(square, color, legal_mask),
         }
     }
 
-    fn translate_piece_moves_from_bitboard(
-        self,
-        piece_bits: u64,
-        mut piece_move_bits: u64,
-        color: Turn,
-        board: Board,
-    ) -> Vec<LegalMove> {
-        let piece_index = Movement::get_piece_index(piece_bits);
-        let from_column: i8 = piece_index % 8;
-        let from_row: i8 = piece_index / 8;
-
-        let mut legal_moves: Vec<LegalMove> = Vec::new();
-
-        while piece_move_bits > 0 {
-            let piece_pos = Movement::msb_pos(piece_move_bits);
-            let piece_bits = (1 as u64) << piece_pos;
-
-            let to_column = piece_pos % 8;
-            let to_row = piece_pos / 8;
-
-            let lega_move = LegalMove::new(from_row, from_column, to_row, to_column);
-
-            if self.is_move_legal(lega_move, color) {
-                legal_moves.push(lega_move.to_owned())
-            }
-
-            piece_move_bits = piece_move_bits & !piece_bits;
+    //Targets are never own pieces, so any occupied target is a capture
+    fn translate_piece_moves_from_bitboard(&self, from: Square, targets: Bitboard) -> Vec<Move> {
+        let ocupancy = self.getOcupancy();
+        let mut legal_moves: Vec<Move> = Vec::new();
+        for to in targets {
+            let flag = if ocupancy.contains(to) {
+                MoveFlag::Capture
+            } else {
+                MoveFlag::Quiet
+            };
+            legal_moves.push(Move::new(from, to, flag));
         }
-
         return legal_moves;
     }
 
-    fn get_knigth_moves(self, knight_bits: u64, color: Turn) -> Vec<LegalMove> {
-        let bitboard_moves = Knight::get_moves(knight_bits, color, self);
+    fn get_knigth_moves(self, square: Square, color: Turn, legal_mask: Bitboard) -> Vec<Move> {
+        let bitboard_moves = Knight::get_moves(square.bitboard(), color, self) & legal_mask;
 
-        return self.translate_piece_moves_from_bitboard(knight_bits, bitboard_moves, color, self);
+        return self.translate_piece_moves_from_bitboard(square, bitboard_moves);
     }
 
-    fn get_pawn_moves(self, pawn_bits: u64, color: Turn) -> Vec<LegalMove> {
-        let bitboard_moves = Pawn::get_moves(pawn_bits, color, self);
-        return self.translate_piece_moves_from_bitboard(pawn_bits, bitboard_moves, color, self);
-    }
-
-    fn is_enpassant(piece_bits: u64, en_passant_pos: u64) -> bool {
-        return (piece_bits << 1 & en_passant_pos != 0) || (piece_bits >> 1 & en_passant_pos != 0);
+    fn get_pawn_moves(self, square: Square, color: Turn, legal_mask: Bitboard) -> Vec<Move> {
+        let bitboard_moves = Pawn::get_moves(square.bitboard(), color, self) & legal_mask;
+        let ocupancy = self.getOcupancy();
+
+        let mut legal_moves: Vec<Move> = Vec::new();
+        for to in bitboard_moves {
+            let capture = ocupancy.contains(to);
+            if (RANK_1 | RANK_8).contains(to) {
+                //One move per promotion piece, the piece does not change the move legality
+                for promotion in PromotionOptions::ALL {
+                    legal_moves.push(Move::new(
+                        square,
+                        to,
+                        MoveFlag::promotion(promotion, capture),
+                    ));
+                }
+            } else if capture {
+                legal_moves.push(Move::new(square, to, MoveFlag::Capture));
+            } else if square.rank().abs_diff(to.rank()) == 2 {
+                legal_moves.push(Move::new(square, to, MoveFlag::DoublePush));
+            } else {
+                legal_moves.push(Move::new(square, to, MoveFlag::Quiet));
+            }
+        }
+        return legal_moves;
     }
 
     fn get_pawn_moves_enpassant(
         self,
-        pawn_bits: u64,
-        enpassant_bits: u64,
+        square: Square,
+        enpassant_bits: Bitboard,
         color: Turn,
-    ) -> Vec<LegalMove> {
-        let bitboard_moves = Pawn::get_moves_enpassant(pawn_bits, enpassant_bits, color);
-        return self.translate_piece_moves_from_bitboard(pawn_bits, bitboard_moves, color, self);
+    ) -> Vec<Move> {
+        let to = match Pawn::get_moves_enpassant(square.bitboard(), enpassant_bits, color).first() {
+            Some(to) => to,
+            None => return Vec::new(),
+        };
+
+        //Both pawns leave the row at once, so pins and checks are tested on the final occupancy
+        if let Some(king_square) = self.get_king_square(color) {
+            let ocupancy = (self.getOcupancy() & !square.bitboard() & !enpassant_bits).with(to);
+            let enemy = Movement::get_oposite_color(color);
+            let attackers = self.get_attackers(king_square, enemy, ocupancy);
+            if !(attackers & !enpassant_bits).is_empty() {
+                return Vec::new();
+            }
+        }
+        return vec![Move::new(square, to, MoveFlag::EnPassant)];
     }
 
-    fn get_rook_moves(self, rook_bits: u64, color: Turn) -> Vec<LegalMove> {
-        let bitboard_moves = Rook::get_moves(rook_bits, color, self);
+    fn get_rook_moves(self, square: Square, color: Turn, legal_mask: Bitboard) -> Vec<Move> {
+        let bitboard_moves = Rook::get_moves(square.bitboard(), color, self) & legal_mask;
 
-        return self.translate_piece_moves_from_bitboard(rook_bits, bitboard_moves, color, self);
+        return self.translate_piece_moves_from_bitboard(square, bitboard_moves);
     }
 
-    fn get_bishop_moves(self, bishop_bits: u64, color: Turn) -> Vec<LegalMove> {
-        let bitboard_moves = Bishop::get_moves(bishop_bits, color, self);
+    fn get_bishop_moves(self, square: Square, color: Turn, legal_mask: Bitboard) -> Vec<Move> {
+        let bitboard_moves = Bishop::get_moves(square.bitboard(), color, self) & legal_mask;
 
-        return self.translate_piece_moves_from_bitboard(bishop_bits, bitboard_moves, color, self);
+        return self.translate_piece_moves_from_bitboard(square, bitboard_moves);
     }
 
-    fn get_queen_moves(self, queen_bits: u64, color: Turn) -> Vec<LegalMove> {
-        let bitboard_moves = Queen::get_moves(queen_bits, color, self);
+    fn get_queen_moves(self, square: Square, color: Turn, legal_mask: Bitboard) -> Vec<Move> {
+        let bitboard_moves = Queen::get_moves(square.bitboard(), color, self) & legal_mask;
 
-        return self.translate_piece_moves_from_bitboard(queen_bits, bitboard_moves, color, self);
+        return self.translate_piece_moves_from_bitboard(square, bitboard_moves);
     }
 
-    fn get_king_moves(self, king_bits: u64, color: Turn) -> Vec<LegalMove> {
-        let bitboard_moves = King::get_moves(king_bits, color, self);
+    //The king is left out of the occupancy so it can not step back along a checking ray
+    fn get_king_moves(self, square: Square, color: Turn, legal_mask: Bitboard) -> Vec<Move> {
+        let enemy = Movement::get_oposite_color(color);
+        let ocupancy = self.getOcupancy().without(square);
+        let mut bitboard_moves = Bitboard::EMPTY;
+        for target in King::get_moves(square.bitboard(), color, self) & legal_mask {
+            if self.get_attackers(target, enemy, ocupancy).is_empty() {
+                bitboard_moves = bitboard_moves.with(target);
+            }
+        }
 
-        return self.translate_piece_moves_from_bitboard(king_bits, bitboard_moves, color, self);
+        return self.translate_piece_moves_from_bitboard(square, bitboard_moves);
     }
 
     //BITBoard calculations
-    pub fn pub_get_knigth_moves_bitboard(self, knight_bits: u64, color: Turn) -> u64 {
+    pub fn pub_get_knigth_moves_bitboard(self, knight_bits: Bitboard, color: Turn) -> Bitboard {
         return Knight::get_moves(knight_bits, color, self);
     }
 
-    pub fn pub_get_pawn_moves_bitboard(self, pawn_bits: u64, color: Turn) -> u64 {
-        return Pawn::get_moves(pawn_bits, color, self);
+    //Pawn::get_moves looks at a single pawn, so every pawn is asked on its own
+    pub fn pub_get_pawn_moves_bitboard(self, pawn_bits: Bitboard, color: Turn) -> Bitboard {
+        let mut move_bits = Bitboard::EMPTY;
+        for square in pawn_bits {
+            move_bits |= Pawn::get_moves(square.bitboard(), color, self);
+        }
+        return move_bits;
     }
 
-    pub fn pub_is_enpassant_bitboard(self, piece_bits: u64, en_passant_pos: u64) -> bool {
-        return (piece_bits << 1 & en_passant_pos != 0) || (piece_bits >> 1 & en_passant_pos != 0);
+    pub fn pub_is_enpassant_bitboard(self, piece_bits: Bitboard, en_passant_pos: Bitboard) -> bool {
+        return !((piece_bits.east() | piece_bits.west()) & en_passant_pos).is_empty();
     }
 
     pub fn pub_get_pawn_moves_enpassant_bitboard(
         self,
-        pawn_bits: u64,
-        enpassant_bits: u64,
+        pawn_bits: Bitboard,
+        enpassant_bits: Bitboard,
         color: Turn,
-    ) -> u64 {
+    ) -> Bitboard {
         return Pawn::get_moves_enpassant(pawn_bits, enpassant_bits, color);
     }
 
-    pub fn pub_get_rook_moves_bitboard(self, rook_bits: u64, color: Turn) -> u64 {
+    pub fn pub_get_rook_moves_bitboard(self, rook_bits: Bitboard, color: Turn) -> Bitboard {
         return Rook::get_moves(rook_bits, color, self);
     }
 
-    pub fn pub_get_bishop_moves_bitboard(self, bishop_bits: u64, color: Turn) -> u64 {
+    pub fn pub_get_bishop_moves_bitboard(self, bishop_bits: Bitboard, color: Turn) -> Bitboard {
         return Bishop::get_moves(bishop_bits, color, self);
     }
 
-    pub fn pub_get_queen_moves_bitboard(self, queen_bits: u64, color: Turn) -> u64 {
+    pub fn pub_get_queen_moves_bitboard(self, queen_bits: Bitboard, color: Turn) -> Bitboard {
         return Queen::get_moves(queen_bits, color, self);
     }
 
-    pub fn pub_get_king_moves_bitboard(self, king_bits: u64, color: Turn) -> u64 {
+    pub fn pub_get_king_moves_bitboard(self, king_bits: Bitboard, color: Turn) -> Bitboard {
         return King::get_moves(king_bits, color, self);
     }
 }
@@ -0,0 +1,75 @@
+pub struct Chess960 {}
+
+pub const POSITIONS: u16 = 960;
+
+//Knight squares among the five left once bishops and queen are placed
+const KNIGHT_PAIRS: [(usize, usize); 10] = [
+    (0, 1),
+    (0, 2),
+    (0, 3),
+    (0, 4),
+    (1, 2),
+    (1, 3),
+    (1, 4),
+    (2, 3),
+    (2, 4),
+    (3, 4),
+];
+
+impl Chess960 {
+    //White back rank from the a file, numbered as in Scharnagl's table, None past the last one
+    pub fn get_back_rank(number: u16) -> Option<[char; 8]> {
+        if number >= POSITIONS {
+            return None;
+        }
+        let mut number = number as usize;
+        let mut back_rank = [' '; 8];
+
+        //Light squared bishop on b, d, f or h and the dark one on a, c, e or g
+        back_rank[number % 4 * 2 + 1] = 'B';
+        number /= 4;
+        back_rank[number % 4 * 2] = 'B';
+        number /= 4;
+
+        let queen = number % 6;
+        number /= 6;
+        back_rank[Chess960::get_empty_files(&back_rank)[queen]] = 'Q';
+
+        let (first_knight, second_knight) = KNIGHT_PAIRS[number];
+        let empty_files = Chess960::get_empty_files(&back_rank);
+        back_rank[empty_files[first_knight]] = 'N';
+        back_rank[empty_files[second_knight]] = 'N';
+
+        //The king always stands between the rooks
+        for (file, piece) in Chess960::get_empty_files(&back_rank)
+            .into_iter()
+            .zip(['R', 'K', 'R'])
+        {
+            back_rank[file] = piece;
+        }
+        return Some(back_rank);
+    }
+
+    //Rooks are the outermost on each side of the king, so KQkq is valid X-FEN
+    pub fn get_start_fen(number: u16) -> Option<String> {
+        let white: String = Chess960::get_back_rank(number)?.iter().collect();
+        return Some(format!(
+            "{}/pppppppp/8/8/8/8/PPPPPPPP/{} w KQkq - 0 1",
+            white.to_lowercase(),
+            white
+        ));
+    }
+
+    //splitmix64, nearby seeds still give unrelated positions
+    pub fn get_number_from_seed(seed: u64) -> u16 {
+        let mut key = seed.wrapping_add(0x9e3779b97f4a7c15);
+        key = (key ^ (key >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        key = (key ^ (key >> 27)).wrapping_mul(0x94d049bb133111eb);
+        key ^= key >> 31;
+        return (key % POSITIONS as u64) as u16;
+    }
+
+    fn get_empty_files(back_rank: &[char; 8]) -> Vec<usize> {
+        return (0..8).filter(|file| back_rank[*file] == ' ').collect();
+    }
+}
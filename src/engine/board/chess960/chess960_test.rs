@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod chess960_tests {
+    use std::collections::HashSet;
+
+    use crate::engine::board::{
+        board::{Board, Turn},
+        chess960::chess960::{Chess960, POSITIONS},
+        position::position::CastleOptions,
+    };
+
+    //Scharnagl number of the standard setup
+    const STANDARD_POSITION: u16 = 518;
+
+    #[test]
+    fn standard_position_number() {
+        assert_eq!(
+            Chess960::get_start_fen(STANDARD_POSITION).unwrap(),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+        );
+        assert_eq!(
+            Chess960::get_back_rank(0)
+                .unwrap()
+                .iter()
+                .collect::<String>(),
+            "BBQNNRKR"
+        );
+        assert_eq!(
+            Chess960::get_back_rank(959)
+                .unwrap()
+                .iter()
+                .collect::<String>(),
+            "RKRNNQBB"
+        );
+    }
+
+    #[test]
+    fn numbers_past_the_last_position_are_refused() {
+        assert!(Chess960::get_back_rank(POSITIONS).is_none());
+        assert!(Chess960::get_start_fen(POSITIONS).is_none());
+        assert!(Chess960::get_start_fen(u16::MAX).is_none());
+    }
+
+    #[test]
+    fn every_position_is_valid_and_distinct() {
+        let mut back_ranks = HashSet::new();
+        for number in 0..POSITIONS {
+            let back_rank = Chess960::get_back_rank(number).unwrap();
+            let files = |piece: char| -> Vec<usize> {
+                return (0..8).filter(|file| back_rank[*file] == piece).collect();
+            };
+
+            let bishops = files('B');
+            assert_eq!(bishops.len(), 2, "{}", number);
+            assert_ne!(bishops[0] % 2, bishops[1] % 2, "{}", number);
+            let rooks = files('R');
+            let king = files('K');
+            assert_eq!(rooks.len(), 2, "{}", number);
+            assert!(rooks[0] < king[0] && king[0] < rooks[1], "{}", number);
+            assert_eq!(files('Q').len(), 1, "{}", number);
+            assert_eq!(files('N').len(), 2, "{}", number);
+
+            assert!(back_ranks.insert(back_rank), "{}", number);
+        }
+    }
+
+    #[test]
+    fn seeds_are_reproducible() {
+        for seed in 0..100 {
+            let number = Chess960::get_number_from_seed(seed);
+            assert!(number < POSITIONS);
+            assert_eq!(number, Chess960::get_number_from_seed(seed));
+        }
+        assert!(Board::new_960(42) == Board::new_960(42));
+        assert!(Board::new_960(42).chess960);
+    }
+
+    #[test]
+    fn start_positions_keep_both_castling_rooks() {
+        for number in 0..POSITIONS {
+            let fen = Chess960::get_start_fen(number).unwrap();
+            let board = Board::new_from_fenn_notation(&fen);
+            assert_eq!(board.to_fen(), fen);
+
+            let back_rank = Chess960::get_back_rank(number).unwrap();
+            for color in [Turn::White, Turn::Black] {
+                for castle in [CastleOptions::KingSide, CastleOptions::QueenSide] {
+                    let rook_square = board.get_castle_rook_square(color, castle).unwrap();
+                    assert_eq!(back_rank[rook_square.file() as usize], 'R', "{}", fen);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod chess960;
+mod chess960_test;
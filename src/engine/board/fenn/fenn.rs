@@ -1,11 +1,230 @@
-use crate::engine::board::board::{Board, Turn};
+use std::fmt;
+
+use crate::engine::{
+    board::{
+        board::{Board, PieceType, Turn},
+        position::position::{CastleOptions, Position},
+        square::square::Square,
+        validation::validation::PositionIssue,
+        zobrist::zobrist::Zobrist,
+    },
+    movement::movement::{ROW_3, ROW_6},
+};
 
 const COLUMNS: [&'static str; 8] = ["a", "b", "c", "d", "e", "f", "g", "h"];
 pub struct FEN {}
 
+#[derive(Debug, PartialEq)]
+pub enum FenError {
+    WrongFieldCount(usize),
+    WrongRankCount(usize),
+    BadRankLength { rank: usize, length: usize },
+    InvalidPieceChar(char),
+    InvalidSideToMove(String),
+    BadCastlingField(String),
+    ImpossibleEnPassantSquare(String),
+    InvalidClock(String),
+    MissingKing(Turn),
+    DuplicateKing(Turn),
+    InvalidPosition(Vec<PositionIssue>),
+}
+
+impl fmt::Display for FenError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FenError::WrongFieldCount(count) => {
+                write!(fmt, "expected 6 fields, found {}", count)
+            }
+            FenError::WrongRankCount(count) => write!(fmt, "expected 8 ranks, found {}", count),
+            FenError::BadRankLength { rank, length } => {
+                write!(fmt, "rank {} has {} squares", rank, length)
+            }
+            FenError::InvalidPieceChar(piece) => write!(fmt, "invalid piece '{}'", piece),
+            FenError::InvalidSideToMove(player) => write!(fmt, "invalid side to move '{}'", player),
+            FenError::BadCastlingField(castles) => {
+                write!(fmt, "invalid castling field '{}'", castles)
+            }
+            FenError::ImpossibleEnPassantSquare(square) => {
+                write!(fmt, "impossible en passant square '{}'", square)
+            }
+            FenError::InvalidClock(clock) => write!(fmt, "invalid move clock '{}'", clock),
+            FenError::MissingKing(color) => write!(fmt, "missing {} king", color),
+            FenError::DuplicateKing(color) => write!(fmt, "more than one {} king", color),
+            FenError::InvalidPosition(issues) => {
+                let issues: Vec<String> = issues.iter().map(|issue| issue.to_string()).collect();
+                write!(fmt, "invalid position: {}", issues.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for FenError {}
+
 impl FEN {
-    pub fn get_board_from_fenn_str(fen_str: &'static str) -> Board {
-        let fenn_split = fen_str.split(" ").collect::<Vec<&str>>();
+    //X-FEN, castles are KQkq unless an inner rook holds the right
+    pub fn get_fenn_str_from_board(board: &Board) -> String {
+        return FEN::get_fenn_str(board, false);
+    }
+
+    //Shredder-FEN, castles are always written as rook files (HAha)
+    pub fn get_shredder_fenn_str_from_board(board: &Board) -> String {
+        return FEN::get_fenn_str(board, true);
+    }
+
+    fn get_fenn_str(board: &Board, shredder: bool) -> String {
+        let mut placement = String::new();
+        for row in (0..8).rev() {
+            let mut empty_squares = 0;
+            for column in (0..8).rev() {
+                let square = Square::from_index(row * 8 + column);
+                match FEN::get_piece_char_at(board, square) {
+                    Some(piece) => {
+                        if empty_squares > 0 {
+                            placement.push_str(&empty_squares.to_string());
+                            empty_squares = 0;
+                        }
+                        placement.push(piece);
+                    }
+                    None => empty_squares += 1,
+                }
+            }
+            if empty_squares > 0 {
+                placement.push_str(&empty_squares.to_string());
+            }
+            if row > 0 {
+                placement.push('/');
+            }
+        }
+
+        let player = match board.get_turn() {
+            Turn::White => "w",
+            Turn::Black => "b",
+        };
+
+        let mut castles = String::new();
+        for (color, castle) in [
+            (Turn::White, CastleOptions::KingSide),
+            (Turn::White, CastleOptions::QueenSide),
+            (Turn::Black, CastleOptions::KingSide),
+            (Turn::Black, CastleOptions::QueenSide),
+        ] {
+            let rook_square = match board.get_castle_rook_square(color, castle) {
+                Some(rook_square) => rook_square,
+                None => continue,
+            };
+            let castle_char = if shredder || !FEN::is_outermost_rook(board, color, rook_square) {
+                COLUMNS[rook_square.file() as usize].chars().next().unwrap()
+            } else if castle == CastleOptions::KingSide {
+                'k'
+            } else {
+                'q'
+            };
+            match color {
+                Turn::White => castles.push(castle_char.to_ascii_uppercase()),
+                Turn::Black => castles.push(castle_char),
+            }
+        }
+        if castles.is_empty() {
+            castles.push('-');
+        }
+
+        //The board keeps the pawn square, FEN the square behind it
+        let en_passant_square =
+            match (board.w_en_passant.south() | board.b_en_passant.north()).first() {
+                Some(square) => square.to_string(),
+                None => String::from("-"),
+            };
+
+        return format!(
+            "{} {} {} {} {} {}",
+            placement,
+            player,
+            castles,
+            en_passant_square,
+            board.half_move_count,
+            board.full_move_count
+        );
+    }
+
+    //No rook of the same colour between it and the corner, so K or Q names it
+    fn is_outermost_rook(board: &Board, color: Turn, rook_square: Square) -> bool {
+        let rook = match color {
+            Turn::White => PieceType::WhiteRook,
+            Turn::Black => PieceType::BlackRook,
+        };
+        let outer_files = match board.get_king_square(color) {
+            Some(king_square) if king_square.file() > rook_square.file() => 0..rook_square.file(),
+            _ => rook_square.file() + 1..8,
+        };
+        return outer_files
+            .into_iter()
+            .all(|file| board.piece_at(Square::new(file, rook_square.rank())) != Some(rook));
+    }
+
+    //K and Q pick the outermost rook, a file letter names the rook directly (Shredder-FEN)
+    fn get_castle_rook_file(
+        board: &Board,
+        color: Turn,
+        castle_value: char,
+    ) -> Option<(CastleOptions, u8)> {
+        let (rank, rook) = match color {
+            Turn::White => (0, PieceType::WhiteRook),
+            Turn::Black => (7, PieceType::BlackRook),
+        };
+        let king_file = board
+            .get_king_square(color)
+            .filter(|king_square| king_square.rank() == rank)
+            .map(|king_square| king_square.file());
+        let is_rook = |file: &u8| board.piece_at(Square::new(*file, rank)) == Some(rook);
+
+        match (castle_value.to_ascii_lowercase(), king_file) {
+            //Without a king at home the right can never be used, keep the standard rook
+            ('k', None) => return Some((CastleOptions::KingSide, 7)),
+            ('q', None) => return Some((CastleOptions::QueenSide, 0)),
+            ('k', Some(king_file)) => {
+                let file = (king_file + 1..8).rev().find(is_rook).unwrap_or(7);
+                return Some((CastleOptions::KingSide, file));
+            }
+            ('q', Some(king_file)) => {
+                let file = (0..king_file).find(is_rook).unwrap_or(0);
+                return Some((CastleOptions::QueenSide, file));
+            }
+            (file_char @ 'a'..='h', Some(king_file)) => {
+                let file = COLUMNS.iter().position(|x| x.starts_with(file_char))? as u8;
+                if !is_rook(&file) || file == king_file {
+                    return None;
+                }
+                if file > king_file {
+                    return Some((CastleOptions::KingSide, file));
+                }
+                return Some((CastleOptions::QueenSide, file));
+            }
+            _ => return None,
+        }
+    }
+
+    fn get_piece_char_at(board: &Board, square: Square) -> Option<char> {
+        match board.piece_at(square)? {
+            PieceType::WhitePawn | PieceType::WhitePawnEnPassant => return Some('P'),
+            PieceType::WhiteKnight => return Some('N'),
+            PieceType::WhiteBishop => return Some('B'),
+            PieceType::WhiteRook => return Some('R'),
+            PieceType::WhiteQueen => return Some('Q'),
+            PieceType::WhiteKing => return Some('K'),
+            PieceType::BlackPawn | PieceType::BlackPawnEnPassant => return Some('p'),
+            PieceType::BlackKnight => return Some('n'),
+            PieceType::BlackBishop => return Some('b'),
+            PieceType::BlackRook => return Some('r'),
+            PieceType::BlackQueen => return Some('q'),
+            PieceType::BlackKing => return Some('k'),
+        }
+    }
+
+    pub fn get_board_from_fenn_str(fen_str: &str) -> Result<Board, FenError> {
+        let fenn_split = fen_str.split_whitespace().collect::<Vec<&str>>();
+        if fenn_split.len() != 6 {
+            return Err(FenError::WrongFieldCount(fenn_split.len()));
+        }
         let board_str = fenn_split[0];
         let player = fenn_split[1];
         let castles = fenn_split[2];
@@ -13,153 +232,171 @@ impl FEN {
         let half_move_clock = fenn_split[4];
         let full_move_clock = fenn_split[5];
 
-        //Turn
         let mut board = Board::new_empty();
+
+        //Pieces, FEN starts at rank 8 and column a
+        let ranks = board_str.split("/").collect::<Vec<&str>>();
+        if ranks.len() != 8 {
+            return Err(FenError::WrongRankCount(ranks.len()));
+        }
+        for (rank_index, rank) in ranks.iter().enumerate() {
+            let row = 7 - rank_index;
+            let mut column = 0;
+            for piece in rank.chars() {
+                if let Some(empty_squares) = piece.to_digit(10) {
+                    if empty_squares == 0 || empty_squares > 8 {
+                        return Err(FenError::InvalidPieceChar(piece));
+                    }
+                    column += empty_squares as usize;
+                    continue;
+                }
+                if column >= 8 {
+                    return Err(FenError::BadRankLength {
+                        rank: row + 1,
+                        length: column + 1,
+                    });
+                }
+                let piece_type = match piece {
+                    'r' => PieceType::BlackRook,
+                    'n' => PieceType::BlackKnight,
+                    'b' => PieceType::BlackBishop,
+                    'q' => PieceType::BlackQueen,
+                    'k' => PieceType::BlackKing,
+                    'p' => PieceType::BlackPawn,
+                    'R' => PieceType::WhiteRook,
+                    'N' => PieceType::WhiteKnight,
+                    'B' => PieceType::WhiteBishop,
+                    'Q' => PieceType::WhiteQueen,
+                    'K' => PieceType::WhiteKing,
+                    'P' => PieceType::WhitePawn,
+                    _ => return Err(FenError::InvalidPieceChar(piece)),
+                };
+                board.put_piece(piece_type, Square::new(column as u8, row as u8));
+                column += 1;
+            }
+            if column != 8 {
+                return Err(FenError::BadRankLength {
+                    rank: row + 1,
+                    length: column,
+                });
+            }
+        }
+
+        match board.w_king.count() {
+            0 => return Err(FenError::MissingKing(Turn::White)),
+            1 => {}
+            _ => return Err(FenError::DuplicateKing(Turn::White)),
+        }
+        match board.b_king.count() {
+            0 => return Err(FenError::MissingKing(Turn::Black)),
+            1 => {}
+            _ => return Err(FenError::DuplicateKing(Turn::Black)),
+        }
+
+        //Turn
         match player {
             "w" => board.set_turn(Turn::White),
             "b" => board.set_turn(Turn::Black),
-            _ => {}
+            _ => return Err(FenError::InvalidSideToMove(player.to_string())),
         }
 
-        //Castling
-        let castles_values = castles.trim().split("").collect::<Vec<&str>>();
+        //Castling, the board flags are set once the right is lost
         board.has_w_king_side_castle = true;
         board.has_w_queen_side_castle = true;
         board.has_b_king_side_castle = true;
         board.has_b_queen_side_castle = true;
-        for castle_value in castles_values {
-            match castle_value {
-                "K" => board.has_w_king_side_castle = false,
-                "Q" => board.has_w_queen_side_castle = false,
-                "k" => board.has_b_king_side_castle = false,
-                "q" => board.has_b_queen_side_castle = false,
-                _ => {}
+        if castles != "-" {
+            for castle_value in castles.chars() {
+                let color = match castle_value.is_ascii_uppercase() {
+                    true => Turn::White,
+                    false => Turn::Black,
+                };
+                let (castle, file) = match FEN::get_castle_rook_file(&board, color, castle_value) {
+                    Some(castle_rook) => castle_rook,
+                    None => return Err(FenError::BadCastlingField(castles.to_string())),
+                };
+                let (castle_flag, rook_file) = match (color, castle) {
+                    (Turn::White, CastleOptions::KingSide) => (
+                        &mut board.has_w_king_side_castle,
+                        &mut board.w_king_side_rook_file,
+                    ),
+                    (Turn::White, _) => (
+                        &mut board.has_w_queen_side_castle,
+                        &mut board.w_queen_side_rook_file,
+                    ),
+                    (Turn::Black, CastleOptions::KingSide) => (
+                        &mut board.has_b_king_side_castle,
+                        &mut board.b_king_side_rook_file,
+                    ),
+                    (Turn::Black, _) => (
+                        &mut board.has_b_queen_side_castle,
+                        &mut board.b_queen_side_rook_file,
+                    ),
+                };
+                if !*castle_flag {
+                    return Err(FenError::BadCastlingField(castles.to_string()));
+                }
+                *castle_flag = false;
+                *rook_file = file;
             }
         }
 
-        //en passant
-        let en_passant_values = en_passasnt_square.trim().split("").collect::<Vec<&str>>();
-        let mut en_passat_column = -1;
-
-        let mut en_passant_row = -1;
-
-        'enpassant_loop: for e in en_passant_values {
-            match e {
-                "a" => en_passat_column = 7,
-                "b" => en_passat_column = 6,
-                "c" => en_passat_column = 5,
-                "d" => en_passat_column = 4,
-                "e" => en_passat_column = 3,
-                "f" => en_passat_column = 2,
-                "g" => en_passat_column = 1,
-                "h" => en_passat_column = 0,
-                "1" => en_passant_row = 0,
-                "2" => en_passant_row = 1,
-                "3" => en_passant_row = 2,
-                "4" => en_passant_row = 3,
-                "5" => en_passant_row = 4,
-                "6" => en_passant_row = 5,
-                "7" => en_passant_row = 6,
-                "8" => en_passant_row = 7,
-                "-" => {
-                    break 'enpassant_loop;
-                }
-                _ => {}
-            }
+        if board.has_chess960_castling() {
+            board.chess960 = true;
         }
 
-        let en_passant_pos = en_passat_column + en_passant_row * 8;
+        //en passant
+        if en_passasnt_square != "-" {
+            let en_passant_pos = match Position::from_algebraic(en_passasnt_square) {
+                Some(position) => position,
+                None => {
+                    return Err(FenError::ImpossibleEnPassantSquare(
+                        en_passasnt_square.to_string(),
+                    ))
+                }
+            };
+            let square = en_passant_pos.to_bitboard();
+            let ocupancy = board.getOcupancy();
 
-        if en_passat_column > -1 && en_passant_row > -1 {
-            if en_passant_pos <= 31 {
-                board.w_en_passant = 1 << en_passant_pos
-            } else {
-                board.b_en_passant = 1 << en_passant_pos
+            //The board keeps the square of the pawn that did the double push
+            let is_possible = match board.get_turn() {
+                Turn::Black => {
+                    en_passant_pos.row == ROW_3
+                        && !(board.w_pawns & square.north()).is_empty()
+                        && (ocupancy & (square | square.south())).is_empty()
+                }
+                Turn::White => {
+                    en_passant_pos.row == ROW_6
+                        && !(board.b_pawns & square.south()).is_empty()
+                        && (ocupancy & (square | square.north())).is_empty()
+                }
+            };
+            if !is_possible {
+                return Err(FenError::ImpossibleEnPassantSquare(
+                    en_passasnt_square.to_string(),
+                ));
+            }
+            match board.get_turn() {
+                Turn::Black => board.w_en_passant = square.north(),
+                Turn::White => board.b_en_passant = square.south(),
             }
         }
 
         //Moves count
-        board.half_move_count = half_move_clock.parse().unwrap_or(0);
-        board.full_move_count = full_move_clock.parse().unwrap_or(0);
-
-        let mut column = 0;
-        let mut row = 0;
-
-        for rank in board_str.split("/").collect::<Vec<&str>>().iter().rev() {
-            for this_rank in rank.split("").collect::<Vec<&str>>().iter().rev() {
-                let shifted_column = column;
-
-                let shifted_row = row * 8;
-                let mut value_displacement = shifted_column + shifted_row;
+        board.half_move_count = match half_move_clock.parse() {
+            Ok(count) => count,
+            Err(_) => return Err(FenError::InvalidClock(half_move_clock.to_string())),
+        };
+        board.full_move_count = match full_move_clock.parse() {
+            Ok(count) => count,
+            Err(_) => return Err(FenError::InvalidClock(full_move_clock.to_string())),
+        };
 
-                if value_displacement / 8 != row {
-                    value_displacement -= 1;
-                }
-
-                match *this_rank {
-                    "" => {}
-                    "1" => column += 1,
-                    "2" => column += 2,
-                    "3" => column += 3,
-                    "4" => column += 4,
-                    "5" => column += 5,
-                    "6" => column += 6,
-                    "7" => column += 7,
-                    "8" => {}
-                    "r" => {
-                        board.b_rooks |= (1 as u64) << value_displacement;
-                        column += 1;
-                    }
-                    "n" => {
-                        board.b_knights |= (1 as u64) << value_displacement;
-                        column += 1;
-                    }
-                    "b" => {
-                        board.b_bishops |= (1 as u64) << value_displacement;
-                        column += 1;
-                    }
-                    "q" => {
-                        board.b_queen |= (1 as u64) << value_displacement;
-                        column += 1;
-                    }
-                    "k" => {
-                        board.b_king |= (1 as u64) << value_displacement;
-                        column += 1;
-                    }
-                    "p" => {
-                        board.b_pawns |= (1 as u64) << value_displacement;
-                        column += 1;
-                    }
-                    "R" => {
-                        board.w_rooks |= (1 as u64) << value_displacement;
-                        column += 1;
-                    }
-                    "N" => {
-                        board.w_knights |= (1 as u64) << value_displacement;
-                        column += 1;
-                    }
-                    "B" => {
-                        board.w_bishops |= (1 as u64) << value_displacement;
-                        column += 1;
-                    }
-                    "Q" => {
-                        board.w_queen |= (1 as u64) << value_displacement;
-                        column += 1;
-                    }
-                    "K" => {
-                        board.w_king |= (1 as u64) << value_displacement;
-                        column += 1;
-                    }
-                    "P" => {
-                        board.w_pawns |= (1 as u64) << value_displacement;
-                        column += 1;
-                    }
-                    _ => {}
-                }
-            }
-            column = 0;
-            row += 1;
+        if let Err(issues) = board.validate() {
+            return Err(FenError::InvalidPosition(issues));
         }
-        return board;
+
+        board.zobrist_key = Zobrist::get_key(&board);
+        return Ok(board);
     }
 }
@@ -0,0 +1,270 @@
+#[cfg(test)]
+mod fenn_tests {
+    use crate::engine::{
+        board::{
+            board::{Board, Turn},
+            fenn::fenn::FenError,
+            moves::moves::Move,
+            position::position::CastleOptions,
+        },
+        movement::movement::Movement,
+    };
+
+    const FEN_CORPUS: [&str; 12] = [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1",
+        "rnbqkbnr/pp1ppppp/8/2p5/4P3/8/PPPP1PPP/RNBQKBNR w KQkq c6 0 2",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10",
+        "8/8/1k6/2b5/2pP4/8/5K2/8 b - d3 0 1",
+        "5k2/8/8/8/8/8/8/4K2R w K - 0 1",
+        "3k4/8/8/8/8/8/8/R3K3 w Q - 12 57",
+        "4k3/8/8/8/8/8/8/4K3 b - - 99 120",
+    ];
+
+    fn assert_same_position(board: &Board, parsed: &Board) {
+        assert_eq!(board.w_pawns, parsed.w_pawns);
+        assert_eq!(board.w_knights, parsed.w_knights);
+        assert_eq!(board.w_bishops, parsed.w_bishops);
+        assert_eq!(board.w_rooks, parsed.w_rooks);
+        assert_eq!(board.w_queen, parsed.w_queen);
+        assert_eq!(board.w_king, parsed.w_king);
+        assert_eq!(board.b_pawns, parsed.b_pawns);
+        assert_eq!(board.b_knights, parsed.b_knights);
+        assert_eq!(board.b_bishops, parsed.b_bishops);
+        assert_eq!(board.b_rooks, parsed.b_rooks);
+        assert_eq!(board.b_queen, parsed.b_queen);
+        assert_eq!(board.b_king, parsed.b_king);
+        assert_eq!(board.w_en_passant, parsed.w_en_passant);
+        assert_eq!(board.b_en_passant, parsed.b_en_passant);
+        assert!(board.get_turn() == parsed.get_turn());
+        assert_eq!(board.half_move_count, parsed.half_move_count);
+        assert_eq!(board.full_move_count, parsed.full_move_count);
+        assert_eq!(board.to_fen(), parsed.to_fen());
+    }
+
+    #[test]
+    fn to_fen_start_position() {
+        assert_eq!(
+            Board::new().to_fen(),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+        );
+    }
+
+    #[test]
+    fn to_fen_corpus_is_unchanged() {
+        for fen in FEN_CORPUS {
+            assert_eq!(Board::new_from_fenn_notation(fen).to_fen(), fen);
+        }
+    }
+
+    #[test]
+    fn to_fen_after_moves() {
+        let mut board = Board::new();
+        for notation in ["e2e4", "c7c5", "g1f3"] {
+            let turn = board.get_turn();
+            let legal_move = Move::from_long_algebraic(notation, &board, turn).unwrap();
+            board.do_move(legal_move, turn);
+            board.set_turn(Movement::get_oposite_color(turn));
+        }
+        assert_eq!(
+            board.to_fen(),
+            "rnbqkbnr/pp1ppppp/8/2p5/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2"
+        );
+    }
+
+    //Walks pseudo random games from every corpus position checking parse(to_fen(board)) == board
+    #[test]
+    fn round_trip_random_games() {
+        let mut seed: u64 = 0x2545f4914f6cdd1d;
+        for fen in FEN_CORPUS {
+            for _ in 0..4 {
+                let mut board = Board::new_from_fenn_notation(fen);
+                for _ in 0..30 {
+                    let parsed = Board::new_from_fenn_notation(&board.to_fen());
+                    assert_same_position(&board, &parsed);
+
+                    let turn = board.get_turn();
+                    let legal_moves = board.get_moves(turn);
+                    if legal_moves.is_empty() {
+                        break;
+                    }
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    board.do_move(
+                        legal_moves[(seed % legal_moves.len() as u64) as usize],
+                        turn,
+                    );
+                    board.set_turn(Movement::get_oposite_color(turn));
+                }
+            }
+        }
+    }
+
+    fn fen_error(fen: &str) -> FenError {
+        match Board::from_fen(fen) {
+            Ok(_) => panic!("{} should not parse", fen),
+            Err(error) => error,
+        }
+    }
+
+    #[test]
+    fn from_fen_accepts_corpus() {
+        for fen in FEN_CORPUS {
+            assert!(Board::from_fen(fen).is_ok(), "{}", fen);
+        }
+    }
+
+    #[test]
+    fn from_fen_wrong_field_count() {
+        assert_eq!(fen_error(""), FenError::WrongFieldCount(0));
+        assert_eq!(
+            fen_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w"),
+            FenError::WrongFieldCount(2)
+        );
+        assert_eq!(
+            fen_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 1"),
+            FenError::WrongFieldCount(7)
+        );
+    }
+
+    #[test]
+    fn from_fen_bad_ranks() {
+        assert_eq!(
+            fen_error("rnbqkbnr/pppppppp/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"),
+            FenError::WrongRankCount(7)
+        );
+        assert_eq!(
+            fen_error("rnbqkbnr/ppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"),
+            FenError::BadRankLength { rank: 7, length: 7 }
+        );
+        assert_eq!(
+            fen_error("rnbqkbnr/pppppppp/8/8/4P4/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"),
+            FenError::BadRankLength { rank: 4, length: 9 }
+        );
+    }
+
+    #[test]
+    fn from_fen_invalid_piece_char() {
+        assert_eq!(
+            fen_error("rnbqkbnr/pppppppp/8/8/8/3X4/PPPPPPPP/RNBQKBNR w KQkq - 0 1"),
+            FenError::InvalidPieceChar('X')
+        );
+        assert_eq!(
+            fen_error("rnbqkbnr/pppppppp/8/8/8/09/PPPPPPPP/RNBQKBNR w KQkq - 0 1"),
+            FenError::InvalidPieceChar('0')
+        );
+    }
+
+    #[test]
+    fn from_fen_invalid_side_to_move() {
+        assert_eq!(
+            fen_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR x KQkq - 0 1"),
+            FenError::InvalidSideToMove(String::from("x"))
+        );
+    }
+
+    #[test]
+    fn from_fen_bad_castling_field() {
+        assert_eq!(
+            fen_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQxq - 0 1"),
+            FenError::BadCastlingField(String::from("KQxq"))
+        );
+        assert_eq!(
+            fen_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KK - 0 1"),
+            FenError::BadCastlingField(String::from("KK"))
+        );
+    }
+
+    #[test]
+    fn from_fen_impossible_en_passant_square() {
+        //Wrong rank for the side to move
+        assert_eq!(
+            fen_error("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e3 0 1"),
+            FenError::ImpossibleEnPassantSquare(String::from("e3"))
+        );
+        //No pawn in front of the square
+        assert_eq!(
+            fen_error("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq d3 0 1"),
+            FenError::ImpossibleEnPassantSquare(String::from("d3"))
+        );
+        assert_eq!(
+            fen_error("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e9 0 1"),
+            FenError::ImpossibleEnPassantSquare(String::from("e9"))
+        );
+    }
+
+    #[test]
+    fn from_fen_invalid_clocks() {
+        assert_eq!(
+            fen_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - x 1"),
+            FenError::InvalidClock(String::from("x"))
+        );
+        assert_eq!(
+            fen_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 -1a"),
+            FenError::InvalidClock(String::from("-1a"))
+        );
+    }
+
+    #[test]
+    fn from_fen_kings() {
+        assert_eq!(
+            fen_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQ1BNR w kq - 0 1"),
+            FenError::MissingKing(Turn::White)
+        );
+        assert_eq!(
+            fen_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNk w KQkq - 0 1"),
+            FenError::DuplicateKing(Turn::Black)
+        );
+    }
+
+    #[test]
+    fn shredder_fen_round_trip() {
+        let board = Board::new();
+        assert_eq!(
+            board.to_shredder_fen(),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w HAha - 0 1"
+        );
+        assert!(!board.chess960);
+
+        let fen = "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9";
+        let board = Board::from_fen(fen).unwrap();
+        assert!(board.chess960);
+        assert_eq!(board.to_shredder_fen(), fen);
+        assert_eq!(
+            board.to_fen(),
+            "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w KQkq - 2 9"
+        );
+        assert!(Board::from_fen(&board.to_fen()).unwrap() == board);
+    }
+
+    #[test]
+    fn x_fen_names_inner_rooks_by_file() {
+        let fen = "4k3/8/8/8/8/8/8/RR2K3 w B - 0 1";
+        let board = Board::from_fen(fen).unwrap();
+        assert!(board.chess960);
+        assert_eq!(board.to_fen(), fen);
+        assert_eq!(
+            board.get_castle_rook_square(Turn::White, CastleOptions::QueenSide),
+            Some("b1".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn from_fen_bad_chess960_castling_field() {
+        //No rook on the named file
+        assert_eq!(
+            fen_error("4k3/8/8/8/8/8/8/R3K3 w B - 0 1"),
+            FenError::BadCastlingField(String::from("B"))
+        );
+        //The king's own file
+        assert_eq!(
+            fen_error("4k3/8/8/8/8/8/8/R3K3 w E - 0 1"),
+            FenError::BadCastlingField(String::from("E"))
+        );
+    }
+}
@@ -1 +1,2 @@
 pub mod fenn;
+mod fenn_test;
@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod legal_moves_tests {
+    use crate::engine::board::board::Board;
+
+    fn get_moves(fen: &str) -> Vec<String> {
+        let board = Board::new_from_fenn_notation(fen);
+        let turn = board.get_turn();
+        let mut moves: Vec<String> = board
+            .get_moves(turn)
+            .iter()
+            .map(|legal_move| legal_move.to_long_algebraic())
+            .collect();
+        moves.sort();
+        return moves;
+    }
+
+    #[test]
+    fn double_check_only_moves_the_king() {
+        //Rook and knight both check, taking the knight with the rook is not enough
+        assert_eq!(
+            get_moves("4r2k/8/8/8/8/R2n4/8/4K3 w - - 0 1"),
+            vec!["e1d1", "e1d2", "e1f1"]
+        );
+    }
+
+    #[test]
+    fn single_check_is_blocked_or_captured() {
+        assert_eq!(
+            get_moves("4r2k/8/8/8/8/8/R7/4K3 w - - 0 1"),
+            vec!["a2e2", "e1d1", "e1d2", "e1f1", "e1f2"]
+        );
+        assert_eq!(
+            get_moves("3r3k/8/8/8/8/8/8/R2K4 w - - 0 1"),
+            vec!["d1c1", "d1c2", "d1e1", "d1e2"]
+        );
+    }
+
+    #[test]
+    fn king_can_not_step_along_the_checking_ray() {
+        assert_eq!(
+            get_moves("4r2k/8/8/8/8/8/8/4K3 w - - 0 1"),
+            vec!["e1d1", "e1d2", "e1f1", "e1f2"]
+        );
+        assert_eq!(
+            get_moves("7k/8/b7/8/8/8/4K3/8 w - - 0 1"),
+            vec!["e2d1", "e2d2", "e2e1", "e2e3", "e2f2", "e2f3"]
+        );
+    }
+
+    #[test]
+    fn pinned_pieces_stay_on_the_pin_ray() {
+        assert_eq!(
+            get_moves("4r2k/8/8/8/8/8/4R3/4K3 w - - 0 1"),
+            vec!["e1d1", "e1d2", "e1f1", "e1f2", "e2e3", "e2e4", "e2e5", "e2e6", "e2e7", "e2e8"]
+        );
+        assert_eq!(
+            get_moves("7k/8/8/b7/8/8/3N4/4K3 w - - 0 1"),
+            vec!["e1d1", "e1e2", "e1f1", "e1f2"]
+        );
+    }
+
+    #[test]
+    fn en_passant_can_not_uncover_the_king() {
+        let moves = get_moves("8/8/8/KPp4r/8/8/8/7k w - c6 0 1");
+        assert!(!moves.contains(&"b5c6".to_string()));
+        assert!(moves.contains(&"b5b6".to_string()));
+
+        let moves = get_moves("8/8/8/KPp5/8/8/8/7k w - c6 0 1");
+        assert!(moves.contains(&"b5c6".to_string()));
+    }
+
+    #[test]
+    fn en_passant_captures_the_checker() {
+        let moves = get_moves("8/8/8/3pP3/4K3/8/8/7k w - d6 0 1");
+        assert!(moves.contains(&"e5d6".to_string()));
+        assert!(!moves.contains(&"e5e6".to_string()));
+    }
+
+    #[test]
+    fn generated_moves_never_leave_the_king_in_check() {
+        let mut seed: u64 = 0x2545f4914f6cdd1d;
+        let fens = [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        ];
+        for fen in fens {
+            for _ in 0..4 {
+                let mut board = Board::new_from_fenn_notation(fen);
+                for _ in 0..60 {
+                    let turn = board.get_turn();
+                    let legal_moves = board.get_moves(turn);
+                    if legal_moves.is_empty() {
+                        break;
+                    }
+                    for legal_move in legal_moves.to_owned() {
+                        let undo = board.make_move(legal_move, turn);
+                        assert!(!board.is_in_check(turn), "{}", board.to_fen());
+                        board.unmake_move(undo);
+                    }
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    board.make_move(
+                        legal_moves[(seed % legal_moves.len() as u64) as usize],
+                        turn,
+                    );
+                }
+            }
+        }
+    }
+
+    fn get_castles(fen: &str) -> Vec<String> {
+        let board = Board::new_from_fenn_notation(fen);
+        let turn = board.get_turn();
+        let mut moves: Vec<String> = board
+            .get_moves(turn)
+            .iter()
+            .filter(|legal_move| legal_move.is_castle())
+            .map(|legal_move| legal_move.to_uci(true))
+            .collect();
+        moves.sort();
+        return moves;
+    }
+
+    #[test]
+    fn chess960_castles() {
+        assert_eq!(
+            get_castles("1r4kr/8/8/8/8/8/8/1R4KR w HBhb - 0 1"),
+            vec!["g1b1", "g1h1"]
+        );
+        //The other rook stands on the castling rook's target square
+        assert_eq!(
+            get_castles("4k3/8/8/8/8/8/8/5RKR w H - 0 1"),
+            Vec::<String>::new()
+        );
+        //Castling would lift the rook that shields the king from a1
+        assert_eq!(
+            get_castles("4k3/8/8/8/8/8/8/rRK5 w B - 0 1"),
+            Vec::<String>::new()
+        );
+        //The king may not pass an attacked square even if it starts next to its target
+        assert_eq!(
+            get_castles("4kr2/8/8/8/8/8/8/1KR5 w C - 0 1"),
+            Vec::<String>::new()
+        );
+        assert_eq!(get_castles("4k3/8/8/8/8/8/8/1KR5 w C - 0 1"), vec!["b1c1"]);
+    }
+}
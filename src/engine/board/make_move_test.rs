@@ -0,0 +1,200 @@
+#[cfg(test)]
+mod make_move_tests {
+    use crate::engine::{
+        board::{
+            board::{Board, PieceType},
+            moves::moves::Move,
+            square::square::Square,
+        },
+        movement::movement::Movement,
+    };
+
+    const POSITIONS: [&str; 9] = [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        "8/8/1k6/2b5/2pP4/8/5K2/8 b - d3 0 1",
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+        "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9",
+        "1r4kr/8/8/8/8/8/8/1R4KR w HBhb - 0 1",
+    ];
+
+    fn assert_make_unmake(board: &Board, legal_move: Move) {
+        let turn = board.get_turn();
+
+        let mut played = *board;
+        played.do_move(legal_move, turn);
+        played.set_turn(Movement::get_oposite_color(turn));
+
+        let mut made = *board;
+        let undo = made.make_move(legal_move, turn);
+        assert!(
+            made == played,
+            "{} {}",
+            board.to_fen(),
+            legal_move.to_long_algebraic()
+        );
+
+        made.unmake_move(undo);
+        assert!(
+            made == *board,
+            "{} {}",
+            board.to_fen(),
+            legal_move.to_long_algebraic()
+        );
+    }
+
+    #[test]
+    fn unmake_restores_every_move() {
+        for fen in POSITIONS {
+            let board = Board::new_from_fenn_notation(fen);
+            for legal_move in board.get_moves(board.get_turn()) {
+                assert_make_unmake(&board, legal_move);
+            }
+        }
+    }
+
+    #[test]
+    fn unmake_restores_random_games() {
+        let mut seed: u64 = 0x9e3779b97f4a7c15;
+        for fen in POSITIONS {
+            for _ in 0..4 {
+                let mut board = Board::new_from_fenn_notation(fen);
+                for _ in 0..40 {
+                    let turn = board.get_turn();
+                    let legal_moves = board.get_moves(turn);
+                    if legal_moves.is_empty() {
+                        break;
+                    }
+                    for legal_move in legal_moves.to_owned() {
+                        assert_make_unmake(&board, legal_move);
+                    }
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    board.make_move(
+                        legal_moves[(seed % legal_moves.len() as u64) as usize],
+                        turn,
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unmake_stack_restores_start() {
+        let start = Board::new();
+        let mut board = start;
+        let mut undo_stack = Vec::new();
+        for notation in [
+            "e2e4", "d7d5", "e4d5", "d8d5", "b1c3", "d5a5", "g1f3", "c8g4",
+        ] {
+            let turn = board.get_turn();
+            let legal_move = Move::from_long_algebraic(notation, &board, turn).unwrap();
+            undo_stack.push(board.make_move(legal_move, turn));
+        }
+        assert_eq!(
+            board.to_fen(),
+            "rn2kbnr/ppp1pppp/8/q7/6b1/2N2N2/PPPP1PPP/R1BQKB1R w KQkq - 4 5"
+        );
+
+        while let Some(undo) = undo_stack.pop() {
+            board.unmake_move(undo);
+        }
+        assert!(board == start);
+    }
+
+    fn get_square(name: &str) -> Square {
+        return name.parse().unwrap();
+    }
+
+    fn play(board: &mut Board, notation: &str) {
+        let turn = board.get_turn();
+        let legal_move = Move::from_long_algebraic(notation, board, turn).unwrap();
+        board.make_move(legal_move, turn);
+    }
+
+    #[test]
+    fn piece_at_follows_special_moves() {
+        let mut board = Board::new_from_fenn_notation("r3k2r/6P1/8/8/4p3/8/3P4/R3K2R w KQkq - 0 1");
+        assert_eq!(board.piece_at(get_square("a8")), Some(PieceType::BlackRook));
+        assert_eq!(board.piece_at(get_square("e4")), Some(PieceType::BlackPawn));
+        assert_eq!(board.piece_at(get_square("e5")), None);
+
+        play(&mut board, "e1g1");
+        assert_eq!(board.piece_at(get_square("g1")), Some(PieceType::WhiteKing));
+        assert_eq!(board.piece_at(get_square("f1")), Some(PieceType::WhiteRook));
+        assert_eq!(board.piece_at(get_square("e1")), None);
+        assert_eq!(board.piece_at(get_square("h1")), None);
+
+        play(&mut board, "e8c8");
+        assert_eq!(board.piece_at(get_square("c8")), Some(PieceType::BlackKing));
+        assert_eq!(board.piece_at(get_square("d8")), Some(PieceType::BlackRook));
+        assert_eq!(board.piece_at(get_square("a8")), None);
+
+        play(&mut board, "d2d4");
+        play(&mut board, "e4d3");
+        assert_eq!(board.piece_at(get_square("d3")), Some(PieceType::BlackPawn));
+        assert_eq!(board.piece_at(get_square("d4")), None);
+
+        play(&mut board, "g7g8n");
+        assert_eq!(
+            board.piece_at(get_square("g8")),
+            Some(PieceType::WhiteKnight)
+        );
+        assert_eq!(board.piece_at(get_square("g7")), None);
+    }
+
+    #[test]
+    fn put_and_remove_piece() {
+        let mut board = Board::new_empty();
+        board.put_piece(PieceType::WhiteQueen, get_square("d1"));
+        assert_eq!(board.w_queen, get_square("d1").bitboard());
+
+        board.put_piece(PieceType::BlackKnight, get_square("d1"));
+        assert!(board.w_queen.is_empty());
+        assert_eq!(board.b_knights, get_square("d1").bitboard());
+
+        assert_eq!(
+            board.remove_piece(get_square("d1")),
+            Some(PieceType::BlackKnight)
+        );
+        assert_eq!(board.remove_piece(get_square("d1")), None);
+        assert!(board.getOcupancy().is_empty());
+    }
+
+    #[test]
+    fn sync_mailbox_reads_the_bitboards() {
+        let mut board = Board::new_empty();
+        board.w_king = get_square("e1").bitboard();
+        board.b_pawns = get_square("e7").bitboard() | get_square("f7").bitboard();
+        assert_eq!(board.piece_at(get_square("e1")), None);
+
+        board.sync_mailbox();
+        assert_eq!(board.piece_at(get_square("e1")), Some(PieceType::WhiteKing));
+        assert_eq!(board.piece_at(get_square("f7")), Some(PieceType::BlackPawn));
+        assert_eq!(board.piece_at(get_square("e2")), None);
+    }
+
+    #[test]
+    fn chess960_castle_moves_king_and_rook() {
+        //King b1 castles king side past its own rook on c1
+        let mut board = Board::new_from_fenn_notation("4k3/8/8/8/8/8/8/1KR5 w C - 0 1");
+        play(&mut board, "b1c1");
+        assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/8/5RK1 b - - 1 1");
+        assert_eq!(board.piece_at(get_square("b1")), None);
+        assert_eq!(board.piece_at(get_square("c1")), None);
+
+        //The king is already on its target square, only the rook moves
+        let mut board = Board::new_from_fenn_notation("4k3/8/8/8/8/8/8/6KR w H - 0 1");
+        play(&mut board, "g1h1");
+        assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/8/5RK1 b - - 1 1");
+
+        //King and rook swap squares
+        let mut board = Board::new_from_fenn_notation("4k3/8/8/8/8/8/8/2RK4 w C - 0 1");
+        play(&mut board, "d1c1");
+        assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/8/2KR4 b - - 1 1");
+    }
+}
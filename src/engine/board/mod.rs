@@ -1,3 +1,17 @@
+mod attackers_test;
+pub mod bitboard;
 pub mod board;
+pub mod chess960;
 pub mod fenn;
+mod legal_moves_test;
+mod make_move_test;
+pub mod moves;
+mod perft_test;
 pub mod position;
+pub mod san;
+pub mod see;
+pub mod square;
+mod status_test;
+mod transform_test;
+pub mod validation;
+pub mod zobrist;
@@ -0,0 +1,2 @@
+pub mod moves;
+mod moves_test;
@@ -0,0 +1,255 @@
+use crate::engine::board::{
+    board::{Board, Turn},
+    position::position::{CastleOptions, LegalMove, PromotionOptions},
+    square::square::Square,
+};
+
+const FROM_MASK: u16 = 0x3f;
+const TO_SHIFT: u16 = 6;
+const FLAG_SHIFT: u16 = 12;
+const CAPTURE_BIT: u16 = 0x4;
+const PROMOTION_BIT: u16 = 0x8;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum MoveFlag {
+    Quiet = 0,
+    DoublePush = 1,
+    KingCastle = 2,
+    QueenCastle = 3,
+    Capture = 4,
+    EnPassant = 5,
+    KnightPromotion = 8,
+    BishopPromotion = 9,
+    RookPromotion = 10,
+    QueenPromotion = 11,
+    KnightPromotionCapture = 12,
+    BishopPromotionCapture = 13,
+    RookPromotionCapture = 14,
+    QueenPromotionCapture = 15,
+}
+
+impl MoveFlag {
+    fn from_bits(bits: u16) -> MoveFlag {
+        match bits {
+            1 => return MoveFlag::DoublePush,
+            2 => return MoveFlag::KingCastle,
+            3 => return MoveFlag::QueenCastle,
+            4 => return MoveFlag::Capture,
+            5 => return MoveFlag::EnPassant,
+            8 => return MoveFlag::KnightPromotion,
+            9 => return MoveFlag::BishopPromotion,
+            10 => return MoveFlag::RookPromotion,
+            11 => return MoveFlag::QueenPromotion,
+            12 => return MoveFlag::KnightPromotionCapture,
+            13 => return MoveFlag::BishopPromotionCapture,
+            14 => return MoveFlag::RookPromotionCapture,
+            15 => return MoveFlag::QueenPromotionCapture,
+            _ => return MoveFlag::Quiet,
+        }
+    }
+
+    //A promotion without a chosen piece is a queen promotion
+    pub fn promotion(promotion: PromotionOptions, capture: bool) -> MoveFlag {
+        let piece_bits = match promotion {
+            PromotionOptions::Knight => 0,
+            PromotionOptions::Bishop => 1,
+            PromotionOptions::Rook => 2,
+            PromotionOptions::Queen | PromotionOptions::None => 3,
+        };
+        let capture_bits = if capture { CAPTURE_BIT } else { 0 };
+        return MoveFlag::from_bits(PROMOTION_BIT | capture_bits | piece_bits);
+    }
+}
+
+//From square, to square and flag packed in 16 bits, squares use the bitboard index
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Move(u16);
+
+impl Move {
+    //Never generated, stands for "no move"
+    pub const NULL: Move = Move(0);
+
+    pub fn new(from: Square, to: Square, flag: MoveFlag) -> Move {
+        return Move(
+            from.index() as u16 | (to.index() as u16) << TO_SHIFT | (flag as u16) << FLAG_SHIFT,
+        );
+    }
+
+    pub fn king_side_castle(board: &Board, color: Turn) -> Move {
+        return Move::castle(board, color, CastleOptions::KingSide);
+    }
+
+    pub fn queen_side_castle(board: &Board, color: Turn) -> Move {
+        return Move::castle(board, color, CastleOptions::QueenSide);
+    }
+
+    //Castles go from the king to the castling rook, so Chess960 castles stay unambiguous
+    fn castle(board: &Board, color: Turn, castle: CastleOptions) -> Move {
+        let (rank, rook_file, flag) = match (color, castle) {
+            (Turn::White, CastleOptions::QueenSide) => (0, 0, MoveFlag::QueenCastle),
+            (Turn::Black, CastleOptions::QueenSide) => (7, 0, MoveFlag::QueenCastle),
+            (Turn::White, _) => (0, 7, MoveFlag::KingCastle),
+            (Turn::Black, _) => (7, 7, MoveFlag::KingCastle),
+        };
+        let king = board.get_king_square(color).unwrap_or(Square::new(4, rank));
+        let rook = board
+            .get_castle_rook_square(color, castle)
+            .unwrap_or(Square::new(rook_file, rank));
+        return Move::new(king, rook, flag);
+    }
+
+    pub fn get_from(&self) -> Square {
+        return Square::from_index((self.0 & FROM_MASK) as usize);
+    }
+
+    pub fn get_to(&self) -> Square {
+        return Square::from_index((self.0 >> TO_SHIFT & FROM_MASK) as usize);
+    }
+
+    pub fn get_flag(&self) -> MoveFlag {
+        return MoveFlag::from_bits(self.0 >> FLAG_SHIFT);
+    }
+
+    pub fn is_capture(&self) -> bool {
+        return (self.0 >> FLAG_SHIFT) & CAPTURE_BIT != 0;
+    }
+
+    pub fn is_promotion(&self) -> bool {
+        return (self.0 >> FLAG_SHIFT) & PROMOTION_BIT != 0;
+    }
+
+    pub fn is_en_passant(&self) -> bool {
+        return self.get_flag() == MoveFlag::EnPassant;
+    }
+
+    pub fn is_double_push(&self) -> bool {
+        return self.get_flag() == MoveFlag::DoublePush;
+    }
+
+    pub fn is_castle(&self) -> bool {
+        return self.get_castle() != CastleOptions::None;
+    }
+
+    pub fn get_castle(&self) -> CastleOptions {
+        match self.get_flag() {
+            MoveFlag::KingCastle => return CastleOptions::KingSide,
+            MoveFlag::QueenCastle => return CastleOptions::QueenSide,
+            _ => return CastleOptions::None,
+        }
+    }
+
+    pub fn get_promotion(&self) -> PromotionOptions {
+        if !self.is_promotion() {
+            return PromotionOptions::None;
+        }
+        match (self.0 >> FLAG_SHIFT) & 0x3 {
+            0 => return PromotionOptions::Knight,
+            1 => return PromotionOptions::Bishop,
+            2 => return PromotionOptions::Rook,
+            _ => return PromotionOptions::Queen,
+        }
+    }
+
+    //The board gives the flags a LegalMove does not carry
+    pub fn from_legal_move(legal_move: LegalMove, board: &Board, color: Turn) -> Move {
+        match legal_move.castle {
+            CastleOptions::KingSide => return Move::king_side_castle(board, color),
+            CastleOptions::QueenSide => return Move::queen_side_castle(board, color),
+            CastleOptions::None => {}
+        }
+
+        let from = Square::from_index((legal_move.from.row * 8 + legal_move.from.colum) as usize);
+        let to = Square::from_index((legal_move.to.row * 8 + legal_move.to.colum) as usize);
+        let (pawns, enemy_bits, en_passant_target) = match color {
+            Turn::White => (
+                board.w_pawns,
+                board.getBlackBitboard(),
+                board.b_en_passant.north(),
+            ),
+            Turn::Black => (
+                board.b_pawns,
+                board.getWhiteBitboard(),
+                board.w_en_passant.south(),
+            ),
+        };
+        let capture = enemy_bits.contains(to);
+
+        if pawns.contains(from) {
+            if legal_move.is_promotion(board, color) {
+                return Move::new(from, to, MoveFlag::promotion(legal_move.promotion, capture));
+            }
+            if to.bitboard() == en_passant_target {
+                return Move::new(from, to, MoveFlag::EnPassant);
+            }
+            if from.rank().abs_diff(to.rank()) == 2 {
+                return Move::new(from, to, MoveFlag::DoublePush);
+            }
+        }
+        if capture {
+            return Move::new(from, to, MoveFlag::Capture);
+        }
+        return Move::new(from, to, MoveFlag::Quiet);
+    }
+
+    pub fn to_legal_move(&self) -> LegalMove {
+        let color = if self.get_from().rank() == 0 {
+            Turn::White
+        } else {
+            Turn::Black
+        };
+        match self.get_castle() {
+            CastleOptions::KingSide => return LegalMove::king_side_castle(color),
+            CastleOptions::QueenSide => return LegalMove::queen_side_castle(color),
+            CastleOptions::None => {}
+        }
+
+        let from = self.get_from().index() as i8;
+        let to = self.get_to().index() as i8;
+        return LegalMove::new(from / 8, from % 8, to / 8, to % 8)
+            .with_promotion(self.get_promotion());
+    }
+
+    //Long algebraic notation as used by UCI (e2e4, e7e8q, e1g1)
+    pub fn to_long_algebraic(&self) -> String {
+        let to = match self.get_castle() {
+            CastleOptions::KingSide => Square::new(6, self.get_from().rank()),
+            CastleOptions::QueenSide => Square::new(2, self.get_from().rank()),
+            CastleOptions::None => self.get_to(),
+        };
+        let mut notation = format!("{}{}", self.get_from(), to);
+        if let Some(piece) = self.get_promotion().to_char() {
+            notation.push(piece);
+        }
+        return notation;
+    }
+
+    //UCI_Chess960 notation, castles are written as the king taking its own rook (e1h1)
+    pub fn to_chess960_algebraic(&self) -> String {
+        if self.is_castle() {
+            return format!("{}{}", self.get_from(), self.get_to());
+        }
+        return self.to_long_algebraic();
+    }
+
+    pub fn to_uci(&self, chess960: bool) -> String {
+        if chess960 {
+            return self.to_chess960_algebraic();
+        }
+        return self.to_long_algebraic();
+    }
+
+    //Chess960 boards expect castles written as the king taking its rook
+    pub fn from_long_algebraic(notation: &str, board: &Board, color: Turn) -> Option<Move> {
+        let notation = notation.trim().to_lowercase();
+        return board
+            .get_moves(color)
+            .into_iter()
+            .find(|movve| movve.to_uci(board.chess960) == notation);
+    }
+}
+
+impl std::fmt::Display for Move {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(fmt, "{}", self.to_long_algebraic())
+    }
+}
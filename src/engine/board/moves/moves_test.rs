@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod moves_tests {
+    use crate::engine::board::{
+        board::{Board, Turn},
+        moves::moves::{Move, MoveFlag},
+        position::position::{CastleOptions, LegalMove, PromotionOptions},
+        square::square::Square,
+    };
+
+    const POSITIONS: [&str; 5] = [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R b KQkq - 0 1",
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+        "8/8/8/3pP3/8/8/8/K6k w - d6 0 1",
+    ];
+
+    #[test]
+    fn packs_squares_and_flag() {
+        let movve = Move::new(
+            Square::from_index(63),
+            Square::from_index(0),
+            MoveFlag::QueenPromotionCapture,
+        );
+        assert_eq!(movve.get_from(), Square::from_index(63));
+        assert_eq!(movve.get_to(), Square::from_index(0));
+        assert_eq!(movve.get_flag(), MoveFlag::QueenPromotionCapture);
+        assert!(movve.is_capture());
+        assert!(movve.is_promotion());
+        assert_eq!(movve.get_promotion(), PromotionOptions::Queen);
+        assert_eq!(std::mem::size_of::<Move>(), 2);
+    }
+
+    #[test]
+    fn flags_by_kind() {
+        let double_push = Move::new(
+            Square::from_index(11),
+            Square::from_index(27),
+            MoveFlag::DoublePush,
+        );
+        assert!(double_push.is_double_push() && !double_push.is_capture());
+
+        let en_passant = Move::new(
+            Square::from_index(35),
+            Square::from_index(44),
+            MoveFlag::EnPassant,
+        );
+        assert!(en_passant.is_en_passant() && en_passant.is_capture());
+
+        let knight = Move::new(
+            Square::from_index(52),
+            Square::from_index(60),
+            MoveFlag::KnightPromotion,
+        );
+        assert_eq!(knight.get_promotion(), PromotionOptions::Knight);
+        assert!(!knight.is_capture());
+
+        assert_eq!(
+            Move::new(
+                Square::from_index(1),
+                Square::from_index(18),
+                MoveFlag::Quiet
+            )
+            .get_promotion(),
+            PromotionOptions::None
+        );
+        assert_eq!(
+            Move::king_side_castle(&Board::new(), Turn::White).get_castle(),
+            CastleOptions::KingSide
+        );
+        assert_eq!(
+            Move::queen_side_castle(&Board::new(), Turn::Black).get_castle(),
+            CastleOptions::QueenSide
+        );
+    }
+
+    #[test]
+    fn castles_use_king_squares() {
+        assert_eq!(
+            Move::king_side_castle(&Board::new(), Turn::White).to_long_algebraic(),
+            "e1g1"
+        );
+        assert_eq!(
+            Move::queen_side_castle(&Board::new(), Turn::White).to_long_algebraic(),
+            "e1c1"
+        );
+        assert_eq!(
+            Move::king_side_castle(&Board::new(), Turn::Black).to_long_algebraic(),
+            "e8g8"
+        );
+        assert_eq!(
+            Move::queen_side_castle(&Board::new(), Turn::Black).to_long_algebraic(),
+            "e8c8"
+        );
+    }
+
+    #[test]
+    fn generated_flags_match_the_board() {
+        let board = Board::new_from_fenn_notation(POSITIONS[4]);
+        let en_passant = Move::from_long_algebraic("e5d6", &board, Turn::White).unwrap();
+        assert_eq!(en_passant.get_flag(), MoveFlag::EnPassant);
+
+        let board = Board::new();
+        let double_push = Move::from_long_algebraic("e2e4", &board, Turn::White).unwrap();
+        assert_eq!(double_push.get_flag(), MoveFlag::DoublePush);
+        let quiet = Move::from_long_algebraic("g1f3", &board, Turn::White).unwrap();
+        assert_eq!(quiet.get_flag(), MoveFlag::Quiet);
+
+        let board = Board::new_from_fenn_notation(POSITIONS[3]);
+        let promotion = Move::from_long_algebraic("g2h1r", &board, Turn::Black).unwrap();
+        assert_eq!(promotion.get_flag(), MoveFlag::RookPromotionCapture);
+    }
+
+    #[test]
+    fn legal_move_round_trip() {
+        for fen in POSITIONS {
+            let board = Board::new_from_fenn_notation(fen);
+            let turn = board.get_turn();
+            for movve in board.get_moves(turn) {
+                let legal_move: LegalMove = movve.to_legal_move();
+                assert!(
+                    Move::from_legal_move(legal_move, &board, turn) == movve,
+                    "{} {}",
+                    fen,
+                    movve
+                );
+                assert_eq!(
+                    legal_move.to_long_algebraic(&board, turn),
+                    movve.to_long_algebraic()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,318 @@
+#[cfg(test)]
+mod perft_tests {
+    use crate::engine::board::{board::Board, moves::moves::Move};
+
+    //Reference positions and node counts from https://www.chessprogramming.org/Perft_Results
+    const START_POSITION: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+    const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+    const POSITION_3: &str = "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1";
+    const POSITION_4: &str = "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1";
+    const POSITION_5: &str = "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8";
+    const POSITION_6: &str =
+        "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10";
+
+    fn assert_perft(fen: &str, expected_nodes: &[u64]) {
+        let board = Board::new_from_fenn_notation(fen);
+        for (index, expected) in expected_nodes.iter().enumerate() {
+            let depth = index as u8 + 1;
+            assert_eq!(board.perft(depth), *expected, "{} at depth {}", fen, depth);
+        }
+    }
+
+    #[test]
+    fn perft_start_position() {
+        assert_perft(START_POSITION, &[20, 400, 8902, 197281]);
+    }
+
+    #[test]
+    #[ignore = "slow, run with --release -- --ignored"]
+    fn perft_start_position_deep() {
+        let board = Board::new_from_fenn_notation(START_POSITION);
+        assert_eq!(board.perft(5), 4865609);
+    }
+
+    #[test]
+    fn perft_kiwipete() {
+        assert_perft(KIWIPETE, &[48, 2039, 97862]);
+    }
+
+    #[test]
+    #[ignore = "slow, run with --release -- --ignored"]
+    fn perft_kiwipete_deep() {
+        let board = Board::new_from_fenn_notation(KIWIPETE);
+        assert_eq!(board.perft(4), 4085603);
+    }
+
+    #[test]
+    fn perft_position_3() {
+        assert_perft(POSITION_3, &[14, 191, 2812, 43238, 674624]);
+    }
+
+    #[test]
+    #[ignore = "slow, run with --release -- --ignored"]
+    fn perft_position_3_deep() {
+        let board = Board::new_from_fenn_notation(POSITION_3);
+        assert_eq!(board.perft(6), 11030083);
+    }
+
+    #[test]
+    fn perft_position_4() {
+        assert_perft(POSITION_4, &[6, 264, 9467, 422333]);
+    }
+
+    #[test]
+    fn perft_position_5() {
+        assert_perft(POSITION_5, &[44, 1486, 62379]);
+    }
+
+    #[test]
+    fn perft_position_6() {
+        assert_perft(POSITION_6, &[46, 2079, 89890]);
+    }
+
+    #[test]
+    #[ignore = "slow, run with --release -- --ignored"]
+    fn perft_position_6_deep() {
+        let board = Board::new_from_fenn_notation(POSITION_6);
+        assert_eq!(board.perft(4), 3894594);
+    }
+
+    //Edge cases, see http://www.talkchess.com/forum/viewtopic.php?t=47318
+    #[test]
+    fn perft_avoid_illegal_en_passant() {
+        assert_eq!(
+            Board::new_from_fenn_notation("3k4/3p4/8/K1P4r/8/8/8/8 b - - 0 1").perft(6),
+            1134888
+        );
+        assert_eq!(
+            Board::new_from_fenn_notation("8/8/4k3/8/2p5/8/B2P2K1/8 w - - 0 1").perft(6),
+            1015133
+        );
+    }
+
+    #[test]
+    fn perft_en_passant_capture_checks_opponent() {
+        let board = Board::new_from_fenn_notation("8/8/1k6/2b5/2pP4/8/5K2/8 b - d3 0 1");
+        assert_eq!(board.perft(6), 1440467);
+    }
+
+    #[test]
+    fn perft_castling_gives_check() {
+        assert_eq!(
+            Board::new_from_fenn_notation("5k2/8/8/8/8/8/8/4K2R w K - 0 1").perft(6),
+            661072
+        );
+        assert_eq!(
+            Board::new_from_fenn_notation("3k4/8/8/8/8/8/8/R3K3 w Q - 0 1").perft(6),
+            803711
+        );
+    }
+
+    #[test]
+    fn perft_castling_rights_lost_on_rook_capture() {
+        let board = Board::new_from_fenn_notation("r3k2r/1b4bq/8/8/8/8/7B/R3K2R w KQkq - 0 1");
+        assert_eq!(board.perft(4), 1274206);
+    }
+
+    #[test]
+    fn perft_castling_prevented() {
+        let board = Board::new_from_fenn_notation("r3k2r/8/3Q4/8/8/5q2/8/R3K2R b KQkq - 0 1");
+        assert_eq!(board.perft(4), 1720476);
+    }
+
+    #[test]
+    fn perft_promotions() {
+        //Promote out of check
+        assert_eq!(
+            Board::new_from_fenn_notation("2K2r2/4P3/8/8/8/8/8/3k4 w - - 0 1").perft(4),
+            19174
+        );
+        //Promote to give check
+        assert_eq!(
+            Board::new_from_fenn_notation("4k3/1P6/8/8/8/8/K7/8 w - - 0 1").perft(6),
+            217342
+        );
+        //Underpromote to check
+        assert_eq!(
+            Board::new_from_fenn_notation("8/P1k5/K7/8/8/8/8/8 w - - 0 1").perft(6),
+            92683
+        );
+    }
+
+    #[test]
+    #[ignore = "slow, run with --release -- --ignored"]
+    fn perft_promotions_deep() {
+        assert_eq!(
+            Board::new_from_fenn_notation("2K2r2/4P3/8/8/8/8/8/3k4 w - - 0 1").perft(6),
+            3821001
+        );
+    }
+
+    #[test]
+    fn perft_discovered_check() {
+        let board = Board::new_from_fenn_notation("8/8/1P2K3/8/2n5/1q6/8/5k2 b - - 0 1");
+        assert_eq!(board.perft(5), 1004658);
+    }
+
+    #[test]
+    fn perft_self_stalemate() {
+        let board = Board::new_from_fenn_notation("K1k5/8/P7/8/8/8/8/8 w - - 0 1");
+        assert_eq!(board.perft(6), 2217);
+    }
+
+    #[test]
+    fn perft_stalemate_and_checkmate() {
+        let board = Board::new_from_fenn_notation("8/8/2k5/5q2/5n2/8/5K2/8 b - - 0 1");
+        assert_eq!(board.perft(4), 23527);
+    }
+
+    #[test]
+    fn perft_stalemate_and_checkmate_promotion() {
+        let board = Board::new_from_fenn_notation("8/k1P5/8/1K6/8/8/8/8 w - - 0 1");
+        assert_eq!(board.perft(7), 567584);
+    }
+
+    #[test]
+    fn divide_matches_perft() {
+        let board = Board::new_from_fenn_notation(KIWIPETE);
+        let divided = board.divide(2);
+
+        assert_eq!(divided.len(), 48);
+        let nodes: u64 = divided.iter().map(|(_, nodes)| nodes).sum();
+        assert_eq!(nodes, board.perft(2));
+    }
+
+    //Chess960 positions from the fischer.epd suite, castling rights as rook files
+    const CHESS960_POSITIONS: [(&str, [u64; 4]); 5] = [
+        (
+            "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9",
+            [21, 528, 12189, 326672],
+        ),
+        (
+            "2nnrbkr/p1qppppp/8/1ppb4/6PP/3PP3/PPP2P2/BQNNRBKR w HEhe - 1 9",
+            [21, 807, 18002, 667366],
+        ),
+        (
+            "b1q1rrkb/pppppppp/3nn3/8/P7/1PPP4/4PPPP/BQNNRKRB w GE - 1 9",
+            [20, 479, 10471, 273318],
+        ),
+        (
+            "rbbqn1kr/pp2p1pp/6n1/2pp1p2/2P4P/P7/BP1PPPP1/R1BQNNKR w HAha - 0 9",
+            [27, 916, 25798, 890435],
+        ),
+        (
+            "rqbbknr1/1ppp2pp/p5n1/4pp2/P7/1PP5/1Q1PPPPP/R1BBKNRN w GAga - 0 9",
+            [24, 600, 15347, 408207],
+        ),
+    ];
+
+    #[test]
+    fn perft_chess960() {
+        for (fen, expected_nodes) in CHESS960_POSITIONS {
+            assert_perft(fen, &expected_nodes[..3]);
+        }
+    }
+
+    #[test]
+    #[ignore = "slow, run with --release -- --ignored"]
+    fn perft_chess960_deep() {
+        for (fen, expected_nodes) in CHESS960_POSITIONS {
+            assert_perft(fen, &expected_nodes);
+        }
+    }
+
+    fn get_sorted_uci(moves: &[Move]) -> Vec<String> {
+        let mut notations: Vec<String> = moves.iter().map(|movve| movve.to_uci(true)).collect();
+        notations.sort();
+        return notations;
+    }
+
+    //Perft built from the staged generators, checking every node against the full generator
+    fn staged_perft(board: &Board, depth: u8) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+        let turn = board.get_turn();
+        let captures = board.get_captures(turn);
+        let quiets = board.get_quiets(turn);
+        let evasions = board.get_evasions(turn);
+        let all_moves = board.get_moves(turn);
+        let fen = board.to_fen();
+
+        assert!(
+            captures
+                .iter()
+                .all(|movve| movve.is_capture() || movve.is_promotion()),
+            "{}",
+            fen
+        );
+        assert!(
+            quiets
+                .iter()
+                .all(|movve| !movve.is_capture() && !movve.is_promotion()),
+            "{}",
+            fen
+        );
+        let staged = [captures, quiets].concat();
+        assert_eq!(
+            get_sorted_uci(&staged),
+            get_sorted_uci(&all_moves),
+            "{}",
+            fen
+        );
+        if board.is_in_check(turn) {
+            assert_eq!(
+                get_sorted_uci(&evasions),
+                get_sorted_uci(&all_moves),
+                "{}",
+                fen
+            );
+        } else {
+            assert!(evasions.is_empty(), "{}", fen);
+        }
+
+        let mut nodes = 0;
+        for movve in staged {
+            let mut child = *board;
+            child.make_move(movve, turn);
+            nodes += staged_perft(&child, depth - 1);
+        }
+        return nodes;
+    }
+
+    #[test]
+    fn perft_staged_generators() {
+        for (fen, depth, expected_nodes) in [
+            (START_POSITION, 3, 8902),
+            (KIWIPETE, 2, 2039),
+            (POSITION_3, 3, 2812),
+            (POSITION_4, 3, 9467),
+            (POSITION_5, 2, 1486),
+            (POSITION_6, 2, 2079),
+            (CHESS960_POSITIONS[0].0, 2, CHESS960_POSITIONS[0].1[1]),
+        ] {
+            let board = Board::new_from_fenn_notation(fen);
+            assert_eq!(staged_perft(&board, depth), expected_nodes, "{}", fen);
+        }
+    }
+
+    #[test]
+    fn staged_generators_in_check() {
+        //Double check, only king moves are left
+        let board = Board::new_from_fenn_notation("4r2k/8/8/8/8/R2n4/8/4K3 w - - 0 1");
+        assert_eq!(
+            get_sorted_uci(&board.get_evasions(board.get_turn())),
+            ["e1d1", "e1d2", "e1f1"]
+        );
+        assert!(board.get_captures(board.get_turn()).is_empty());
+
+        //A quiet promotion is a capture stage move and an evasion
+        let board = Board::new_from_fenn_notation("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1");
+        let captures = get_sorted_uci(&board.get_captures(board.get_turn()));
+        assert_eq!(
+            captures,
+            ["a7a8b", "a7a8n", "a7a8q", "a7a8r", "a7b8b", "a7b8n", "a7b8q", "a7b8r"]
+        );
+        assert!(board.get_evasions(board.get_turn()).is_empty());
+    }
+}
@@ -1,16 +1,90 @@
-use crate::engine::board::board::{PieceType, Turn};
-#[derive(PartialEq, PartialOrd, Clone, Copy)]
+use crate::engine::board::{
+    bitboard::bitboard::Bitboard,
+    board::{Board, PieceType, Turn},
+    moves::moves::Move,
+};
+
+const COLUMNS: [char; 8] = ['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h'];
+
+#[derive(PartialEq, PartialOrd, Clone, Copy, Debug)]
 pub enum CastleOptions {
     None,
     KingSide,
     QueenSide,
 }
+
+#[derive(PartialEq, PartialOrd, Clone, Copy, Debug)]
+pub enum PromotionOptions {
+    None,
+    Queen,
+    Rook,
+    Bishop,
+    Knight,
+}
+
+impl PromotionOptions {
+    //Every piece a pawn can promote to, best first
+    pub const ALL: [PromotionOptions; 4] = [
+        PromotionOptions::Queen,
+        PromotionOptions::Rook,
+        PromotionOptions::Bishop,
+        PromotionOptions::Knight,
+    ];
+
+    pub fn from_char(piece: char) -> Option<PromotionOptions> {
+        match piece.to_ascii_lowercase() {
+            'q' => return Some(PromotionOptions::Queen),
+            'r' => return Some(PromotionOptions::Rook),
+            'b' => return Some(PromotionOptions::Bishop),
+            'n' => return Some(PromotionOptions::Knight),
+            _ => return None,
+        }
+    }
+
+    pub fn to_char(&self) -> Option<char> {
+        match self {
+            PromotionOptions::None => return None,
+            PromotionOptions::Queen => return Some('q'),
+            PromotionOptions::Rook => return Some('r'),
+            PromotionOptions::Bishop => return Some('b'),
+            PromotionOptions::Knight => return Some('n'),
+        }
+    }
+}
+
 #[derive(PartialEq, PartialOrd, Clone, Copy)]
 pub struct Position {
     pub colum: i8,
     pub row: i8,
 }
 
+impl Position {
+    //Columns are stored reversed (a = 7, h = 0)
+    pub fn from_algebraic(square: &str) -> Option<Position> {
+        let chars: Vec<char> = square.chars().collect();
+        if chars.len() != 2 {
+            return None;
+        }
+        let column = COLUMNS.iter().position(|&x| x == chars[0])? as i8;
+        let row = chars[1].to_digit(10)? as i8;
+        if row < 1 || row > 8 {
+            return None;
+        }
+        return Some(Position {
+            colum: 7 - column,
+            row: row - 1,
+        });
+    }
+
+    pub fn to_algebraic(&self) -> String {
+        return format!("{}{}", COLUMNS[(7 - self.colum) as usize], self.row + 1);
+    }
+
+    pub fn to_bitboard(&self) -> Bitboard {
+        return Bitboard((1 as u64) << (self.row * 8 + self.colum));
+    }
+}
+
 #[derive(Clone, Copy)]
 pub enum AnalyzerPieceType {
     WhiteKing,
@@ -65,6 +139,7 @@ impl UnCheckedMove {
             from: analyzer_move.from,
             to: analyzer_move.to,
             castle: analyzer_move.castle,
+            promotion: PromotionOptions::None,
         };
     }
 
@@ -115,6 +190,7 @@ pub struct LegalMove {
     pub from: Position,
     pub to: Position,
     pub castle: CastleOptions,
+    pub promotion: PromotionOptions,
 }
 
 impl LegalMove {
@@ -129,7 +205,25 @@ impl LegalMove {
                 row: to_row,
             },
             castle: CastleOptions::None,
+            promotion: PromotionOptions::None,
+        };
+    }
+
+    pub fn with_promotion(&self, promotion: PromotionOptions) -> LegalMove {
+        let mut legal_move = *self;
+        legal_move.promotion = promotion;
+        return legal_move;
+    }
+
+    //Pawn moves landing on the last rank, before picking the promotion piece
+    pub fn is_promotion(&self, board: &Board, color: Turn) -> bool {
+        let pawns = match color {
+            Turn::White => board.w_pawns,
+            Turn::Black => board.b_pawns,
         };
+        return self.castle == CastleOptions::None
+            && !(pawns & self.from.to_bitboard()).is_empty()
+            && (self.to.row == 7 || self.to.row == 0);
     }
 
     pub fn king_side_castle(color: Turn) -> LegalMove {
@@ -139,6 +233,7 @@ impl LegalMove {
                     from: (Position { colum: 0, row: 0 }),
                     to: Position { colum: 0, row: 0 },
                     castle: CastleOptions::KingSide,
+                    promotion: PromotionOptions::None,
                 };
             }
             Turn::Black => {
@@ -146,6 +241,7 @@ impl LegalMove {
                     from: (Position { colum: 0, row: 0 }),
                     to: Position { colum: 0, row: 0 },
                     castle: CastleOptions::KingSide,
+                    promotion: PromotionOptions::None,
                 };
             }
         }
@@ -158,6 +254,7 @@ impl LegalMove {
                     from: (Position { colum: 0, row: 0 }),
                     to: Position { colum: 0, row: 0 },
                     castle: CastleOptions::QueenSide,
+                    promotion: PromotionOptions::None,
                 };
             }
             Turn::Black => {
@@ -165,8 +262,31 @@ impl LegalMove {
                     from: (Position { colum: 0, row: 0 }),
                     to: Position { colum: 0, row: 0 },
                     castle: CastleOptions::QueenSide,
+                    promotion: PromotionOptions::None,
                 };
             }
         }
     }
 }
+
+impl LegalMove {
+    //Long algebraic notation as used by UCI (e2e4, e7e8q, e1g1)
+    pub fn to_long_algebraic(&self, board: &Board, color: Turn) -> String {
+        //The king and rook squares of a castle come from the board
+        if self.castle != CastleOptions::None {
+            return Move::from_legal_move(*self, board, color).to_uci(board.chess960);
+        }
+
+        let mut notation = format!("{}{}", self.from.to_algebraic(), self.to.to_algebraic());
+
+        if self.is_promotion(board, color) {
+            notation.push(self.promotion.to_char().unwrap_or('q'));
+        }
+        return notation;
+    }
+
+    pub fn from_long_algebraic(notation: &str, board: &Board, color: Turn) -> Option<LegalMove> {
+        return Move::from_long_algebraic(notation, board, color)
+            .map(|movve| movve.to_legal_move());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod san;
+mod san_test;
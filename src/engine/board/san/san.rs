@@ -0,0 +1,234 @@
+use std::fmt;
+
+use crate::engine::{
+    board::{
+        board::{Board, PieceType},
+        moves::moves::Move,
+        position::position::{CastleOptions, PromotionOptions},
+        square::square::Square,
+    },
+    movement::movement::Movement,
+};
+
+//Standard algebraic notation, Nf3, exd5, e8=Q+, O-O
+pub struct SAN {}
+
+#[derive(Debug, PartialEq)]
+pub enum SanError {
+    Empty,
+    InvalidSyntax(String),
+    IllegalMove(String),
+    AmbiguousMove(String),
+}
+
+impl fmt::Display for SanError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SanError::Empty => write!(fmt, "empty move"),
+            SanError::InvalidSyntax(san) => write!(fmt, "invalid move '{}'", san),
+            SanError::IllegalMove(san) => write!(fmt, "illegal move '{}'", san),
+            SanError::AmbiguousMove(san) => write!(fmt, "ambiguous move '{}'", san),
+        }
+    }
+}
+
+impl std::error::Error for SanError {}
+
+impl SAN {
+    //None for pawns, they are written without a letter
+    fn get_piece_letter(piece_type: PieceType) -> Option<char> {
+        match piece_type {
+            PieceType::WhiteKing | PieceType::BlackKing => return Some('K'),
+            PieceType::WhiteQueen | PieceType::BlackQueen => return Some('Q'),
+            PieceType::WhiteRook | PieceType::BlackRook => return Some('R'),
+            PieceType::WhiteBishop | PieceType::BlackBishop => return Some('B'),
+            PieceType::WhiteKnight | PieceType::BlackKnight => return Some('N'),
+            _ => return None,
+        }
+    }
+
+    fn get_promotion_letter(promotion: PromotionOptions) -> Option<char> {
+        match promotion {
+            PromotionOptions::Queen => return Some('Q'),
+            PromotionOptions::Rook => return Some('R'),
+            PromotionOptions::Bishop => return Some('B'),
+            PromotionOptions::Knight => return Some('N'),
+            PromotionOptions::None => return None,
+        }
+    }
+
+    fn get_promotion_from_letter(letter: char) -> Option<PromotionOptions> {
+        match letter.to_ascii_uppercase() {
+            'Q' => return Some(PromotionOptions::Queen),
+            'R' => return Some(PromotionOptions::Rook),
+            'B' => return Some(PromotionOptions::Bishop),
+            'N' => return Some(PromotionOptions::Knight),
+            _ => return None,
+        }
+    }
+
+    fn get_file_letter(square: Square) -> char {
+        return (b'a' + square.file()) as char;
+    }
+
+    fn get_moved_letter(board: &Board, movve: Move) -> Option<char> {
+        return board
+            .piece_at(movve.get_from())
+            .and_then(SAN::get_piece_letter);
+    }
+
+    pub fn move_to_san(board: &Board, movve: Move) -> String {
+        let turn = board.get_turn();
+        let mut san = match movve.get_castle() {
+            CastleOptions::KingSide => String::from("O-O"),
+            CastleOptions::QueenSide => String::from("O-O-O"),
+            CastleOptions::None => SAN::get_move_text(board, movve),
+        };
+
+        let mut played = *board;
+        played.make_move(movve, turn);
+        let enemy = Movement::get_oposite_color(turn);
+        if played.is_in_check(enemy) {
+            if played.get_moves(enemy).is_empty() {
+                san.push('#');
+            } else {
+                san.push('+');
+            }
+        }
+        return san;
+    }
+
+    fn get_move_text(board: &Board, movve: Move) -> String {
+        let from = movve.get_from();
+        let to = movve.get_to();
+        let letter = SAN::get_moved_letter(board, movve);
+        let mut san = String::new();
+
+        match letter {
+            Some(letter) => {
+                san.push(letter);
+                //Name the file, else the rank, else both when another piece of the kind can reach the square
+                let rivals: Vec<Square> = board
+                    .get_moves(board.get_turn())
+                    .into_iter()
+                    .filter(|other| {
+                        other.get_to() == to
+                            && other.get_from() != from
+                            && !other.is_castle()
+                            && SAN::get_moved_letter(board, *other) == Some(letter)
+                    })
+                    .map(|other| other.get_from())
+                    .collect();
+                if !rivals.is_empty() {
+                    let file = SAN::get_file_letter(from);
+                    let rank = (b'1' + from.rank()) as char;
+                    if rivals.iter().all(|rival| rival.file() != from.file()) {
+                        san.push(file);
+                    } else if rivals.iter().all(|rival| rival.rank() != from.rank()) {
+                        san.push(rank);
+                    } else {
+                        san.push(file);
+                        san.push(rank);
+                    }
+                }
+            }
+            None if movve.is_capture() => san.push(SAN::get_file_letter(from)),
+            None => {}
+        }
+
+        if movve.is_capture() {
+            san.push('x');
+        }
+        san.push_str(&to.to_string());
+        if let Some(promotion) = SAN::get_promotion_letter(movve.get_promotion()) {
+            san.push('=');
+            san.push(promotion);
+        }
+        return san;
+    }
+
+    //Accepts loose forms as well, 0-0, e8Q, ed5, Ng1f3, missing or extra check marks
+    pub fn parse_san(board: &Board, text: &str) -> Result<Move, SanError> {
+        let san = text
+            .trim()
+            .trim_end_matches(|c| matches!(c, '+' | '#' | '!' | '?'));
+        let san = san.strip_suffix("e.p.").unwrap_or(san).trim_end();
+        if san.is_empty() {
+            return Err(SanError::Empty);
+        }
+        let legal_moves = board.get_moves(board.get_turn());
+
+        let castle = match san.replace('0', "O").as_str() {
+            "O-O" => CastleOptions::KingSide,
+            "O-O-O" => CastleOptions::QueenSide,
+            _ => CastleOptions::None,
+        };
+        if castle != CastleOptions::None {
+            return legal_moves
+                .into_iter()
+                .find(|movve| movve.get_castle() == castle)
+                .ok_or(SanError::IllegalMove(text.to_string()));
+        }
+
+        let mut chars: Vec<char> = san
+            .chars()
+            .filter(|c| !matches!(c, 'x' | ':' | '-'))
+            .collect();
+        let letter = match chars.first() {
+            Some(c) if matches!(c, 'K' | 'Q' | 'R' | 'B' | 'N') => Some(chars.remove(0)),
+            _ => None,
+        };
+
+        //Promotion with or without the equal sign, only for pawns
+        let mut promotion = PromotionOptions::None;
+        if letter.is_none() && chars.len() >= 3 {
+            let last = chars[chars.len() - 1];
+            let before = chars[chars.len() - 2];
+            if let Some(option) = SAN::get_promotion_from_letter(last) {
+                if last.is_ascii_uppercase() || matches!(before, '=' | '1' | '8') {
+                    promotion = option;
+                    chars.pop();
+                }
+            }
+        }
+        if chars.last() == Some(&'=') {
+            chars.pop();
+        }
+        if chars.len() < 2 || chars.contains(&'=') {
+            return Err(SanError::InvalidSyntax(text.to_string()));
+        }
+
+        let square_text: String = chars[chars.len() - 2..].iter().collect();
+        let to: Square = match square_text.parse() {
+            Ok(square) => square,
+            Err(_) => return Err(SanError::InvalidSyntax(text.to_string())),
+        };
+        let mut from_file = None;
+        let mut from_rank = None;
+        for c in &chars[..chars.len() - 2] {
+            match c {
+                'a'..='h' if from_file.is_none() => from_file = Some(*c as u8 - b'a'),
+                '1'..='8' if from_rank.is_none() => from_rank = Some(*c as u8 - b'1'),
+                _ => return Err(SanError::InvalidSyntax(text.to_string())),
+            }
+        }
+
+        let candidates: Vec<Move> = legal_moves
+            .into_iter()
+            .filter(|movve| {
+                let from = movve.get_from();
+                !movve.is_castle()
+                    && movve.get_to() == to
+                    && SAN::get_moved_letter(board, *movve) == letter
+                    && from_file.map_or(true, |file| from.file() == file)
+                    && from_rank.map_or(true, |rank| from.rank() == rank)
+                    && (promotion == PromotionOptions::None || movve.get_promotion() == promotion)
+            })
+            .collect();
+        match candidates.len() {
+            0 => return Err(SanError::IllegalMove(text.to_string())),
+            1 => return Ok(candidates[0]),
+            _ => return Err(SanError::AmbiguousMove(text.to_string())),
+        }
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod san_tests {
+    use crate::engine::board::{board::Board, moves::moves::Move, san::san::SanError};
+
+    const POSITIONS: [&str; 5] = [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+        "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9",
+    ];
+
+    fn san(fen: &str, notation: &str) -> String {
+        let board = Board::new_from_fenn_notation(fen);
+        let movve = Move::from_long_algebraic(notation, &board, board.get_turn()).unwrap();
+        return board.move_to_san(movve);
+    }
+
+    fn parse(fen: &str, san: &str) -> Result<String, SanError> {
+        let board = Board::new_from_fenn_notation(fen);
+        return board
+            .parse_san(san)
+            .map(|movve| movve.to_uci(board.chess960));
+    }
+
+    #[test]
+    fn pieces_pawns_and_captures() {
+        let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        assert_eq!(san(start, "g1f3"), "Nf3");
+        assert_eq!(san(start, "e2e4"), "e4");
+        let fen = "rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2";
+        assert_eq!(san(fen, "e4d5"), "exd5");
+        let fen = "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3";
+        assert_eq!(san(fen, "e5f6"), "exf6");
+        assert_eq!(san(fen, "d1h5"), "Qh5+");
+    }
+
+    #[test]
+    fn castles_promotions_and_mate() {
+        let kiwipete = POSITIONS[1];
+        assert_eq!(san(kiwipete, "e1g1"), "O-O");
+        assert_eq!(san(kiwipete, "e1c1"), "O-O-O");
+        assert_eq!(san("4k3/P7/8/8/8/8/8/4K3 w - - 0 1", "a7a8q"), "a8=Q+");
+        assert_eq!(san("4k3/P7/8/8/8/8/8/4K3 w - - 0 1", "a7a8n"), "a8=N");
+        assert_eq!(san("1n2k3/P7/8/8/8/8/8/4K3 w - - 0 1", "a7b8r"), "axb8=R+");
+        assert_eq!(san("6k1/5ppp/8/8/8/8/8/3R2K1 w - - 0 1", "d1d8"), "Rd8#");
+    }
+
+    #[test]
+    fn disambiguation() {
+        let by_file = "4k3/8/8/8/8/8/8/R4RK1 w - - 0 1";
+        assert_eq!(san(by_file, "a1d1"), "Rad1");
+        assert_eq!(san(by_file, "f1d1"), "Rfd1");
+        assert_eq!(san(by_file, "a1a7"), "Ra7");
+        let by_rank = "4k3/8/8/R7/8/8/8/R3K3 w - - 0 1";
+        assert_eq!(san(by_rank, "a1a3"), "R1a3");
+        assert_eq!(san(by_rank, "a5a3"), "R5a3");
+        let by_square = "4k3/8/8/8/8/Q7/8/Q1Q1K3 w - - 0 1";
+        assert_eq!(san(by_square, "a1b2"), "Qa1b2");
+        assert_eq!(san(by_square, "a3b2"), "Q3b2");
+        assert_eq!(san(by_square, "c1b2"), "Qcb2");
+        //A pinned knight does not count as a second candidate
+        assert_eq!(san("4k3/8/8/8/4r3/8/4N3/2N1K3 w - - 0 1", "c1d3"), "Nd3");
+    }
+
+    #[test]
+    fn parses_loose_forms() {
+        let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        assert_eq!(parse(start, "Nf3"), Ok(String::from("g1f3")));
+        assert_eq!(parse(start, "Nf3+!?"), Ok(String::from("g1f3")));
+        assert_eq!(parse(start, "Ng1-f3"), Ok(String::from("g1f3")));
+        assert_eq!(parse(start, " e4 "), Ok(String::from("e2e4")));
+        assert_eq!(parse(start, "e2e4"), Ok(String::from("e2e4")));
+
+        let fen = "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3";
+        assert_eq!(parse(fen, "exf6"), Ok(String::from("e5f6")));
+        assert_eq!(parse(fen, "exf6 e.p."), Ok(String::from("e5f6")));
+        assert_eq!(parse(fen, "ef6"), Ok(String::from("e5f6")));
+
+        let kiwipete = POSITIONS[1];
+        assert_eq!(parse(kiwipete, "O-O"), Ok(String::from("e1g1")));
+        assert_eq!(parse(kiwipete, "0-0-0"), Ok(String::from("e1c1")));
+
+        let promotion = "1n2k3/P7/8/8/8/8/8/4K3 w - - 0 1";
+        assert_eq!(
+            parse(promotion, "e8=Q+"),
+            Err(SanError::IllegalMove(String::from("e8=Q+")))
+        );
+        assert_eq!(parse(promotion, "a8=Q+"), Ok(String::from("a7a8q")));
+        assert_eq!(parse(promotion, "a8Q"), Ok(String::from("a7a8q")));
+        assert_eq!(parse(promotion, "a8=n"), Ok(String::from("a7a8n")));
+        assert_eq!(parse(promotion, "axb8=R"), Ok(String::from("a7b8r")));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        assert_eq!(parse(start, "  "), Err(SanError::Empty));
+        assert_eq!(
+            parse(start, "Nf9"),
+            Err(SanError::InvalidSyntax(String::from("Nf9")))
+        );
+        assert_eq!(
+            parse(start, "Zf3"),
+            Err(SanError::InvalidSyntax(String::from("Zf3")))
+        );
+        assert_eq!(
+            parse(start, "Nf4"),
+            Err(SanError::IllegalMove(String::from("Nf4")))
+        );
+        assert_eq!(
+            parse(start, "O-O"),
+            Err(SanError::IllegalMove(String::from("O-O")))
+        );
+        assert_eq!(
+            parse("4k3/8/8/8/8/8/8/R4RK1 w - - 0 1", "Rd1"),
+            Err(SanError::AmbiguousMove(String::from("Rd1")))
+        );
+        assert_eq!(
+            parse("4k3/P7/8/8/8/8/8/4K3 w - - 0 1", "a8"),
+            Err(SanError::AmbiguousMove(String::from("a8")))
+        );
+    }
+
+    //Every legal move of every visited position reads back from its own SAN
+    #[test]
+    fn round_trip_random_games() {
+        let mut seed: u64 = 0x2545f4914f6cdd1d;
+        for fen in POSITIONS {
+            for _ in 0..3 {
+                let mut board = Board::new_from_fenn_notation(fen);
+                for _ in 0..30 {
+                    let turn = board.get_turn();
+                    let legal_moves = board.get_moves(turn);
+                    if legal_moves.is_empty() {
+                        break;
+                    }
+                    for legal_move in legal_moves.to_owned() {
+                        let san = board.move_to_san(legal_move);
+                        assert_eq!(
+                            board.parse_san(&san),
+                            Ok(legal_move),
+                            "{} {}",
+                            board.to_fen(),
+                            san
+                        );
+                    }
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    board.make_move(
+                        legal_moves[(seed % legal_moves.len() as u64) as usize],
+                        turn,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod see;
+mod see_test;
@@ -0,0 +1,160 @@
+use crate::engine::{
+    board::{
+        bitboard::bitboard::Bitboard,
+        board::{Board, PieceType, Turn},
+        moves::moves::Move,
+        position::position::PromotionOptions,
+    },
+    movement::movement::Movement,
+};
+
+pub const PAWN_VALUE: i32 = 100;
+pub const KNIGHT_VALUE: i32 = 300;
+pub const BISHOP_VALUE: i32 = 350;
+pub const ROOK_VALUE: i32 = 500;
+pub const QUEEN_VALUE: i32 = 900;
+pub const KING_VALUE: i32 = 20000;
+
+//Static exchange evaluation, the material balance of the captures on a single square
+pub struct SEE {}
+
+impl SEE {
+    pub fn get_piece_value(piece_type: PieceType) -> i32 {
+        match piece_type {
+            PieceType::WhitePawn
+            | PieceType::BlackPawn
+            | PieceType::WhitePawnEnPassant
+            | PieceType::BlackPawnEnPassant => return PAWN_VALUE,
+            PieceType::WhiteKnight | PieceType::BlackKnight => return KNIGHT_VALUE,
+            PieceType::WhiteBishop | PieceType::BlackBishop => return BISHOP_VALUE,
+            PieceType::WhiteRook | PieceType::BlackRook => return ROOK_VALUE,
+            PieceType::WhiteQueen | PieceType::BlackQueen => return QUEEN_VALUE,
+            PieceType::WhiteKing | PieceType::BlackKing => return KING_VALUE,
+        }
+    }
+
+    fn get_promotion_value(promotion: PromotionOptions) -> i32 {
+        match promotion {
+            PromotionOptions::Queen => return QUEEN_VALUE,
+            PromotionOptions::Rook => return ROOK_VALUE,
+            PromotionOptions::Bishop => return BISHOP_VALUE,
+            PromotionOptions::Knight => return KNIGHT_VALUE,
+            PromotionOptions::None => return PAWN_VALUE,
+        }
+    }
+
+    //Least valuable first, the king goes last
+    fn get_pieces_by_value(board: &Board, color: Turn) -> [(Bitboard, i32); 6] {
+        match color {
+            Turn::White => {
+                return [
+                    (board.w_pawns, PAWN_VALUE),
+                    (board.w_knights, KNIGHT_VALUE),
+                    (board.w_bishops, BISHOP_VALUE),
+                    (board.w_rooks, ROOK_VALUE),
+                    (board.w_queen, QUEEN_VALUE),
+                    (board.w_king, KING_VALUE),
+                ]
+            }
+            Turn::Black => {
+                return [
+                    (board.b_pawns, PAWN_VALUE),
+                    (board.b_knights, KNIGHT_VALUE),
+                    (board.b_bishops, BISHOP_VALUE),
+                    (board.b_rooks, ROOK_VALUE),
+                    (board.b_queen, QUEEN_VALUE),
+                    (board.b_king, KING_VALUE),
+                ]
+            }
+        }
+    }
+
+    fn get_least_valuable_attacker(
+        board: &Board,
+        attackers: Bitboard,
+        color: Turn,
+    ) -> Option<(Bitboard, i32)> {
+        for (pieces_bits, value) in SEE::get_pieces_by_value(board, color) {
+            if let Some(square) = (attackers & pieces_bits).first() {
+                return Some((square.bitboard(), value));
+            }
+        }
+        return None;
+    }
+
+    //Gain of the move for the side playing it once both sides stop capturing at their best
+    pub fn see(board: &Board, movve: Move) -> i32 {
+        if movve.is_castle() {
+            return 0;
+        }
+        let from = movve.get_from();
+        let to = movve.get_to();
+        let moving = match board.piece_at(from) {
+            Some(piece_type) => piece_type,
+            None => return 0,
+        };
+        let mut color = if board.getWhiteBitboard().contains(from) {
+            Turn::White
+        } else {
+            Turn::Black
+        };
+
+        let mut ocupancy = board.getOcupancy().without(from);
+        let mut gain: [i32; 32] = [0; 32];
+        gain[0] = match board.piece_at(to) {
+            Some(captured) => SEE::get_piece_value(captured),
+            None => 0,
+        };
+        if movve.is_en_passant() {
+            //The captured pawn stands behind the target square
+            let captured_square = match color {
+                Turn::White => to.offset(0, -1),
+                Turn::Black => to.offset(0, 1),
+            };
+            if let Some(captured_square) = captured_square {
+                ocupancy = ocupancy.without(captured_square);
+            }
+            gain[0] = PAWN_VALUE;
+        }
+        let mut on_square_value = SEE::get_piece_value(moving);
+        if movve.is_promotion() {
+            on_square_value = SEE::get_promotion_value(movve.get_promotion());
+            gain[0] += on_square_value - PAWN_VALUE;
+        }
+
+        //Sliders behind a capturing piece join in once it leaves the occupancy
+        let mut depth = 0;
+        loop {
+            color = Movement::get_oposite_color(color);
+            let attackers = board.attackers_to(to, ocupancy) & ocupancy;
+            let (attacker, value) = match SEE::get_least_valuable_attacker(board, attackers, color)
+            {
+                Some(attacker) => attacker,
+                None => break,
+            };
+            //The king may only take last
+            let enemy = Movement::get_oposite_color(color);
+            let defenders = attackers & !attacker;
+            if value == KING_VALUE
+                && SEE::get_least_valuable_attacker(board, defenders, enemy).is_some()
+            {
+                break;
+            }
+            if depth + 1 == gain.len() {
+                break;
+            }
+
+            depth += 1;
+            gain[depth] = on_square_value - gain[depth - 1];
+            ocupancy = ocupancy & !attacker;
+            on_square_value = value;
+        }
+
+        //Each side may also stop capturing, walk back keeping the better option
+        while depth > 0 {
+            gain[depth - 1] = -(-gain[depth - 1]).max(gain[depth]);
+            depth -= 1;
+        }
+        return gain[0];
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod see_tests {
+    use crate::engine::board::{
+        board::Board,
+        moves::moves::Move,
+        see::see::{BISHOP_VALUE, KNIGHT_VALUE, PAWN_VALUE, QUEEN_VALUE, ROOK_VALUE},
+    };
+
+    fn see(fen: &str, notation: &str) -> i32 {
+        let board = Board::new_from_fenn_notation(fen);
+        let movve = Move::from_long_algebraic(notation, &board, board.get_turn()).unwrap();
+        return board.see(movve);
+    }
+
+    #[test]
+    fn undefended_piece() {
+        assert_eq!(
+            see("1k1r4/1pp4p/p7/4p3/8/P5P1/1PP4P/2K1R3 w - - 0 1", "e1e5"),
+            PAWN_VALUE
+        );
+    }
+
+    #[test]
+    fn defended_piece() {
+        //Knight takes a pawn defended by a pawn
+        assert_eq!(
+            see("4k3/8/3p4/4p3/8/5N2/8/4K3 w - - 0 1", "f3e5"),
+            PAWN_VALUE - KNIGHT_VALUE
+        );
+        //Pawn takes a knight defended by a pawn
+        assert_eq!(
+            see("4k3/8/3p4/4n3/3P4/8/8/4K3 w - - 0 1", "d4e5"),
+            KNIGHT_VALUE - PAWN_VALUE
+        );
+    }
+
+    #[test]
+    fn x_ray_attackers_join_the_exchange() {
+        //The queen behind the rook backs up the capture
+        assert_eq!(
+            see("3rk3/3r4/8/3p4/8/8/3R4/3QK3 w - - 0 1", "d2d5"),
+            PAWN_VALUE - ROOK_VALUE
+        );
+        assert_eq!(
+            see("4k3/3r4/8/3p4/8/8/3R4/3QK3 w - - 0 1", "d2d5"),
+            PAWN_VALUE
+        );
+        //Bishop behind the pawn on the diagonal
+        assert_eq!(
+            see("4k3/8/2b5/3n4/4P3/5B2/8/4K3 w - - 0 1", "e4d5"),
+            KNIGHT_VALUE
+        );
+    }
+
+    #[test]
+    fn quiet_moves_and_hanging_pieces() {
+        assert_eq!(see("4k3/8/8/8/8/8/8/R3K3 w - - 0 1", "a1a5"), 0);
+        //Rook steps onto a square a pawn guards
+        assert_eq!(see("4k3/1p6/8/8/8/8/8/R3K3 w - - 0 1", "a1a6"), -ROOK_VALUE);
+    }
+
+    #[test]
+    fn king_only_takes_undefended_pieces() {
+        assert_eq!(see("4k3/8/8/8/8/8/4q3/4K3 w - - 0 1", "e1e2"), QUEEN_VALUE);
+        //The king takes the pawn back only while nothing else guards d4
+        assert_eq!(
+            see("4k3/8/8/4p3/3n4/4K3/8/3R4 w - - 0 1", "d1d4"),
+            KNIGHT_VALUE - ROOK_VALUE + PAWN_VALUE
+        );
+        assert_eq!(
+            see("3rk3/8/8/4p3/3n4/4K3/8/3R4 w - - 0 1", "d1d4"),
+            KNIGHT_VALUE - ROOK_VALUE
+        );
+    }
+
+    #[test]
+    fn en_passant_and_promotion() {
+        assert_eq!(see("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1", "e5d6"), PAWN_VALUE);
+        //Promotion gains the new piece but the rook takes it back
+        assert_eq!(
+            see("r3k3/1P6/8/8/8/8/8/4K3 w - - 0 1", "b7b8q"),
+            QUEEN_VALUE - PAWN_VALUE - QUEEN_VALUE
+        );
+        assert_eq!(
+            see("r3k3/1P6/8/8/8/8/8/4K3 w - - 0 1", "b7a8q"),
+            ROOK_VALUE + QUEEN_VALUE - PAWN_VALUE
+        );
+        assert_eq!(
+            see("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1", "b7b8n"),
+            KNIGHT_VALUE - PAWN_VALUE
+        );
+    }
+
+    #[test]
+    fn see_ge_thresholds() {
+        let board = Board::new_from_fenn_notation("4k3/8/3p4/4p3/8/5N2/8/4K3 w - - 0 1");
+        let movve = Move::from_long_algebraic("f3e5", &board, board.get_turn()).unwrap();
+        assert!(board.see_ge(movve, PAWN_VALUE - KNIGHT_VALUE));
+        assert!(!board.see_ge(movve, 0));
+
+        let board = Board::new_from_fenn_notation("4k3/8/8/2b5/3B4/8/8/4K3 w - - 0 1");
+        let movve = Move::from_long_algebraic("d4c5", &board, board.get_turn()).unwrap();
+        assert!(board.see_ge(movve, BISHOP_VALUE));
+        assert!(!board.see_ge(movve, BISHOP_VALUE + 1));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod square;
+mod square_test;
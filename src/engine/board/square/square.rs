@@ -0,0 +1,90 @@
+use std::{fmt, str::FromStr};
+
+use crate::engine::board::bitboard::bitboard::Bitboard;
+
+const FILES: [char; 8] = ['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h'];
+
+//Bitboard index of a square, files are stored reversed so h1 is 0 and a1 is 7
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Square(u8);
+
+#[derive(Debug, PartialEq)]
+pub enum SquareError {
+    InvalidSquare(String),
+}
+
+impl fmt::Display for SquareError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SquareError::InvalidSquare(square) => write!(fmt, "invalid square '{}'", square),
+        }
+    }
+}
+
+impl Square {
+    //File 0 is the a file, rank 0 is the first rank
+    pub fn new(file: u8, rank: u8) -> Square {
+        debug_assert!(file < 8 && rank < 8, "square out of the board");
+        return Square(rank * 8 + 7 - file);
+    }
+
+    pub fn from_index(index: usize) -> Square {
+        debug_assert!(index < 64, "square index {} out of the board", index);
+        return Square(index as u8);
+    }
+
+    pub fn index(&self) -> usize {
+        return self.0 as usize;
+    }
+
+    pub fn file(&self) -> u8 {
+        return 7 - self.0 % 8;
+    }
+
+    pub fn rank(&self) -> u8 {
+        return self.0 / 8;
+    }
+
+    pub fn bitboard(&self) -> Bitboard {
+        return Bitboard((1 as u64) << self.0);
+    }
+
+    //None when the step leaves the board
+    pub fn offset(&self, file_step: i8, rank_step: i8) -> Option<Square> {
+        let file = self.file() as i8 + file_step;
+        let rank = self.rank() as i8 + rank_step;
+        if !(0..8).contains(&file) || !(0..8).contains(&rank) {
+            return None;
+        }
+        return Some(Square::new(file as u8, rank as u8));
+    }
+}
+
+impl FromStr for Square {
+    type Err = SquareError;
+
+    fn from_str(square: &str) -> Result<Square, SquareError> {
+        let chars: Vec<char> = square.chars().collect();
+        if chars.len() != 2 {
+            return Err(SquareError::InvalidSquare(square.to_string()));
+        }
+        let file = FILES.iter().position(|&x| x == chars[0]);
+        let rank = chars[1].to_digit(10).filter(|rank| (1..=8).contains(rank));
+        match (file, rank) {
+            (Some(file), Some(rank)) => return Ok(Square::new(file as u8, rank as u8 - 1)),
+            _ => return Err(SquareError::InvalidSquare(square.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Square {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}{}", FILES[self.file() as usize], self.rank() + 1)
+    }
+}
+
+impl fmt::Debug for Square {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}", self)
+    }
+}
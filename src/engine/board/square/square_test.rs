@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod square_tests {
+    use crate::engine::board::square::square::{Square, SquareError};
+
+    #[test]
+    fn parse_and_display_round_trip() {
+        for index in 0..64 {
+            let square = Square::from_index(index);
+            let parsed: Square = square.to_string().parse().unwrap();
+            assert_eq!(parsed, square);
+        }
+    }
+
+    #[test]
+    fn matches_bitboard_layout() {
+        assert_eq!("h1".parse::<Square>().unwrap().index(), 0);
+        assert_eq!("a1".parse::<Square>().unwrap().index(), 7);
+        assert_eq!("e1".parse::<Square>().unwrap().index(), 3);
+        assert_eq!("a8".parse::<Square>().unwrap().index(), 63);
+        assert_eq!("e1".parse::<Square>().unwrap().bitboard().0, 0x8);
+        assert_eq!(Square::from_index(56).to_string(), "h8");
+    }
+
+    #[test]
+    fn file_and_rank() {
+        let square: Square = "c6".parse().unwrap();
+        assert_eq!(square.file(), 2);
+        assert_eq!(square.rank(), 5);
+        assert_eq!(Square::new(2, 5), square);
+    }
+
+    #[test]
+    fn invalid_squares() {
+        for invalid in ["", "e", "e9", "e0", "i4", "E4", "e44", "4e"] {
+            assert_eq!(
+                invalid.parse::<Square>(),
+                Err(SquareError::InvalidSquare(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn offset_stays_on_board() {
+        let e4: Square = "e4".parse().unwrap();
+        assert_eq!(e4.offset(1, 2), Some("f6".parse().unwrap()));
+        assert_eq!(e4.offset(-4, -3), Some("a1".parse().unwrap()));
+        assert_eq!(e4.offset(-5, 0), None);
+        assert_eq!(e4.offset(0, 5), None);
+
+        let h8: Square = "h8".parse().unwrap();
+        assert_eq!(h8.offset(1, 0), None);
+        assert_eq!(h8.offset(0, 1), None);
+    }
+}
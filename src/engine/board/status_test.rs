@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod status_tests {
+    use crate::engine::board::board::{Board, BoardStatus, Turn};
+
+    fn status(fen: &str) -> BoardStatus {
+        return Board::new_from_fenn_notation(fen).status();
+    }
+
+    #[test]
+    fn ongoing() {
+        assert_eq!(Board::new().status(), BoardStatus::Ongoing);
+        //In check with an escape
+        assert_eq!(
+            status("rnbqkbnr/ppppp1pp/8/5p1Q/4P3/8/PPPP1PPP/RNB1KBNR b KQkq - 1 2"),
+            BoardStatus::Ongoing
+        );
+    }
+
+    #[test]
+    fn checkmate() {
+        //Fool's mate
+        assert_eq!(
+            status("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3"),
+            BoardStatus::Checkmate
+        );
+        //Scholar's mate
+        assert_eq!(
+            status("r1bqkb1r/pppp1Qpp/2n2n2/4p3/2B1P3/8/PPPP1PPP/RNB1K1NR b KQkq - 0 4"),
+            BoardStatus::Checkmate
+        );
+        //Back rank
+        assert_eq!(
+            status("6k1/5ppp/8/8/8/8/8/3R2K1 b - - 0 1"),
+            BoardStatus::Ongoing
+        );
+        assert_eq!(
+            status("3R2k1/5ppp/8/8/8/8/8/6K1 b - - 0 1"),
+            BoardStatus::Checkmate
+        );
+    }
+
+    #[test]
+    fn stalemate() {
+        assert_eq!(
+            status("7k/8/6Q1/8/8/8/8/K7 b - - 0 1"),
+            BoardStatus::Stalemate
+        );
+        assert_eq!(
+            status("8/8/8/8/8/1q6/2k5/K7 w - - 0 1"),
+            BoardStatus::Stalemate
+        );
+        assert_eq!(
+            status("k7/P7/K7/8/8/8/8/8 b - - 0 1"),
+            BoardStatus::Stalemate
+        );
+        //The bishop is pinned, so it can not move either
+        assert_eq!(
+            status("kb5R/P7/K7/8/8/8/8/8 b - - 0 1"),
+            BoardStatus::Stalemate
+        );
+    }
+
+    #[test]
+    fn in_check() {
+        let board = Board::new_from_fenn_notation(
+            "rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3",
+        );
+        assert!(board.is_in_check(Turn::White));
+        assert!(!board.is_in_check(Turn::Black));
+    }
+
+    fn insufficient(fen: &str) -> bool {
+        return Board::new_from_fenn_notation(fen).is_insufficient_material();
+    }
+
+    #[test]
+    fn insufficient_material() {
+        //K v K
+        assert!(insufficient("8/8/4k3/8/8/3K4/8/8 w - - 0 1"));
+        //K+minor v K
+        assert!(insufficient("8/8/4k3/8/8/3K4/8/6N1 w - - 0 1"));
+        assert!(insufficient("8/8/4k3/8/8/3K4/8/6b1 b - - 0 1"));
+        //Bishops on the same square colour, c1 and f8 are both dark
+        assert!(insufficient("5b2/8/4k3/8/8/3K4/8/2B5 w - - 0 1"));
+        assert!(insufficient("5b2/8/4k3/8/8/3K4/8/B1B5 w - - 0 1"));
+    }
+
+    #[test]
+    fn sufficient_material() {
+        assert!(!Board::new().is_insufficient_material());
+        assert!(!insufficient("8/8/4k3/8/8/3K4/4P3/8 w - - 0 1"));
+        assert!(!insufficient("8/8/4k3/8/8/3K4/8/R7 w - - 0 1"));
+        assert!(!insufficient("8/8/4k3/8/8/3K4/8/4q3 w - - 0 1"));
+        //Bishops on opposite colours, c1 dark and c8 light
+        assert!(!insufficient("2b5/8/4k3/8/8/3K4/8/2B5 w - - 0 1"));
+        //Knights can mate with help
+        assert!(!insufficient("8/8/4k3/8/8/3K4/8/1NN5 w - - 0 1"));
+        assert!(!insufficient("8/8/4k3/8/8/3K4/8/1Nb5 w - - 0 1"));
+        assert!(!insufficient("6n1/8/4k3/8/8/3K4/8/1N6 w - - 0 1"));
+    }
+}
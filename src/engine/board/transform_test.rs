@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod transform_tests {
+    use crate::engine::board::board::{Board, Turn};
+
+    const POSITIONS: [&str; 6] = [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        "8/8/1k6/2b5/2pP4/8/5K2/8 b - d3 0 1",
+        "r3k2r/8/8/3pP3/8/8/8/R3K2R w Kq d6 0 1",
+        "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9",
+    ];
+
+    //Castling is not mirror symmetric, the king lands on c or g whichever side it starts from
+    fn without_castling(fen: &str) -> String {
+        let mut fields: Vec<&str> = fen.split_whitespace().collect();
+        fields[2] = "-";
+        return fields.join(" ");
+    }
+
+    #[test]
+    fn flipped_swaps_colours() {
+        assert_eq!(
+            Board::new().flipped().to_fen(),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 1"
+        );
+        let board = Board::new_from_fenn_notation("r3k2r/8/8/3pP3/8/8/8/R3K2R w Kq d6 0 1");
+        assert_eq!(
+            board.flipped().to_fen(),
+            "r3k2r/8/8/8/3Pp3/8/8/R3K2R b Qk d3 0 1"
+        );
+        assert!(board.flipped().validate().is_ok());
+    }
+
+    #[test]
+    fn mirrored_swaps_files() {
+        let board = Board::new_from_fenn_notation("4k3/8/8/3pP3/8/8/1N6/R3K3 w Q d6 0 1");
+        assert_eq!(
+            board.mirrored().to_fen(),
+            "3k4/8/8/3Pp3/8/8/6N1/3K3R w K e6 0 1"
+        );
+        assert!(board.mirrored().chess960);
+        assert!(board.mirrored().can_castle_king_side(Turn::White));
+    }
+
+    #[test]
+    fn mirrored_keeps_castling_rights() {
+        let board = Board::new_from_fenn_notation("r3k2r/8/8/8/8/8/8/R3K2R w Kq - 0 1");
+        let mirrored = board.mirrored();
+        assert_eq!(mirrored.to_fen(), "r2k3r/8/8/8/8/8/8/R2K3R w Qk - 0 1");
+        assert_eq!(
+            mirrored.get_zobrist_key(),
+            Board::from_fen(&mirrored.to_fen())
+                .unwrap()
+                .get_zobrist_key()
+        );
+    }
+
+    #[test]
+    fn transforms_are_involutions() {
+        for fen in POSITIONS {
+            let board = Board::new_from_fenn_notation(fen);
+            assert!(board.flipped().flipped() == board, "{}", fen);
+            assert!(board.mirrored().mirrored() == board, "{}", fen);
+            assert_eq!(
+                board.mirrored().mirrored().to_fen(),
+                board.to_fen(),
+                "{}",
+                fen
+            );
+            assert_eq!(
+                board.flipped().get_zobrist_key(),
+                Board::from_fen(&board.flipped().to_fen())
+                    .unwrap()
+                    .get_zobrist_key(),
+                "{}",
+                fen
+            );
+        }
+    }
+
+    #[test]
+    fn transformed_positions_have_the_same_perft() {
+        for fen in POSITIONS {
+            let board = Board::new_from_fenn_notation(fen);
+            let nodes = board.perft(2);
+            assert_eq!(board.flipped().perft(2), nodes, "{}", fen);
+            let without_castles = Board::new_from_fenn_notation(&without_castling(fen));
+            assert_eq!(
+                without_castles.mirrored().perft(2),
+                without_castles.perft(2),
+                "{}",
+                fen
+            );
+        }
+    }
+}
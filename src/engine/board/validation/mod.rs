@@ -0,0 +1,2 @@
+pub mod validation;
+mod validation_test;
@@ -0,0 +1,199 @@
+use std::fmt;
+
+use crate::engine::{
+    board::{
+        bitboard::bitboard::{Bitboard, RANK_1, RANK_8},
+        board::{Board, PieceType, Turn},
+        position::position::CastleOptions,
+        square::square::Square,
+    },
+    movement::movement::Movement,
+};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PositionIssue {
+    MissingKing(Turn),
+    TooManyKings(Turn),
+    TooManyPawns(Turn),
+    TooManyPieces(Turn),
+    TooManyPromotedPieces(Turn),
+    PawnOnBackRank(Square),
+    OverlappingPieces(Square),
+    CastlingRookMissing(Turn, CastleOptions),
+    BadEnPassantSquare(Square),
+    OpponentInCheck(Turn),
+}
+
+impl fmt::Display for PositionIssue {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PositionIssue::MissingKing(color) => write!(fmt, "missing {} king", color),
+            PositionIssue::TooManyKings(color) => write!(fmt, "more than one {} king", color),
+            PositionIssue::TooManyPawns(color) => write!(fmt, "more than 8 {} pawns", color),
+            PositionIssue::TooManyPieces(color) => write!(fmt, "more than 16 {} pieces", color),
+            PositionIssue::TooManyPromotedPieces(color) => {
+                write!(fmt, "more {} promoted pieces than missing pawns", color)
+            }
+            PositionIssue::PawnOnBackRank(square) => write!(fmt, "pawn on {}", square),
+            PositionIssue::OverlappingPieces(square) => {
+                write!(fmt, "more than one piece on {}", square)
+            }
+            PositionIssue::CastlingRookMissing(color, castle) => {
+                write!(
+                    fmt,
+                    "{} {:?} castling right without its rook",
+                    color, castle
+                )
+            }
+            PositionIssue::BadEnPassantSquare(square) => {
+                write!(fmt, "impossible en passant square {}", square)
+            }
+            PositionIssue::OpponentInCheck(color) => {
+                write!(fmt, "{} is in check but not to move", color)
+            }
+        }
+    }
+}
+
+pub struct Validation {}
+
+impl Validation {
+    //Collects every issue instead of stopping at the first one
+    pub fn validate(board: &Board) -> Result<(), Vec<PositionIssue>> {
+        let mut issues: Vec<PositionIssue> = Vec::new();
+        Validation::check_overlapping_pieces(board, &mut issues);
+        for color in [Turn::White, Turn::Black] {
+            Validation::check_piece_counts(board, color, &mut issues);
+        }
+        for square in (board.w_pawns | board.b_pawns) & (RANK_1 | RANK_8) {
+            issues.push(PositionIssue::PawnOnBackRank(square));
+        }
+        for color in [Turn::White, Turn::Black] {
+            Validation::check_castling_rights(board, color, &mut issues);
+        }
+        Validation::check_en_passant(board, &mut issues);
+
+        //The side that just moved can not have left its king attacked
+        let waiting = Movement::get_oposite_color(board.get_turn());
+        if board.is_in_check(waiting) {
+            issues.push(PositionIssue::OpponentInCheck(waiting));
+        }
+
+        if issues.is_empty() {
+            return Ok(());
+        }
+        return Err(issues);
+    }
+
+    fn check_overlapping_pieces(board: &Board, issues: &mut Vec<PositionIssue>) {
+        let mut seen = Bitboard::EMPTY;
+        let mut overlapping = Bitboard::EMPTY;
+        for (pieces_bits, _) in board.get_piece_bitboards() {
+            overlapping |= seen & pieces_bits;
+            seen |= pieces_bits;
+        }
+        for square in overlapping {
+            issues.push(PositionIssue::OverlappingPieces(square));
+        }
+    }
+
+    fn check_piece_counts(board: &Board, color: Turn, issues: &mut Vec<PositionIssue>) {
+        let (king, pawns, pieces) = match color {
+            Turn::White => (board.w_king, board.w_pawns, board.getWhiteBitboard()),
+            Turn::Black => (board.b_king, board.b_pawns, board.getBlackBitboard()),
+        };
+        match king.count() {
+            0 => issues.push(PositionIssue::MissingKing(color)),
+            1 => {}
+            _ => issues.push(PositionIssue::TooManyKings(color)),
+        }
+        if pawns.count() > 8 {
+            issues.push(PositionIssue::TooManyPawns(color));
+        }
+        if pieces.count() > 16 {
+            issues.push(PositionIssue::TooManyPieces(color));
+        }
+
+        //Pieces beyond the starting set can only come from promoted pawns
+        let (knights, bishops, rooks, queens) = match color {
+            Turn::White => (
+                board.w_knights,
+                board.w_bishops,
+                board.w_rooks,
+                board.w_queen,
+            ),
+            Turn::Black => (
+                board.b_knights,
+                board.b_bishops,
+                board.b_rooks,
+                board.b_queen,
+            ),
+        };
+        let promoted = knights.count().saturating_sub(2)
+            + bishops.count().saturating_sub(2)
+            + rooks.count().saturating_sub(2)
+            + queens.count().saturating_sub(1);
+        if promoted > 8_u32.saturating_sub(pawns.count()) {
+            issues.push(PositionIssue::TooManyPromotedPieces(color));
+        }
+    }
+
+    //A right needs the king and its rook on the back rank, the rook on the matching side
+    fn check_castling_rights(board: &Board, color: Turn, issues: &mut Vec<PositionIssue>) {
+        let (king, rook) = match color {
+            Turn::White => (PieceType::WhiteKing, PieceType::WhiteRook),
+            Turn::Black => (PieceType::BlackKing, PieceType::BlackRook),
+        };
+        let king_square = board.get_king_square(color);
+        for castle in [CastleOptions::KingSide, CastleOptions::QueenSide] {
+            let rook_square = match board.get_castle_rook_square(color, castle) {
+                Some(rook_square) => rook_square,
+                None => continue,
+            };
+            let is_valid = match king_square {
+                Some(king_square) => {
+                    let is_on_side = match castle {
+                        CastleOptions::KingSide => rook_square.file() > king_square.file(),
+                        _ => rook_square.file() < king_square.file(),
+                    };
+                    king_square.rank() == rook_square.rank()
+                        && board.piece_at(king_square) == Some(king)
+                        && board.piece_at(rook_square) == Some(rook)
+                        && is_on_side
+                }
+                None => false,
+            };
+            if !is_valid {
+                issues.push(PositionIssue::CastlingRookMissing(color, castle));
+            }
+        }
+    }
+
+    //The board keeps the pawn that just pushed two squares, only the side to move may take it
+    fn check_en_passant(board: &Board, issues: &mut Vec<PositionIssue>) {
+        for (color, en_passant_bits) in [
+            (Turn::White, board.w_en_passant),
+            (Turn::Black, board.b_en_passant),
+        ] {
+            let (pawns, pawn_rank, step) = match color {
+                Turn::White => (board.w_pawns, 3, -1),
+                Turn::Black => (board.b_pawns, 4, 1),
+            };
+            for pawn_square in en_passant_bits {
+                let skipped = pawn_square.offset(0, step);
+                let start = pawn_square.offset(0, 2 * step);
+                let is_possible = board.get_turn() != color
+                    && en_passant_bits.count() == 1
+                    && pawn_square.rank() == pawn_rank
+                    && pawns.contains(pawn_square)
+                    && skipped.map_or(false, |square| board.piece_at(square).is_none())
+                    && start.map_or(false, |square| board.piece_at(square).is_none());
+                if !is_possible {
+                    issues.push(PositionIssue::BadEnPassantSquare(
+                        skipped.unwrap_or(pawn_square),
+                    ));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod validation_tests {
+    use crate::engine::board::{
+        board::{Board, PieceType, Turn},
+        fenn::fenn::FenError,
+        position::position::CastleOptions,
+        square::square::Square,
+        validation::validation::PositionIssue,
+    };
+
+    fn get_square(name: &str) -> Square {
+        return name.parse().unwrap();
+    }
+
+    fn fen_issues(fen: &str) -> Vec<PositionIssue> {
+        match Board::from_fen(fen) {
+            Err(FenError::InvalidPosition(issues)) => return issues,
+            Err(error) => panic!("{} failed with {}", fen, error),
+            Ok(_) => panic!("{} should not be valid", fen),
+        }
+    }
+
+    #[test]
+    fn valid_positions() {
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 3",
+            "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9",
+            "4k3/8/8/8/8/8/8/4K2R b K - 0 1",
+        ] {
+            assert!(Board::from_fen(fen).unwrap().validate().is_ok(), "{}", fen);
+        }
+    }
+
+    #[test]
+    fn fen_loader_rejects_invalid_positions() {
+        assert_eq!(
+            fen_issues("4k3/8/8/8/8/8/8/P3K2P w - - 0 1"),
+            vec![
+                PositionIssue::PawnOnBackRank(get_square("h1")),
+                PositionIssue::PawnOnBackRank(get_square("a1")),
+            ]
+        );
+        assert_eq!(
+            fen_issues("4k3/8/8/8/8/8/8/4RK2 w - - 0 1"),
+            vec![PositionIssue::OpponentInCheck(Turn::Black)]
+        );
+        assert_eq!(
+            fen_issues("4k3/8/8/8/8/8/8/4K3 w K - 0 1"),
+            vec![PositionIssue::CastlingRookMissing(
+                Turn::White,
+                CastleOptions::KingSide
+            )]
+        );
+    }
+
+    #[test]
+    fn every_issue_is_reported() {
+        let mut board = Board::new();
+        board.w_king |= get_square("e4").bitboard();
+        board.w_pawns |= get_square("a3").bitboard();
+        board.b_pawns |= get_square("g8").bitboard();
+        board.sync_mailbox();
+        assert_eq!(
+            board.validate(),
+            Err(vec![
+                PositionIssue::OverlappingPieces(get_square("g8")),
+                PositionIssue::TooManyKings(Turn::White),
+                PositionIssue::TooManyPawns(Turn::White),
+                PositionIssue::TooManyPieces(Turn::White),
+                PositionIssue::TooManyPawns(Turn::Black),
+                PositionIssue::PawnOnBackRank(get_square("g8")),
+            ])
+        );
+    }
+
+    #[test]
+    fn promoted_pieces_need_missing_pawns() {
+        assert_eq!(
+            fen_issues("qqqqkqqq/qqqqqqqq/8/8/8/8/8/4K3 w - - 0 1"),
+            vec![PositionIssue::TooManyPromotedPieces(Turn::Black)]
+        );
+        assert_eq!(
+            fen_issues("4k3/8/8/8/8/8/PPPPPPP1/QQQ1K3 w - - 0 1"),
+            vec![PositionIssue::TooManyPromotedPieces(Turn::White)]
+        );
+        //One missing pawn pays for the second queen
+        let fen = "4k3/8/8/8/8/8/PPPPPPP1/QQ1NKNRR w - - 0 1";
+        assert!(Board::from_fen(fen).unwrap().validate().is_ok());
+    }
+
+    #[test]
+    fn castling_rights_need_their_rook() {
+        let mut board = Board::new();
+        board.remove_piece(get_square("h1"));
+        board.remove_piece(get_square("a8"));
+        assert_eq!(
+            board.validate(),
+            Err(vec![
+                PositionIssue::CastlingRookMissing(Turn::White, CastleOptions::KingSide),
+                PositionIssue::CastlingRookMissing(Turn::Black, CastleOptions::QueenSide),
+            ])
+        );
+
+        //The king moved off its rank
+        let mut board = Board::new_from_fenn_notation("4k3/8/8/8/8/8/8/4K2R w K - 0 1");
+        board.remove_piece(get_square("e1"));
+        board.put_piece(PieceType::WhiteKing, get_square("e2"));
+        assert_eq!(
+            board.validate(),
+            Err(vec![PositionIssue::CastlingRookMissing(
+                Turn::White,
+                CastleOptions::KingSide
+            )])
+        );
+    }
+
+    #[test]
+    fn en_passant_needs_the_pushed_pawn() {
+        let fen = "rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 3";
+
+        let mut board = Board::new_from_fenn_notation(fen);
+        board.w_en_passant = get_square("h4").bitboard();
+        assert_eq!(
+            board.validate(),
+            Err(vec![PositionIssue::BadEnPassantSquare(get_square("h3"))])
+        );
+
+        //Only the side to move may capture en passant
+        let mut board = Board::new_from_fenn_notation(fen);
+        board.b_en_passant = get_square("d4").bitboard();
+        assert_eq!(
+            board.validate(),
+            Err(vec![PositionIssue::BadEnPassantSquare(get_square("d5"))])
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub mod zobrist;
+mod zobrist_test;
@@ -0,0 +1,161 @@
+use crate::engine::board::{
+    bitboard::bitboard::Bitboard,
+    board::{Board, PieceType, Turn},
+};
+
+const ZOBRIST_SEED: u64 = 0x5a0b_8157_c3a4_e9d1;
+
+//splitmix64, so the keys are the same on every build
+const fn next_key(seed: u64) -> (u64, u64) {
+    let seed = seed.wrapping_add(0x9e3779b97f4a7c15);
+    let mut key = seed;
+    key = (key ^ (key >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    key = (key ^ (key >> 27)).wrapping_mul(0x94d049bb133111eb);
+    return (seed, key ^ (key >> 31));
+}
+
+struct ZobristKeys {
+    pieces: [[u64; 64]; 12],
+    castling: [u64; 4],
+    en_passant: [u64; 8],
+    black_to_move: u64,
+}
+
+const fn generate_keys() -> ZobristKeys {
+    let mut keys = ZobristKeys {
+        pieces: [[0; 64]; 12],
+        castling: [0; 4],
+        en_passant: [0; 8],
+        black_to_move: 0,
+    };
+    let mut seed = ZOBRIST_SEED;
+
+    let mut piece = 0;
+    while piece < 12 {
+        let mut square = 0;
+        while square < 64 {
+            let (next_seed, key) = next_key(seed);
+            seed = next_seed;
+            keys.pieces[piece][square] = key;
+            square += 1;
+        }
+        piece += 1;
+    }
+
+    let mut castle = 0;
+    while castle < 4 {
+        let (next_seed, key) = next_key(seed);
+        seed = next_seed;
+        keys.castling[castle] = key;
+        castle += 1;
+    }
+
+    let mut column = 0;
+    while column < 8 {
+        let (next_seed, key) = next_key(seed);
+        seed = next_seed;
+        keys.en_passant[column] = key;
+        column += 1;
+    }
+
+    let (_, key) = next_key(seed);
+    keys.black_to_move = key;
+    return keys;
+}
+
+static KEYS: ZobristKeys = generate_keys();
+
+pub struct Zobrist {}
+
+impl Zobrist {
+    //Full recomputation, the board keeps its key up to date incrementally
+    pub fn get_key(board: &Board) -> u64 {
+        let pieces = [
+            (board.w_pawns, PieceType::WhitePawn),
+            (board.w_knights, PieceType::WhiteKnight),
+            (board.w_bishops, PieceType::WhiteBishop),
+            (board.w_rooks, PieceType::WhiteRook),
+            (board.w_queen, PieceType::WhiteQueen),
+            (board.w_king, PieceType::WhiteKing),
+            (board.b_pawns, PieceType::BlackPawn),
+            (board.b_knights, PieceType::BlackKnight),
+            (board.b_bishops, PieceType::BlackBishop),
+            (board.b_rooks, PieceType::BlackRook),
+            (board.b_queen, PieceType::BlackQueen),
+            (board.b_king, PieceType::BlackKing),
+        ];
+
+        let mut key = 0;
+        for (bitboard, piece_type) in pieces {
+            key ^= Zobrist::get_piece_key(piece_type, bitboard);
+        }
+        if board.get_turn() == Turn::Black {
+            key ^= Zobrist::get_side_key();
+        }
+        return key ^ Zobrist::get_castling_key(board) ^ Zobrist::get_en_passant_key(board);
+    }
+
+    //Xor of the keys of every square set in the bitboard
+    pub fn get_piece_key(piece_type: PieceType, bitboard: Bitboard) -> u64 {
+        let piece_index = Zobrist::get_piece_index(piece_type);
+        let mut key = 0;
+        for square in bitboard {
+            key ^= KEYS.pieces[piece_index][square.index()];
+        }
+        return key;
+    }
+
+    pub fn get_side_key() -> u64 {
+        return KEYS.black_to_move;
+    }
+
+    pub fn get_castling_key(board: &Board) -> u64 {
+        let rights = [
+            !(board.has_w_king_side_castle || board.w_king_has_moved),
+            !(board.has_w_queen_side_castle || board.w_king_has_moved),
+            !(board.has_b_king_side_castle || board.b_king_has_moved),
+            !(board.has_b_queen_side_castle || board.b_king_has_moved),
+        ];
+
+        let mut key = 0;
+        for (index, right) in rights.iter().enumerate() {
+            if *right {
+                key ^= KEYS.castling[index];
+            }
+        }
+        return key;
+    }
+
+    //Keyed by the file of the pawn that just did a double push, only when an enemy pawn
+    //stands next to it, otherwise repeated positions would not share a key
+    pub fn get_en_passant_key(board: &Board) -> u64 {
+        let (en_passant, enemy_pawns) = match board.w_en_passant.is_empty() {
+            true => (board.b_en_passant, board.w_pawns),
+            false => (board.w_en_passant, board.b_pawns),
+        };
+        if ((en_passant.east() | en_passant.west()) & enemy_pawns).is_empty() {
+            return 0;
+        }
+        match en_passant.first() {
+            Some(square) => return KEYS.en_passant[square.index() % 8],
+            None => return 0,
+        }
+    }
+
+    fn get_piece_index(piece_type: PieceType) -> usize {
+        match piece_type {
+            PieceType::WhitePawn | PieceType::WhitePawnEnPassant => return 0,
+            PieceType::WhiteKnight => return 1,
+            PieceType::WhiteBishop => return 2,
+            PieceType::WhiteRook => return 3,
+            PieceType::WhiteQueen => return 4,
+            PieceType::WhiteKing => return 5,
+            PieceType::BlackPawn | PieceType::BlackPawnEnPassant => return 6,
+            PieceType::BlackKnight => return 7,
+            PieceType::BlackBishop => return 8,
+            PieceType::BlackRook => return 9,
+            PieceType::BlackQueen => return 10,
+            PieceType::BlackKing => return 11,
+        }
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod zobrist_tests {
+    use crate::engine::board::{board::Board, moves::moves::Move, zobrist::zobrist::Zobrist};
+
+    const POSITIONS: [&str; 5] = [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        "8/8/1k6/2b5/2pP4/8/5K2/8 b - d3 0 1",
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+    ];
+
+    fn play(board: &mut Board, notations: &[&str]) {
+        for notation in notations {
+            let turn = board.get_turn();
+            let legal_move = Move::from_long_algebraic(notation, board, turn).unwrap();
+            board.make_move(legal_move, turn);
+        }
+    }
+
+    #[test]
+    fn key_covers_side_castling_and_en_passant() {
+        let start = Board::new();
+        let keys = [
+            start.get_zobrist_key(),
+            Board::new_from_fenn_notation(
+                "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 1",
+            )
+            .get_zobrist_key(),
+            Board::new_from_fenn_notation(
+                "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w Kkq - 0 1",
+            )
+            .get_zobrist_key(),
+            Board::new_from_fenn_notation(
+                "rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 3",
+            )
+            .get_zobrist_key(),
+            Board::new_from_fenn_notation(
+                "rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 3",
+            )
+            .get_zobrist_key(),
+        ];
+        for (index, key) in keys.iter().enumerate() {
+            for other in &keys[index + 1..] {
+                assert_ne!(key, other);
+            }
+        }
+    }
+
+    #[test]
+    fn en_passant_square_without_capturer_is_not_keyed() {
+        let pushed = Board::new_from_fenn_notation(
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1",
+        );
+        let quiet = Board::new_from_fenn_notation(
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1",
+        );
+        assert_eq!(pushed.get_zobrist_key(), quiet.get_zobrist_key());
+
+        //A pawn on the far edge does not see the other side of the board
+        let edge = Board::new_from_fenn_notation("4k3/8/8/8/P6p/8/8/4K3 b - a3 0 1");
+        let edge_quiet = Board::new_from_fenn_notation("4k3/8/8/8/P6p/8/8/4K3 b - - 0 1");
+        assert_eq!(edge.get_zobrist_key(), edge_quiet.get_zobrist_key());
+    }
+
+    #[test]
+    fn transpositions_share_key() {
+        let mut knights_first = Board::new();
+        play(&mut knights_first, &["g1f3", "g8f6", "b1c3", "b8c6"]);
+        let mut knights_second = Board::new();
+        play(&mut knights_second, &["b1c3", "b8c6", "g1f3", "g8f6"]);
+        assert_eq!(
+            knights_first.get_zobrist_key(),
+            knights_second.get_zobrist_key()
+        );
+
+        //Going back and forth repeats the starting key
+        let mut board = Board::new();
+        play(&mut board, &["g1f3", "g8f6", "f3g1", "f6g8"]);
+        assert_eq!(board.get_zobrist_key(), Board::new().get_zobrist_key());
+    }
+
+    #[test]
+    fn incremental_key_matches_recomputation() {
+        let mut seed: u64 = 0x2545f4914f6cdd1d;
+        for fen in POSITIONS {
+            for _ in 0..4 {
+                let mut board = Board::new_from_fenn_notation(fen);
+                for _ in 0..40 {
+                    let turn = board.get_turn();
+                    let legal_moves = board.get_moves(turn);
+                    if legal_moves.is_empty() {
+                        break;
+                    }
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    let key = board.get_zobrist_key();
+                    let undo = board.make_move(
+                        legal_moves[(seed % legal_moves.len() as u64) as usize],
+                        turn,
+                    );
+                    assert_eq!(board.get_zobrist_key(), Zobrist::get_key(&board));
+                    assert_eq!(
+                        board.get_zobrist_key(),
+                        Board::new_from_fenn_notation(&board.to_fen()).get_zobrist_key()
+                    );
+
+                    board.unmake_move(undo);
+                    assert_eq!(board.get_zobrist_key(), key);
+                    board.make_move(undo.legal, turn);
+                }
+            }
+        }
+    }
+}
@@ -1,28 +1,37 @@
-use std::f64::{INFINITY, NEG_INFINITY};
+use std::{
+    cmp::Reverse,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+};
 
 use crate::engine::{
     board::{
         board::{Board, Turn},
-        position::position::{CastleOptions, LegalMove, Position},
+        moves::moves::Move,
     },
     game::game::Player,
     movement::movement::Movement,
 };
 
-struct EvalMove {
-    legal: LegalMove,
-    eval: f64,
+pub struct EvalMove {
+    pub legal: Move,
+    pub eval: f64,
 }
 
 pub struct Analyzer {}
 
-const SEARCH_DEPTH: i8 = 6;
+pub const SEARCH_DEPTH: i8 = 6;
+//Score of mating on the spot, a mate n plies away scores MATE - n so shorter mates win
+pub const MATE: f64 = 100000.0;
+
+//Shared with the UCI front-end so a running search can be interrupted
+static STOP_SEARCH: AtomicBool = AtomicBool::new(false);
+static SEARCHED_NODES: AtomicU64 = AtomicU64::new(0);
 
 impl Player for Analyzer {
-    fn do_move(board: Board, color: Turn) -> LegalMove {
+    fn do_move(board: Board, color: Turn) -> Move {
         println!("Analyzer does move");
 
-        let legal = Analyzer::minimax(
+        let eval_move = Analyzer::minimax(
             board,
             color,
             color,
@@ -31,16 +40,53 @@ impl Player for Analyzer {
             f64::INFINITY,
         );
 
-        return legal;
+        return eval_move.legal;
     }
 }
 
 impl Analyzer {
-    fn analyze_state(board: Board) -> f64 {
-        let activity_score = Analyzer::get_activity_score(board);
-        let plain_score = Analyzer::get_plain_score(board);
+    pub fn stop() {
+        STOP_SEARCH.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_stopped() -> bool {
+        return STOP_SEARCH.load(Ordering::SeqCst);
+    }
+
+    //Clears the stop flag and the node counter before a new search
+    pub fn reset_search() {
+        STOP_SEARCH.store(false, Ordering::SeqCst);
+        SEARCHED_NODES.store(0, Ordering::SeqCst);
+    }
+
+    pub fn get_searched_nodes() -> u64 {
+        return SEARCHED_NODES.load(Ordering::SeqCst);
+    }
 
-        return plain_score + activity_score;
+    //Scores are from the point of view of the searching side
+    fn analyze_state(board: Board, original_turn: Turn) -> f64 {
+        match original_turn {
+            Turn::White => return Analyzer::get_static_eval(board),
+            Turn::Black => return -Analyzer::get_static_eval(board),
+        }
+    }
+
+    //Static evaluation from white's point of view
+    pub fn get_static_eval(board: Board) -> f64 {
+        if board.is_insufficient_material() {
+            return 0.0;
+        }
+        return Analyzer::get_plain_score(board) + Analyzer::get_activity_score(board);
+    }
+
+    //Captures and promotions by the material they win, so losing captures go after the quiet moves
+    fn order_moves(board: &Board, legal_moves: &mut [Move]) {
+        legal_moves.sort_by_cached_key(|legal_move| {
+            if !legal_move.is_capture() && !legal_move.is_promotion() {
+                return Reverse(0);
+            }
+            return Reverse(board.see(*legal_move));
+        });
     }
 
     pub fn minimax(
@@ -50,77 +96,92 @@ impl Analyzer {
         depth: i8,
         alpha: f64,
         betha: f64,
-    ) -> LegalMove {
-        println!(
-            "Activity: {} Plain:{}",
-            Analyzer::get_activity_score(board),
-            Analyzer::get_plain_score(board)
-        );
-        let legal_moves = board.get_moves(playing_as);
-        let mut eval_move: LegalMove = LegalMove {
-            from: (Position { colum: 0, row: 0 }),
-            to: Position { colum: 0, row: 0 },
-            castle: CastleOptions::None,
+    ) -> EvalMove {
+        let mut legal_moves = board.get_moves(playing_as);
+        Analyzer::order_moves(&board, &mut legal_moves);
+        //Keep a real move even when every line loses
+        let mut eval_move: Move = match legal_moves.first() {
+            Some(legal_move) => *legal_move,
+            None => Move::NULL,
         };
         let mut max_value = f64::NEG_INFINITY;
+        let mut alpha = alpha;
+        let mut analize_board = board;
         for legal_move in legal_moves.to_owned() {
-            println!("{}", legal_move);
-            let mut analize_board = board;
-            analize_board.do_move(legal_move, playing_as);
+            if Analyzer::is_stopped() {
+                break;
+            }
+            let undo = analize_board.make_move(legal_move, playing_as);
             let minimax_score = Analyzer::r_minimax(
-                analize_board,
+                &mut analize_board,
                 Movement::get_oposite_color(playing_as),
                 player_color,
                 depth - 1,
+                1,
                 alpha,
                 betha,
             );
+            analize_board.unmake_move(undo);
 
             if (minimax_score > max_value) {
                 max_value = minimax_score;
                 eval_move = legal_move;
             }
+            alpha = alpha.max(minimax_score);
         }
 
-        return eval_move;
+        return EvalMove {
+            legal: eval_move,
+            eval: max_value,
+        };
     }
 
     fn r_minimax(
-        board: Board,
+        board: &mut Board,
         playing_As: Turn,
         original_turn: Turn,
         depth: i8,
+        ply: i8,
         mut alpha: f64,
         mut betha: f64,
     ) -> f64 {
         // println!("Alpha: {} Beetha: {}", alpha, betha);
-        if depth == 0 {
-            return Analyzer::analyze_state(board);
+        SEARCHED_NODES.fetch_add(1, Ordering::Relaxed);
+        if Analyzer::is_stopped() {
+            return Analyzer::analyze_state(*board, original_turn);
         }
-        let legal_moves = board.get_moves(playing_As);
 
+        //Mate and stalemate are scored before the depth cutoff so leaves see them too
+        let mut legal_moves = board.get_moves(playing_As);
         if legal_moves.len() == 0 {
-            if Movement::check_for_checkmate(Turn::White, board) {
-                return INFINITY;
-            };
-            if Movement::check_for_checkmate(Turn::White, board) {
-                return NEG_INFINITY;
-            };
+            if !board.is_in_check(playing_As) {
+                return 0.0;
+            }
+            let mate_score = MATE - f64::from(ply);
+            if playing_As == original_turn {
+                return -mate_score;
+            }
+            return mate_score;
         }
+        if depth == 0 {
+            return Analyzer::analyze_state(*board, original_turn);
+        }
+        Analyzer::order_moves(board, &mut legal_moves);
 
         if playing_As == original_turn {
             let mut max_value = f64::NEG_INFINITY;
             for legal_move in legal_moves.to_owned() {
-                let mut analize_board = board;
-                analize_board.do_move(legal_move, playing_As);
+                let undo = board.make_move(legal_move, playing_As);
                 let eval = Analyzer::r_minimax(
-                    analize_board,
+                    board,
                     Movement::get_oposite_color(playing_As),
                     original_turn,
                     depth - 1,
+                    ply + 1,
                     alpha,
                     betha,
                 );
+                board.unmake_move(undo);
                 max_value = max_value.max(eval.to_owned());
                 alpha = alpha.max(eval.to_owned());
 
@@ -133,20 +194,21 @@ impl Analyzer {
         } else {
             let mut min_value = f64::INFINITY;
             for legal_move in legal_moves {
-                let mut analize_board = board;
-                analize_board.do_move(legal_move, playing_As);
+                let undo = board.make_move(legal_move, playing_As);
                 let eval = Analyzer::r_minimax(
-                    analize_board,
+                    board,
                     Movement::get_oposite_color(playing_As),
                     original_turn,
                     depth - 1,
+                    ply + 1,
                     alpha,
                     betha,
                 );
+                board.unmake_move(undo);
                 min_value = min_value.min(eval.to_owned());
                 betha = betha.min(eval);
 
-                if betha <= alpha || eval <= betha {
+                if betha <= alpha {
                     break;
                 }
             }
@@ -156,29 +218,17 @@ impl Analyzer {
     }
 
     pub fn get_plain_score(board: Board) -> f64 {
-        let mut pawns_score_white: f64 = 0.0;
-        let mut knights_score_white: f64 = 0.0;
-        let mut bishops_score_white: f64 = 0.0;
-        let mut rooks_score_white: f64 = 0.0;
-        let mut queen_score_white: f64 = 0.0;
-
-        let mut pawns_score_black: f64 = 0.0;
-        let mut knights_score_black: f64 = 0.0;
-        let mut bishops_score_black: f64 = 0.0;
-        let mut rooks_score_black: f64 = 0.0;
-        let mut queen_score_black: f64 = 0.0;
-
-        pawns_score_black = f64::from(Movement::pieces_count(board.b_pawns));
-        knights_score_black = f64::from(Movement::pieces_count(board.b_knights));
-        bishops_score_black = f64::from(Movement::pieces_count(board.b_bishops));
-        rooks_score_black = f64::from(Movement::pieces_count(board.b_rooks));
-        queen_score_black = f64::from(Movement::pieces_count(board.b_queen));
-
-        pawns_score_white = f64::from(Movement::pieces_count(board.w_pawns));
-        knights_score_white = f64::from(Movement::pieces_count(board.w_knights));
-        bishops_score_white = f64::from(Movement::pieces_count(board.w_bishops));
-        rooks_score_white = f64::from(Movement::pieces_count(board.w_rooks));
-        queen_score_white = f64::from(Movement::pieces_count(board.w_queen));
+        let pawns_score_black = f64::from(Movement::pieces_count(board.b_pawns));
+        let knights_score_black = f64::from(Movement::pieces_count(board.b_knights));
+        let bishops_score_black = f64::from(Movement::pieces_count(board.b_bishops));
+        let rooks_score_black = f64::from(Movement::pieces_count(board.b_rooks));
+        let queen_score_black = f64::from(Movement::pieces_count(board.b_queen));
+
+        let pawns_score_white = f64::from(Movement::pieces_count(board.w_pawns));
+        let knights_score_white = f64::from(Movement::pieces_count(board.w_knights));
+        let bishops_score_white = f64::from(Movement::pieces_count(board.w_bishops));
+        let rooks_score_white = f64::from(Movement::pieces_count(board.w_rooks));
+        let queen_score_white = f64::from(Movement::pieces_count(board.w_queen));
 
         return pawns_score_white
             + knights_score_white * 3.0
@@ -193,55 +243,43 @@ impl Analyzer {
     }
 
     pub fn get_activity_score(board: Board) -> f64 {
-        let mut pawns_score_black: f64 = 0.0;
-        let mut knights_score_black: f64 = 0.0;
-        let mut bishops_score_black: f64 = 0.0;
-        let mut rooks_score_black: f64 = 0.0;
-        let mut queen_score_black: f64 = 0.0;
-
-        let mut pawns_score_white: f64 = 0.0;
-        let mut knights_score_white: f64 = 0.0;
-        let mut bishops_score_white: f64 = 0.0;
-        let mut rooks_score_white: f64 = 0.0;
-        let mut queen_score_white: f64 = 0.0;
-
-        pawns_score_black = f64::from(Movement::pieces_count(
+        let pawns_score_black = f64::from(Movement::pieces_count(
             board.pub_get_pawn_moves_bitboard(board.b_pawns, Turn::Black),
         ));
 
-        knights_score_black = f64::from(Movement::pieces_count(
+        let knights_score_black = f64::from(Movement::pieces_count(
             board.pub_get_knigth_moves_bitboard(board.b_knights, Turn::Black),
         ));
 
-        bishops_score_black = f64::from(Movement::pieces_count(
+        let bishops_score_black = f64::from(Movement::pieces_count(
             board.pub_get_bishop_moves_bitboard(board.b_bishops, Turn::Black),
         ));
 
-        rooks_score_black = f64::from(Movement::pieces_count(
+        let rooks_score_black = f64::from(Movement::pieces_count(
             board.pub_get_rook_moves_bitboard(board.b_rooks, Turn::Black),
         ));
 
-        queen_score_black = f64::from(Movement::pieces_count(
+        let queen_score_black = f64::from(Movement::pieces_count(
             board.pub_get_queen_moves_bitboard(board.b_queen, Turn::Black),
         ));
 
-        pawns_score_white = f64::from(Movement::pieces_count(
+        let pawns_score_white = f64::from(Movement::pieces_count(
             board.pub_get_pawn_moves_bitboard(board.w_pawns, Turn::White),
         ));
 
-        knights_score_white = f64::from(Movement::pieces_count(
+        let knights_score_white = f64::from(Movement::pieces_count(
             board.pub_get_knigth_moves_bitboard(board.w_knights, Turn::White),
         ));
 
-        bishops_score_white = f64::from(Movement::pieces_count(
+        let bishops_score_white = f64::from(Movement::pieces_count(
             board.pub_get_bishop_moves_bitboard(board.w_bishops, Turn::White),
         ));
 
-        rooks_score_white = f64::from(Movement::pieces_count(
+        let rooks_score_white = f64::from(Movement::pieces_count(
             board.pub_get_rook_moves_bitboard(board.w_rooks, Turn::White),
         ));
 
-        queen_score_white = f64::from(Movement::pieces_count(
+        let queen_score_white = f64::from(Movement::pieces_count(
             board.pub_get_queen_moves_bitboard(board.w_queen, Turn::White),
         ));
 
@@ -259,46 +297,37 @@ impl Analyzer {
     }
 
     pub fn get_attacked_score(turn: Turn, board: Board) -> f64 {
-        let mut pawns_score: f64 = 0.0;
-        let mut knights_score: f64 = 0.0;
-        let mut bishops_score: f64 = 0.0;
-        let mut rooks_score: f64 = 0.0;
-        let mut queen_score: f64 = 0.0;
-        if matches!(turn, Turn::Black) {
-            pawns_score = f64::from(Movement::pieces_count(
-                board.pub_get_pawn_moves_bitboard(board.b_pawns, Turn::Black),
-            ));
-            knights_score = f64::from(Movement::pieces_count(
-                board.pub_get_knigth_moves_bitboard(board.b_knights, Turn::Black),
-            ));
-            bishops_score = f64::from(Movement::pieces_count(
-                board.pub_get_bishop_moves_bitboard(board.b_bishops, Turn::Black),
-            ));
-            rooks_score = f64::from(Movement::pieces_count(
-                board.pub_get_rook_moves_bitboard(board.b_rooks, Turn::Black),
-            ));
-            queen_score = f64::from(Movement::pieces_count(
-                board.pub_get_queen_moves_bitboard(board.b_queen, Turn::Black),
-            ));
-        }
-
-        if matches!(turn, Turn::White) {
-            pawns_score = f64::from(Movement::pieces_count(
-                board.pub_get_pawn_moves_bitboard(board.w_pawns, Turn::White),
-            ));
-            knights_score = f64::from(Movement::pieces_count(
-                board.pub_get_knigth_moves_bitboard(board.w_knights, Turn::White),
-            ));
-            bishops_score = f64::from(Movement::pieces_count(
-                board.pub_get_bishop_moves_bitboard(board.w_bishops, Turn::White),
-            ));
-            rooks_score = f64::from(Movement::pieces_count(
-                board.pub_get_rook_moves_bitboard(board.w_rooks, Turn::White),
-            ));
-            queen_score = f64::from(Movement::pieces_count(
-                board.pub_get_queen_moves_bitboard(board.w_queen, Turn::White),
-            ));
-        }
+        let (pawns, knights, bishops, rooks, queens) = match turn {
+            Turn::White => (
+                board.w_pawns,
+                board.w_knights,
+                board.w_bishops,
+                board.w_rooks,
+                board.w_queen,
+            ),
+            Turn::Black => (
+                board.b_pawns,
+                board.b_knights,
+                board.b_bishops,
+                board.b_rooks,
+                board.b_queen,
+            ),
+        };
+        let pawns_score = f64::from(Movement::pieces_count(
+            board.pub_get_pawn_moves_bitboard(pawns, turn),
+        ));
+        let knights_score = f64::from(Movement::pieces_count(
+            board.pub_get_knigth_moves_bitboard(knights, turn),
+        ));
+        let bishops_score = f64::from(Movement::pieces_count(
+            board.pub_get_bishop_moves_bitboard(bishops, turn),
+        ));
+        let rooks_score = f64::from(Movement::pieces_count(
+            board.pub_get_rook_moves_bitboard(rooks, turn),
+        ));
+        let queen_score = f64::from(Movement::pieces_count(
+            board.pub_get_queen_moves_bitboard(queens, turn),
+        ));
         return pawns_score + knights_score + bishops_score + rooks_score + queen_score;
     }
 }
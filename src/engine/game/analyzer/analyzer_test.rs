@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod analyzer_tests {
+    use crate::engine::{
+        board::board::{Board, BoardStatus},
+        game::analyzer::analyzer::{Analyzer, MATE},
+    };
+
+    fn best_move_status(fen: &str, depth: i8) -> BoardStatus {
+        let mut board = Board::new_from_fenn_notation(fen);
+        let turn = board.get_turn();
+        Analyzer::reset_search();
+        let eval_move =
+            Analyzer::minimax(board, turn, turn, depth, f64::NEG_INFINITY, f64::INFINITY);
+        board.make_move(eval_move.legal, turn);
+        return board.status();
+    }
+
+    #[test]
+    fn finds_mate_in_one_for_white() {
+        assert_eq!(
+            best_move_status("6k1/5ppp/8/8/8/8/8/3R2K1 w - - 0 1", 2),
+            BoardStatus::Checkmate
+        );
+    }
+
+    #[test]
+    fn finds_mate_in_one_for_black() {
+        assert_eq!(
+            best_move_status("3r2k1/8/8/8/8/8/5PPP/6K1 b - - 0 1", 2),
+            BoardStatus::Checkmate
+        );
+    }
+
+    #[test]
+    fn prefers_mate_over_stalemate() {
+        assert_eq!(
+            best_move_status("k7/2Q5/1K6/8/8/8/8/8 w - - 0 1", 2),
+            BoardStatus::Checkmate
+        );
+    }
+
+    fn root_eval(fen: &str, depth: i8) -> f64 {
+        let board = Board::new_from_fenn_notation(fen);
+        let turn = board.get_turn();
+        Analyzer::reset_search();
+        return Analyzer::minimax(board, turn, turn, depth, f64::NEG_INFINITY, f64::INFINITY).eval;
+    }
+
+    #[test]
+    fn mates_at_the_leaves_are_scored() {
+        //Back rank mate in one, seen at depth 1 now that leaves check for mate
+        assert_eq!(
+            best_move_status("6k1/5ppp/8/8/8/8/8/3R2K1 w - - 0 1", 1),
+            BoardStatus::Checkmate
+        );
+        assert_eq!(
+            root_eval("6k1/5ppp/8/8/8/8/8/3R2K1 w - - 0 1", 1),
+            MATE - 1.0
+        );
+        //Black is mated on the second ply whatever it plays
+        assert_eq!(root_eval("k7/8/1K6/8/8/8/8/7Q b - - 0 1", 2), -(MATE - 2.0));
+    }
+
+    #[test]
+    fn shorter_mates_score_higher() {
+        //Mate in one is on the board, deeper searches must keep preferring it
+        let fen = "6k1/5ppp/8/8/8/8/5PPP/3RR1K1 w - - 0 1";
+        for depth in [1, 3, 4] {
+            assert_eq!(root_eval(fen, depth), MATE - 1.0, "depth {}", depth);
+            assert_eq!(best_move_status(fen, depth), BoardStatus::Checkmate);
+        }
+    }
+
+    #[test]
+    fn dead_positions_score_as_draws() {
+        let board = Board::new_from_fenn_notation("8/8/8/4k3/8/8/8/4KN2 w - - 0 1");
+        let turn = board.get_turn();
+        Analyzer::reset_search();
+        let eval_move = Analyzer::minimax(board, turn, turn, 1, f64::NEG_INFINITY, f64::INFINITY);
+        assert_eq!(eval_move.eval, 0.0);
+    }
+
+    const SYMMETRY_POSITIONS: [&str; 10] = [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "rnbqkbnr/pp1ppppp/8/2p5/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10",
+        "8/8/1k6/2b5/2pP4/8/5K2/8 b - d3 0 1",
+        "6k1/5ppp/8/8/8/8/8/3R2K1 w - - 0 1",
+        "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9",
+    ];
+
+    //Every position where eval(pos) != -eval(flip(pos)) or eval(pos) != eval(mirror(pos))
+    fn get_eval_asymmetries(fens: &[&str]) -> Vec<String> {
+        let mut asymmetries: Vec<String> = Vec::new();
+        for fen in fens {
+            let board = Board::new_from_fenn_notation(fen);
+            let eval = Analyzer::get_static_eval(board);
+            let flipped_eval = Analyzer::get_static_eval(board.flipped());
+            let mirrored_eval = Analyzer::get_static_eval(board.mirrored());
+            if (eval + flipped_eval).abs() > 1e-9 {
+                asymmetries.push(format!("{} eval {} flipped {}", fen, eval, flipped_eval));
+            }
+            if (eval - mirrored_eval).abs() > 1e-9 {
+                asymmetries.push(format!("{} eval {} mirrored {}", fen, eval, mirrored_eval));
+            }
+        }
+        return asymmetries;
+    }
+
+    #[test]
+    fn evaluation_is_symmetric() {
+        let asymmetries = get_eval_asymmetries(&SYMMETRY_POSITIONS);
+        assert!(asymmetries.is_empty(), "{}", asymmetries.join("\n"));
+    }
+}
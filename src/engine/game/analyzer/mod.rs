@@ -1 +1,2 @@
 pub mod analyzer;
+mod analyzer_test;
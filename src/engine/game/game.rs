@@ -1,14 +1,16 @@
-use std::process::exit;
-
 use crate::engine::{
     board::{
-        board::{Board, Turn},
-        position::position::LegalMove,
+        board::{Board, BoardStatus, Turn, UndoMove},
+        moves::moves::Move,
     },
     movement::movement::Movement,
 };
 
-use super::{analyzer::analyzer::Analyzer, human::human::Human};
+use super::{
+    analyzer::analyzer::Analyzer,
+    human::human::Human,
+    result::result::{GameEndReason, GameResult, GameStatus},
+};
 
 pub enum PlayerTypes {
     AI,
@@ -21,15 +23,23 @@ enum Players {
 }
 
 pub trait Player {
-    fn do_move(board: Board, color: Turn) -> LegalMove;
+    fn do_move(board: Board, color: Turn) -> Move;
 }
 
 pub struct Game {
     white: Players,
     black: Players,
     board: Board,
+    history: Vec<UndoMove>,
+    //Zobrist key of every position reached, starting with the initial one
+    positions: Vec<u64>,
+    status: GameStatus,
 }
 
+//Half moves without a capture or a pawn move
+const FIFTY_MOVE_RULE: u16 = 100;
+const SEVENTY_FIVE_MOVE_RULE: u16 = 150;
+
 impl Game {
     pub fn setup(white: PlayerTypes, black: PlayerTypes) -> Game {
         let white_player: Players;
@@ -48,10 +58,14 @@ impl Game {
             black_player = Players::Player;
         }
 
+        let board = Board::new();
         return Game {
             white: white_player,
             black: black_player,
-            board: Board::new(),
+            board: board,
+            history: Vec::new(),
+            positions: vec![board.get_zobrist_key()],
+            status: GameStatus::Ongoing,
         };
     }
     pub fn setup_from_fenn(
@@ -74,61 +88,162 @@ impl Game {
             black_player = Players::Player;
         }
 
+        let board = Board::new_from_fenn_notation(board_state);
         return Game {
             white: white_player,
             black: black_player,
-            board: Board::new_from_fenn_notation(board_state),
+            board: board,
+            history: Vec::new(),
+            positions: vec![board.get_zobrist_key()],
+            status: GameStatus::Ongoing,
         };
     }
 
-    pub fn take_turn(&mut self) {
-        if self.is_checkmate(self.board.get_turn()) {
-            print!("CheckMate!");
-            exit(0);
+    //Fischer Random game, the seed picks one of the 960 start positions
+    pub fn setup_960(seed: u64, white: PlayerTypes, black: PlayerTypes) -> Game {
+        let mut game = Game::setup(white, black);
+        game.board = Board::new_960(seed);
+        game.positions = vec![game.board.get_zobrist_key()];
+        return game;
+    }
+
+    //Plays one move and returns how the game stands afterwards
+    pub fn take_turn(&mut self) -> GameStatus {
+        if self.update_status().is_finished() {
+            return self.status;
         }
         self.board.print_board_self("Board");
         match self.board.get_turn() {
             Turn::White => {
                 println!("White turn");
-                self.take_turn_white();
-                self.board.set_turn(Turn::Black);
+                return self.take_turn_white();
             }
             Turn::Black => {
                 println!("Black turn");
-                self.take_turn_black();
-                self.board.set_turn(Turn::White);
+                return self.take_turn_black();
             }
         }
     }
 
-    pub fn is_checkmate(&self, color: Turn) -> bool {
-        match self.board.get_turn() {
-            Turn::White => Movement::check_for_checkmate(color, self.get_board()),
-            Turn::Black => Movement::check_for_checkmate(color, self.get_board()),
-        }
+    pub fn get_status(&self) -> GameStatus {
+        return self.status;
     }
 
-    fn take_turn_white(&mut self) {
-        match self.white {
-            Players::Robot => {
-                self.board
-                    .do_move(Analyzer::do_move(self.board, Turn::White), Turn::White);
+    fn update_status(&mut self) -> GameStatus {
+        if self.status.is_finished() {
+            return self.status;
+        }
+        let turn = self.board.get_turn();
+        let repetitions = self.get_repetitions();
+        let half_moves = self.board.half_move_count;
+
+        //Mate on the last move counts over the move rules
+        self.status = match self.board.status() {
+            BoardStatus::Checkmate => GameStatus::Finished(
+                GameResult::win_for(Movement::get_oposite_color(turn)),
+                GameEndReason::Checkmate,
+            ),
+            BoardStatus::Stalemate => {
+                GameStatus::Finished(GameResult::Draw, GameEndReason::Stalemate)
+            }
+            BoardStatus::Ongoing if self.board.is_insufficient_material() => {
+                GameStatus::Finished(GameResult::Draw, GameEndReason::InsufficientMaterial)
             }
-            Players::Player => self
-                .board
-                .do_move(Human::do_move(self.board, Turn::White), Turn::White),
+            BoardStatus::Ongoing if repetitions >= 5 => {
+                GameStatus::Finished(GameResult::Draw, GameEndReason::FivefoldRepetition)
+            }
+            BoardStatus::Ongoing if half_moves >= SEVENTY_FIVE_MOVE_RULE => {
+                GameStatus::Finished(GameResult::Draw, GameEndReason::SeventyFiveMoveRule)
+            }
+            BoardStatus::Ongoing if repetitions >= 3 => {
+                GameStatus::DrawClaimable(GameEndReason::ThreefoldRepetition)
+            }
+            BoardStatus::Ongoing if half_moves >= FIFTY_MOVE_RULE => {
+                GameStatus::DrawClaimable(GameEndReason::FiftyMoveRule)
+            }
+            BoardStatus::Ongoing => GameStatus::Ongoing,
+        };
+        return self.status;
+    }
+
+    //Times the current position has been reached, including now
+    pub fn get_repetitions(&self) -> usize {
+        let key = self.board.get_zobrist_key();
+        return self.positions.iter().filter(|x| **x == key).count();
+    }
+
+    pub fn claim_draw(&mut self) -> GameStatus {
+        if let GameStatus::DrawClaimable(reason) = self.status {
+            self.status = GameStatus::Finished(GameResult::Draw, reason);
+        }
+        return self.status;
+    }
+
+    pub fn resign(&mut self, color: Turn) -> GameStatus {
+        if !self.status.is_finished() {
+            self.status = GameStatus::Finished(
+                GameResult::win_for(Movement::get_oposite_color(color)),
+                GameEndReason::Resignation,
+            );
+        }
+        return self.status;
+    }
+
+    pub fn lose_on_time(&mut self, color: Turn) -> GameStatus {
+        if !self.status.is_finished() {
+            self.status = GameStatus::Finished(
+                GameResult::win_for(Movement::get_oposite_color(color)),
+                GameEndReason::Timeout,
+            );
+        }
+        return self.status;
+    }
+
+    pub fn agree_draw(&mut self) -> GameStatus {
+        if !self.status.is_finished() {
+            self.status = GameStatus::Finished(GameResult::Draw, GameEndReason::Agreement);
+        }
+        return self.status;
+    }
+
+    fn take_turn_white(&mut self) -> GameStatus {
+        let legal_move = match self.white {
+            Players::Robot => Analyzer::do_move(self.board, Turn::White),
+            Players::Player => Human::do_move(self.board, Turn::White),
         };
+        return self.play_move(legal_move, Turn::White);
     }
 
-    fn take_turn_black(&mut self) {
-        match self.black {
-            Players::Robot => self
-                .board
-                .do_move(Analyzer::do_move(self.board, Turn::Black), Turn::Black),
-            Players::Player => self
-                .board
-                .do_move(Human::do_move(self.board, Turn::Black), Turn::Black),
+    fn take_turn_black(&mut self) -> GameStatus {
+        let legal_move = match self.black {
+            Players::Robot => Analyzer::do_move(self.board, Turn::Black),
+            Players::Player => Human::do_move(self.board, Turn::Black),
         };
+        return self.play_move(legal_move, Turn::Black);
+    }
+
+    pub fn play_move(&mut self, legal_move: Move, color: Turn) -> GameStatus {
+        if self.status.is_finished() {
+            return self.status;
+        }
+        let undo = self.board.make_move(legal_move, color);
+        self.history.push(undo);
+        self.positions.push(self.board.get_zobrist_key());
+        return self.update_status();
+    }
+
+    //Takes back the last move, returns false when there is nothing to take back
+    pub fn takeback(&mut self) -> bool {
+        match self.history.pop() {
+            Some(undo) => {
+                self.board.unmake_move(undo);
+                self.positions.pop();
+                self.status = GameStatus::Ongoing;
+                self.update_status();
+                return true;
+            }
+            None => return false,
+        }
     }
 
     pub fn get_board(&self) -> Board {
@@ -0,0 +1,234 @@
+#[cfg(test)]
+mod game_tests {
+    use crate::engine::{
+        board::{
+            board::{Board, Turn},
+            moves::moves::Move,
+        },
+        game::{
+            game::{Game, PlayerTypes},
+            result::result::{GameEndReason, GameResult, GameStatus},
+        },
+    };
+
+    fn play(game: &mut Game, notation: &str) {
+        let board = game.get_board();
+        let turn = board.get_turn();
+        let legal_move = Move::from_long_algebraic(notation, &board, turn).unwrap();
+        game.play_move(legal_move, turn);
+    }
+
+    #[test]
+    fn takeback_restores_previous_positions() {
+        let mut game = Game::setup(PlayerTypes::HUMAN, PlayerTypes::HUMAN);
+        play(&mut game, "e2e4");
+        let after_e4 = game.get_board();
+        play(&mut game, "d7d5");
+        play(&mut game, "e4d5");
+
+        assert!(game.takeback());
+        assert!(game.takeback());
+        assert!(game.get_board() == after_e4);
+        assert!(game.takeback());
+        assert!(game.get_board() == Board::new());
+        assert!(!game.takeback());
+    }
+
+    #[test]
+    fn checkmate_finishes_the_game() {
+        let mut game = Game::setup(PlayerTypes::HUMAN, PlayerTypes::HUMAN);
+        for notation in ["f2f3", "e7e5", "g2g4"] {
+            play(&mut game, notation);
+            assert!(game.get_status() == GameStatus::Ongoing);
+        }
+        play(&mut game, "d8h4");
+        assert!(
+            game.get_status()
+                == GameStatus::Finished(GameResult::BlackWins, GameEndReason::Checkmate)
+        );
+        assert_eq!(GameResult::BlackWins.to_string(), "0-1");
+
+        //Taking the mating move back reopens the game
+        assert!(game.takeback());
+        assert!(game.get_status() == GameStatus::Ongoing);
+    }
+
+    #[test]
+    fn stalemate_is_a_draw() {
+        let mut game = Game::setup_from_fenn(
+            "7k/8/8/5Q2/8/8/8/K7 w - - 0 1",
+            PlayerTypes::HUMAN,
+            PlayerTypes::HUMAN,
+        );
+        play(&mut game, "f5g6");
+        assert!(
+            game.get_status() == GameStatus::Finished(GameResult::Draw, GameEndReason::Stalemate)
+        );
+    }
+
+    #[test]
+    fn resignation_timeout_and_agreement() {
+        let mut game = Game::setup(PlayerTypes::HUMAN, PlayerTypes::HUMAN);
+        assert!(
+            game.resign(Turn::White)
+                == GameStatus::Finished(GameResult::BlackWins, GameEndReason::Resignation)
+        );
+        //The first result stands
+        assert!(
+            game.agree_draw()
+                == GameStatus::Finished(GameResult::BlackWins, GameEndReason::Resignation)
+        );
+
+        let mut game = Game::setup(PlayerTypes::HUMAN, PlayerTypes::HUMAN);
+        assert!(
+            game.lose_on_time(Turn::Black)
+                == GameStatus::Finished(GameResult::WhiteWins, GameEndReason::Timeout)
+        );
+
+        let mut game = Game::setup(PlayerTypes::HUMAN, PlayerTypes::HUMAN);
+        assert!(
+            game.agree_draw() == GameStatus::Finished(GameResult::Draw, GameEndReason::Agreement)
+        );
+    }
+
+    #[test]
+    fn threefold_is_claimable_and_fivefold_is_automatic() {
+        let mut game = Game::setup(PlayerTypes::HUMAN, PlayerTypes::HUMAN);
+        let shuffle = ["g1f3", "g8f6", "f3g1", "f6g8"];
+
+        for notation in shuffle {
+            play(&mut game, notation);
+        }
+        assert!(game.get_status() == GameStatus::Ongoing);
+        assert_eq!(game.get_repetitions(), 2);
+
+        for notation in shuffle {
+            play(&mut game, notation);
+        }
+        assert_eq!(game.get_repetitions(), 3);
+        assert!(game.get_status() == GameStatus::DrawClaimable(GameEndReason::ThreefoldRepetition));
+
+        //Playing on is allowed until the fifth time
+        for _ in 0..2 {
+            for notation in shuffle {
+                play(&mut game, notation);
+            }
+        }
+        assert!(
+            game.get_status()
+                == GameStatus::Finished(GameResult::Draw, GameEndReason::FivefoldRepetition)
+        );
+    }
+
+    #[test]
+    fn repetition_ignores_an_en_passant_square_nobody_can_use() {
+        let mut game = Game::setup(PlayerTypes::HUMAN, PlayerTypes::HUMAN);
+        let moves = [
+            "e2e4", "g8f6", "g1f3", "f6g8", "f3g1", "g8f6", "g1f3", "f6g8", "f3g1",
+        ];
+        for notation in moves {
+            play(&mut game, notation);
+        }
+        assert_eq!(game.get_repetitions(), 3);
+        assert!(game.get_status() == GameStatus::DrawClaimable(GameEndReason::ThreefoldRepetition));
+    }
+
+    #[test]
+    fn claiming_a_draw() {
+        let mut game = Game::setup(PlayerTypes::HUMAN, PlayerTypes::HUMAN);
+        //Nothing to claim yet
+        assert!(game.claim_draw() == GameStatus::Ongoing);
+
+        for _ in 0..2 {
+            for notation in ["b1c3", "b8c6", "c3b1", "c6b8"] {
+                play(&mut game, notation);
+            }
+        }
+        assert!(
+            game.claim_draw()
+                == GameStatus::Finished(GameResult::Draw, GameEndReason::ThreefoldRepetition)
+        );
+    }
+
+    #[test]
+    fn fifty_and_seventy_five_move_rules() {
+        let mut game = Game::setup_from_fenn(
+            "4k3/8/8/8/8/8/4P3/R3K3 w - - 98 80",
+            PlayerTypes::HUMAN,
+            PlayerTypes::HUMAN,
+        );
+        play(&mut game, "a1a2");
+        assert!(game.get_status() == GameStatus::Ongoing);
+        play(&mut game, "e8d8");
+        assert!(game.get_status() == GameStatus::DrawClaimable(GameEndReason::FiftyMoveRule));
+        assert_eq!(
+            game.get_board().to_fen(),
+            "3k4/8/8/8/8/8/R3P3/4K3 w - - 100 81"
+        );
+
+        //A pawn move resets the clock
+        play(&mut game, "e2e3");
+        assert!(game.get_status() == GameStatus::Ongoing);
+        assert_eq!(game.get_board().half_move_count, 0);
+
+        let mut game = Game::setup_from_fenn(
+            "4k3/8/8/8/8/8/4P3/R3K3 b - - 149 300",
+            PlayerTypes::HUMAN,
+            PlayerTypes::HUMAN,
+        );
+        play(&mut game, "e8d8");
+        assert!(
+            game.get_status()
+                == GameStatus::Finished(GameResult::Draw, GameEndReason::SeventyFiveMoveRule)
+        );
+    }
+
+    #[test]
+    fn checkmate_beats_the_move_rules() {
+        let mut game = Game::setup_from_fenn(
+            "6k1/5ppp/8/8/8/8/8/3R2K1 w - - 149 200",
+            PlayerTypes::HUMAN,
+            PlayerTypes::HUMAN,
+        );
+        play(&mut game, "d1d8");
+        assert!(
+            game.get_status()
+                == GameStatus::Finished(GameResult::WhiteWins, GameEndReason::Checkmate)
+        );
+    }
+
+    #[test]
+    fn insufficient_material_ends_the_game() {
+        let mut game = Game::setup_from_fenn(
+            "8/8/4k3/3r4/8/3K4/8/6N1 w - - 0 1",
+            PlayerTypes::HUMAN,
+            PlayerTypes::HUMAN,
+        );
+        assert!(game.get_status() == GameStatus::Ongoing);
+        play(&mut game, "d3c4");
+        play(&mut game, "d5d1");
+        play(&mut game, "g1e2");
+        assert!(game.get_status() == GameStatus::Ongoing);
+        play(&mut game, "d1d4");
+        play(&mut game, "c4d4");
+        assert!(
+            game.get_status()
+                == GameStatus::Finished(GameResult::Draw, GameEndReason::InsufficientMaterial)
+        );
+    }
+
+    #[test]
+    fn setup_960_is_deterministic() {
+        let game = Game::setup_960(42, PlayerTypes::HUMAN, PlayerTypes::HUMAN);
+        let board = game.get_board();
+        assert!(board.chess960);
+        assert!(board.get_turn() == Turn::White);
+        assert_eq!(
+            board.to_fen(),
+            Game::setup_960(42, PlayerTypes::HUMAN, PlayerTypes::HUMAN)
+                .get_board()
+                .to_fen()
+        );
+        assert!(board == Board::new_960(42));
+    }
+}
@@ -3,29 +3,37 @@ use std::io::stdin;
 use crate::engine::{
     board::{
         board::{Board, Turn},
-        position::position::{LegalMove, Position},
+        moves::moves::Move,
+        position::position::{LegalMove, Position, PromotionOptions},
     },
     game::game::Player,
 };
 
-const COLUMNS: [&'static str; 8] = ["a", "b", "c", "d", "e", "f", "g", "h"];
-
 pub struct Human {}
 
 impl Human {
     pub fn new() -> Human {
         return Human {};
     }
+
+    //Promotion piece after the destination square, e8=N or e8n
+    fn get_promotion(to_str: &str) -> PromotionOptions {
+        let piece = to_str.trim().trim_end_matches('+').chars().last();
+        match piece.and_then(PromotionOptions::from_char) {
+            Some(promotion) if to_str.trim().len() > 2 => return promotion,
+            _ => return PromotionOptions::None,
+        }
+    }
 }
 
 impl Player for Human {
-    fn do_move(board: Board, color: Turn) -> LegalMove {
+    fn do_move(board: Board, color: Turn) -> Move {
         let legal_moves = board.get_moves(color);
 
-        let mut legal_move: LegalMove;
+        let mut legal_move: Move;
 
         loop {
-            println!("Enter your move (a1;e2):");
+            println!("Enter your move (Nf3, e8=Q, O-O or a1;e2, a7;a8=N to underpromote):");
             let mut buffer = String::new();
 
             // `read_line` returns `Result` of bytes read
@@ -33,50 +41,50 @@ impl Player for Human {
 
             let input: Vec<&str> = buffer.trim().split(";").collect();
 
+            //Anything without the separator is read as SAN
             if input.len() < 2 {
-                match buffer.trim() {
-                    "O-O" => {
-                        return LegalMove {
-                            from: Position { colum: 0, row: 0 },
-                            to: Position { colum: 0, row: 0 },
-                            castle:
-                                crate::engine::board::position::position::CastleOptions::KingSide,
-                        };
+                match board.parse_san(&buffer) {
+                    Ok(san_move) => {
+                        println!("MOVE: {}", board.move_to_san(san_move));
+                        return san_move;
                     }
-                    "O-O-O" => {
-                        return LegalMove {
-                            from: Position { colum: 0, row: 0 },
-                            to: Position { colum: 0, row: 0 },
-                            castle:
-                                crate::engine::board::position::position::CastleOptions::QueenSide,
-                        };
+                    Err(error) => {
+                        println!("{}", error);
+                        continue;
                     }
-                    _ => {}
                 }
             }
 
             let from_str = input[0];
             let to_str = input[1];
 
-            let from: Vec<&str> = from_str.trim().split("").collect();
-            let to: Vec<&str> = to_str.split("").collect();
+            let promotion = Human::get_promotion(to_str);
 
-            let from_column = 7 - COLUMNS.iter().position(|&x| from[1] == x).unwrap_or(0) as i8;
-            let to_column = 7 - COLUMNS.iter().position(|&x| to[1] == x).unwrap_or(0) as i8;
+            //The destination may carry a promotion piece after its square
+            let squares = (
+                Position::from_algebraic(from_str.trim()),
+                to_str.trim().get(..2).and_then(Position::from_algebraic),
+            );
+            let (from, to) = match squares {
+                (Some(from), Some(to)) => (from, to),
+                _ => {
+                    println!("Invalid squares, use a1;e2");
+                    continue;
+                }
+            };
 
-            legal_move = LegalMove {
-                from: Position {
-                    colum: from_column,
-                    row: from[2].parse().unwrap_or(0) - 1,
-                },
-                to: Position {
-                    colum: to_column,
-                    row: to[2].parse().unwrap_or(0) - 1,
-                },
+            let mut input_move = LegalMove {
+                from: from,
+                to: to,
                 castle: crate::engine::board::position::position::CastleOptions::None,
+                promotion: promotion,
             };
+            if input_move.is_promotion(&board, color) && promotion == PromotionOptions::None {
+                input_move = input_move.with_promotion(PromotionOptions::Queen);
+            }
+            legal_move = Move::from_legal_move(input_move, &board, color);
             for legal in legal_moves.to_owned() {
-                if legal.from == legal_move.from {
+                if legal.get_from() == legal_move.get_from() {
                     println!("{}", legal_move);
                 }
             }
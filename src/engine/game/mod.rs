@@ -1,3 +1,5 @@
 pub mod analyzer;
 pub mod game;
+mod game_test;
 pub mod human;
+pub mod result;
@@ -0,0 +1,75 @@
+use std::fmt;
+
+use crate::engine::board::board::Turn;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum GameResult {
+    WhiteWins,
+    BlackWins,
+    Draw,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum GameEndReason {
+    Checkmate,
+    Stalemate,
+    ThreefoldRepetition,
+    FivefoldRepetition,
+    FiftyMoveRule,
+    SeventyFiveMoveRule,
+    InsufficientMaterial,
+    Resignation,
+    Timeout,
+    Agreement,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum GameStatus {
+    Ongoing,
+    //The game goes on unless the side to move claims the draw
+    DrawClaimable(GameEndReason),
+    Finished(GameResult, GameEndReason),
+}
+
+impl GameResult {
+    pub fn win_for(color: Turn) -> GameResult {
+        match color {
+            Turn::White => return GameResult::WhiteWins,
+            Turn::Black => return GameResult::BlackWins,
+        }
+    }
+}
+
+impl GameStatus {
+    pub fn is_finished(&self) -> bool {
+        return matches!(self, GameStatus::Finished(_, _));
+    }
+}
+
+//PGN style score
+impl fmt::Display for GameResult {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameResult::WhiteWins => write!(fmt, "1-0"),
+            GameResult::BlackWins => write!(fmt, "0-1"),
+            GameResult::Draw => write!(fmt, "1/2-1/2"),
+        }
+    }
+}
+
+impl fmt::Display for GameEndReason {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameEndReason::Checkmate => write!(fmt, "checkmate"),
+            GameEndReason::Stalemate => write!(fmt, "stalemate"),
+            GameEndReason::ThreefoldRepetition => write!(fmt, "threefold repetition"),
+            GameEndReason::FivefoldRepetition => write!(fmt, "fivefold repetition"),
+            GameEndReason::FiftyMoveRule => write!(fmt, "fifty-move rule"),
+            GameEndReason::SeventyFiveMoveRule => write!(fmt, "seventy-five-move rule"),
+            GameEndReason::InsufficientMaterial => write!(fmt, "insufficient material"),
+            GameEndReason::Resignation => write!(fmt, "resignation"),
+            GameEndReason::Timeout => write!(fmt, "timeout"),
+            GameEndReason::Agreement => write!(fmt, "agreement"),
+        }
+    }
+}
@@ -1,4 +1,6 @@
 pub mod board;
 pub mod game;
 pub mod movement;
+pub mod pgn;
 pub mod printer;
+pub mod uci;
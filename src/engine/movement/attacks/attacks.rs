@@ -0,0 +1,70 @@
+use crate::engine::board::{bitboard::bitboard::Bitboard, board::Turn, square::square::Square};
+
+const KNIGHT_OFFSETS: [(i8, i8); 8] = [
+    (2, 1),
+    (2, -1),
+    (-2, 1),
+    (-2, -1),
+    (1, 2),
+    (1, -2),
+    (-1, 2),
+    (-1, -2),
+];
+const KING_OFFSETS: [(i8, i8); 8] = [
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, 1),
+    (0, -1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+const WHITE_PAWN_OFFSETS: [(i8, i8); 2] = [(1, 1), (1, -1)];
+const BLACK_PAWN_OFFSETS: [(i8, i8); 2] = [(-1, 1), (-1, -1)];
+
+static KNIGHT_ATTACKS: [u64; 64] = Attacks::generate(&KNIGHT_OFFSETS);
+static KING_ATTACKS: [u64; 64] = Attacks::generate(&KING_OFFSETS);
+static WHITE_PAWN_ATTACKS: [u64; 64] = Attacks::generate(&WHITE_PAWN_OFFSETS);
+static BLACK_PAWN_ATTACKS: [u64; 64] = Attacks::generate(&BLACK_PAWN_OFFSETS);
+
+//Leaper attacks per square, the tables are built at compile time
+pub struct Attacks {}
+
+impl Attacks {
+    pub fn get_knight_attacks(square: Square) -> Bitboard {
+        return Bitboard(KNIGHT_ATTACKS[square.index()]);
+    }
+
+    pub fn get_king_attacks(square: Square) -> Bitboard {
+        return Bitboard(KING_ATTACKS[square.index()]);
+    }
+
+    pub fn get_pawn_attacks(square: Square, color: Turn) -> Bitboard {
+        match color {
+            Turn::White => return Bitboard(WHITE_PAWN_ATTACKS[square.index()]),
+            Turn::Black => return Bitboard(BLACK_PAWN_ATTACKS[square.index()]),
+        }
+    }
+
+    //Offsets are (row, column) steps, anything leaving the board is dropped
+    const fn generate(offsets: &[(i8, i8)]) -> [u64; 64] {
+        let mut table = [0; 64];
+        let mut square = 0;
+        while square < 64 {
+            let row = (square / 8) as i8;
+            let column = (square % 8) as i8;
+            let mut i = 0;
+            while i < offsets.len() {
+                let target_row = row + offsets[i].0;
+                let target_column = column + offsets[i].1;
+                if target_row >= 0 && target_row < 8 && target_column >= 0 && target_column < 8 {
+                    table[square] |= (1 as u64) << (target_row * 8 + target_column);
+                }
+                i += 1;
+            }
+            square += 1;
+        }
+        return table;
+    }
+}
@@ -0,0 +1,235 @@
+#[cfg(test)]
+mod attacks_tests {
+    use crate::engine::{
+        board::{
+            bitboard::bitboard::Bitboard,
+            board::{Board, Turn},
+            square::square::Square,
+        },
+        movement::{attacks::attacks::Attacks, king::king::King, pawn::pawn::Pawn},
+    };
+
+    const KNIGHT_EDGES: [(&str, &[&str]); 28] = [
+        ("a1", &["b3", "c2"]),
+        ("b1", &["a3", "c3", "d2"]),
+        ("c1", &["a2", "b3", "d3", "e2"]),
+        ("d1", &["b2", "c3", "e3", "f2"]),
+        ("e1", &["c2", "d3", "f3", "g2"]),
+        ("f1", &["d2", "e3", "g3", "h2"]),
+        ("g1", &["e2", "f3", "h3"]),
+        ("h1", &["f2", "g3"]),
+        ("a2", &["b4", "c1", "c3"]),
+        ("h2", &["f1", "f3", "g4"]),
+        ("a3", &["b1", "b5", "c2", "c4"]),
+        ("h3", &["f2", "f4", "g1", "g5"]),
+        ("a4", &["b2", "b6", "c3", "c5"]),
+        ("h4", &["f3", "f5", "g2", "g6"]),
+        ("a5", &["b3", "b7", "c4", "c6"]),
+        ("h5", &["f4", "f6", "g3", "g7"]),
+        ("a6", &["b4", "b8", "c5", "c7"]),
+        ("h6", &["f5", "f7", "g4", "g8"]),
+        ("a7", &["b5", "c6", "c8"]),
+        ("h7", &["f6", "f8", "g5"]),
+        ("a8", &["b6", "c7"]),
+        ("b8", &["a6", "c6", "d7"]),
+        ("c8", &["a7", "b6", "d6", "e7"]),
+        ("d8", &["b7", "c6", "e6", "f7"]),
+        ("e8", &["c7", "d6", "f6", "g7"]),
+        ("f8", &["d7", "e6", "g6", "h7"]),
+        ("g8", &["e7", "f6", "h6"]),
+        ("h8", &["f7", "g6"]),
+    ];
+
+    const KING_EDGES: [(&str, &[&str]); 28] = [
+        ("a1", &["a2", "b1", "b2"]),
+        ("b1", &["a1", "a2", "b2", "c1", "c2"]),
+        ("c1", &["b1", "b2", "c2", "d1", "d2"]),
+        ("d1", &["c1", "c2", "d2", "e1", "e2"]),
+        ("e1", &["d1", "d2", "e2", "f1", "f2"]),
+        ("f1", &["e1", "e2", "f2", "g1", "g2"]),
+        ("g1", &["f1", "f2", "g2", "h1", "h2"]),
+        ("h1", &["g1", "g2", "h2"]),
+        ("a2", &["a1", "a3", "b1", "b2", "b3"]),
+        ("h2", &["g1", "g2", "g3", "h1", "h3"]),
+        ("a3", &["a2", "a4", "b2", "b3", "b4"]),
+        ("h3", &["g2", "g3", "g4", "h2", "h4"]),
+        ("a4", &["a3", "a5", "b3", "b4", "b5"]),
+        ("h4", &["g3", "g4", "g5", "h3", "h5"]),
+        ("a5", &["a4", "a6", "b4", "b5", "b6"]),
+        ("h5", &["g4", "g5", "g6", "h4", "h6"]),
+        ("a6", &["a5", "a7", "b5", "b6", "b7"]),
+        ("h6", &["g5", "g6", "g7", "h5", "h7"]),
+        ("a7", &["a6", "a8", "b6", "b7", "b8"]),
+        ("h7", &["g6", "g7", "g8", "h6", "h8"]),
+        ("a8", &["a7", "b7", "b8"]),
+        ("b8", &["a7", "a8", "b7", "c7", "c8"]),
+        ("c8", &["b7", "b8", "c7", "d7", "d8"]),
+        ("d8", &["c7", "c8", "d7", "e7", "e8"]),
+        ("e8", &["d7", "d8", "e7", "f7", "f8"]),
+        ("f8", &["e7", "e8", "f7", "g7", "g8"]),
+        ("g8", &["f7", "f8", "g7", "h7", "h8"]),
+        ("h8", &["g7", "g8", "h7"]),
+    ];
+
+    const WHITE_PAWN_EDGES: [(&str, &[&str]); 28] = [
+        ("a1", &["b2"]),
+        ("b1", &["a2", "c2"]),
+        ("c1", &["b2", "d2"]),
+        ("d1", &["c2", "e2"]),
+        ("e1", &["d2", "f2"]),
+        ("f1", &["e2", "g2"]),
+        ("g1", &["f2", "h2"]),
+        ("h1", &["g2"]),
+        ("a2", &["b3"]),
+        ("h2", &["g3"]),
+        ("a3", &["b4"]),
+        ("h3", &["g4"]),
+        ("a4", &["b5"]),
+        ("h4", &["g5"]),
+        ("a5", &["b6"]),
+        ("h5", &["g6"]),
+        ("a6", &["b7"]),
+        ("h6", &["g7"]),
+        ("a7", &["b8"]),
+        ("h7", &["g8"]),
+        ("a8", &[]),
+        ("b8", &[]),
+        ("c8", &[]),
+        ("d8", &[]),
+        ("e8", &[]),
+        ("f8", &[]),
+        ("g8", &[]),
+        ("h8", &[]),
+    ];
+
+    const BLACK_PAWN_EDGES: [(&str, &[&str]); 28] = [
+        ("a1", &[]),
+        ("b1", &[]),
+        ("c1", &[]),
+        ("d1", &[]),
+        ("e1", &[]),
+        ("f1", &[]),
+        ("g1", &[]),
+        ("h1", &[]),
+        ("a2", &["b1"]),
+        ("h2", &["g1"]),
+        ("a3", &["b2"]),
+        ("h3", &["g2"]),
+        ("a4", &["b3"]),
+        ("h4", &["g3"]),
+        ("a5", &["b4"]),
+        ("h5", &["g4"]),
+        ("a6", &["b5"]),
+        ("h6", &["g5"]),
+        ("a7", &["b6"]),
+        ("h7", &["g6"]),
+        ("a8", &["b7"]),
+        ("b8", &["a7", "c7"]),
+        ("c8", &["b7", "d7"]),
+        ("d8", &["c7", "e7"]),
+        ("e8", &["d7", "f7"]),
+        ("f8", &["e7", "g7"]),
+        ("g8", &["f7", "h7"]),
+        ("h8", &["g7"]),
+    ];
+
+    fn get_square(name: &str) -> Square {
+        return name.parse().unwrap();
+    }
+
+    fn get_bitboard(names: &[&str]) -> Bitboard {
+        let mut bitboard = Bitboard::EMPTY;
+        for name in names {
+            bitboard = bitboard.with(get_square(name));
+        }
+        return bitboard;
+    }
+
+    fn check_edges(edges: &[(&str, &[&str])], get_attacks: fn(Square) -> Bitboard) {
+        for (square, expected) in edges {
+            assert_eq!(
+                get_attacks(get_square(square)),
+                get_bitboard(expected),
+                "square {}",
+                square
+            );
+        }
+    }
+
+    #[test]
+    fn knight_edge_squares() {
+        check_edges(&KNIGHT_EDGES, Attacks::get_knight_attacks);
+    }
+
+    #[test]
+    fn king_edge_squares() {
+        check_edges(&KING_EDGES, Attacks::get_king_attacks);
+    }
+
+    #[test]
+    fn white_pawn_edge_squares() {
+        check_edges(&WHITE_PAWN_EDGES, |square| {
+            Attacks::get_pawn_attacks(square, Turn::White)
+        });
+    }
+
+    #[test]
+    fn black_pawn_edge_squares() {
+        check_edges(&BLACK_PAWN_EDGES, |square| {
+            Attacks::get_pawn_attacks(square, Turn::Black)
+        });
+    }
+
+    #[test]
+    fn inner_squares() {
+        let d4 = get_square("d4");
+        assert_eq!(
+            Attacks::get_knight_attacks(d4),
+            get_bitboard(&["b3", "b5", "c2", "c6", "e2", "e6", "f3", "f5"])
+        );
+        assert_eq!(
+            Attacks::get_king_attacks(d4),
+            get_bitboard(&["c3", "c4", "c5", "d3", "d5", "e3", "e4", "e5"])
+        );
+        assert_eq!(
+            Attacks::get_pawn_attacks(d4, Turn::White),
+            get_bitboard(&["c5", "e5"])
+        );
+        assert_eq!(
+            Attacks::get_pawn_attacks(d4, Turn::Black),
+            get_bitboard(&["c3", "e3"])
+        );
+    }
+
+    #[test]
+    fn king_moves_up_the_board() {
+        let mut board = Board::new_empty();
+        board.w_king = get_bitboard(&["e1"]);
+        board.w_pawns = get_bitboard(&["d2"]);
+        assert_eq!(
+            King::get_moves(board.w_king, Turn::White, board),
+            get_bitboard(&["d1", "e2", "f1", "f2"])
+        );
+    }
+
+    #[test]
+    fn pawn_captures_do_not_wrap() {
+        let mut board = Board::new_empty();
+        board.w_pawns = get_bitboard(&["a4", "h4"]);
+        board.b_pawns = get_bitboard(&["b5", "h6", "g5", "a6"]);
+        let a4 = get_bitboard(&["a4"]);
+        let h4 = get_bitboard(&["h4"]);
+        assert_eq!(
+            Pawn::get_moves(a4, Turn::White, board),
+            get_bitboard(&["a5", "b5"])
+        );
+        assert_eq!(
+            Pawn::get_moves(h4, Turn::White, board),
+            get_bitboard(&["h5", "g5"])
+        );
+        assert_eq!(
+            Pawn::get_attacks(board.w_pawns, Turn::White),
+            get_bitboard(&["b5", "g5"])
+        );
+    }
+}
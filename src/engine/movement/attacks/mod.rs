@@ -0,0 +1,2 @@
+pub mod attacks;
+mod attacks_test;
@@ -1,26 +1,24 @@
 use crate::engine::{
-    board::board::{Board, Turn},
-    movement::movement::Movement,
+    board::{
+        bitboard::bitboard::Bitboard,
+        board::{Board, Turn},
+    },
+    movement::{attacks::attacks::Attacks, movement::Movement},
 };
 
 pub struct Knight {}
 //rook movement
 
 impl Knight {
-    pub fn get_moves(knight_bits: u64, color: Turn, board: Board) -> u64 {
+    pub fn get_moves(knight_bits: Bitboard, color: Turn, board: Board) -> Bitboard {
         let white_bitboard = board.getWhiteBitboard();
         let black_bitboard = board.getBlackBitboard();
-
-        let l1 = (knight_bits >> 1) & 0x7f7f7f7f7f7f7f7f;
-        let l2 = (knight_bits >> 2) & (0x3f3f3f3f3f3f3f3f);
-        let r1 = (knight_bits << 1) & (0xfefefefefefefefe);
-        let r2 = (knight_bits << 2) & (0xfcfcfcfcfcfcfcfc);
-        let h1 = l1 | r1;
-        let h2 = l2 | r2;
-        let move_bits = (h1 << 16) | (h1 >> 16) | (h2 << 8) | (h2 >> 8);
-
         let ally_bloquers = Movement::ally_blockers(&color, white_bitboard, black_bitboard);
 
+        let mut move_bits = Bitboard::EMPTY;
+        for square in knight_bits {
+            move_bits |= Attacks::get_knight_attacks(square);
+        }
         return move_bits & !ally_bloquers;
     }
 }
@@ -1,19 +1,50 @@
 #[cfg(test)]
 mod knigth_tests {
     use crate::engine::{
-        board::board::{Board, Turn},
+        board::{
+            bitboard::bitboard::Bitboard,
+            board::{Board, Turn},
+        },
         movement::movement::Movement,
     };
 
+    const B_ROOKS: Bitboard = Bitboard(0x8100000000000081);
+    const B_KNIGHTS: Bitboard = Bitboard(0x42000000004200);
+    const B_BISHOPS: Bitboard = Bitboard(0x240000240000);
+    const B_QUEEN: Bitboard = Bitboard(0x800000000);
+    const B_KING: Bitboard = Bitboard(0x0);
+    const B_PAWNS: Bitboard = Bitboard(0x18000000);
+
+    const W_ROOKS: Bitboard = Bitboard(0x8100000000000081);
+    const W_KNIGHTS: Bitboard = Bitboard(0x42000000004200);
+    const W_BISHOPS: Bitboard = Bitboard(0x240000240000);
+    const W_QUEEN: Bitboard = Bitboard(0x800000000);
+    const W_KING: Bitboard = Bitboard(0x0);
+    const W_PAWNS: Bitboard = Bitboard(0x18000000);
+
+    //Kingless fixture, so it can not go through the FEN loader
     fn get_board_by_color(color: &Turn) -> Board {
+        let mut board = Board::new_empty();
         match color {
             Turn::White => {
-                Board::new_from_fenn_notation("R6R/1N4N1/2B2B2/4Q3/3PP3/2B2B2/1N4N1/R6R w - - 0 1")
+                board.w_rooks = W_ROOKS;
+                board.w_knights = W_KNIGHTS;
+                board.w_bishops = W_BISHOPS;
+                board.w_queen = W_QUEEN;
+                board.w_king = W_KING;
+                board.w_pawns = W_PAWNS;
             }
             Turn::Black => {
-                Board::new_from_fenn_notation("R6R/1N4N1/2B2B2/4Q3/3PP3/2B2B2/1N4N1/R6R w - - 0 1")
+                board.b_rooks = B_ROOKS;
+                board.b_knights = B_KNIGHTS;
+                board.b_bishops = B_BISHOPS;
+                board.b_queen = B_QUEEN;
+                board.b_king = B_KING;
+                board.b_pawns = B_PAWNS;
             }
         }
+        board.set_turn(Turn::White);
+        return board;
     }
 
     //Empty board tests
@@ -21,126 +52,81 @@ mod knigth_tests {
     fn empty_board_bottom_right() {
         let board = Board::new_empty();
 
-        let test_bits: u64 = 0x1;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x20400);
+        let test_bits = Bitboard(0x1);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x20400));
     }
 
     #[test]
     fn empty_board_bottom_left() {
         let board = Board::new_empty();
 
-        let test_bits: u64 = 0x80;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x402000);
+        let test_bits = Bitboard(0x80);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x402000));
     }
 
     #[test]
     fn empty_board_top_right() {
         let board = Board::new_empty();
 
-        let test_bits: u64 = 0x100000000000000;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x4020000000000);
+        let test_bits = Bitboard(0x100000000000000);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x4020000000000));
     }
 
     #[test]
     fn empty_board_top_left() {
         let board = Board::new_empty();
 
-        let test_bits: u64 = 0x8000000000000000;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x20400000000000);
+        let test_bits = Bitboard(0x8000000000000000);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x20400000000000));
     }
 
     #[test]
     fn empty_board_middle() {
         let board = Board::new_empty();
 
-        let test_bits: u64 = 0x10000000;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x284400442800);
+        let test_bits = Bitboard(0x10000000);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x284400442800));
     }
 
     #[test]
     fn empty_board_bottom() {
         let board = Board::new_empty();
 
-        let test_bits: u64 = 0x8;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x142200);
+        let test_bits = Bitboard(0x8);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x142200));
     }
 
     #[test]
     fn empty_board_top() {
         let board = Board::new_empty();
 
-        let test_bits: u64 = 0x1000000000000000;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x44280000000000);
+        let test_bits = Bitboard(0x1000000000000000);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x44280000000000));
     }
 
     #[test]
     fn empty_board_left() {
         let board = Board::new_empty();
 
-        let test_bits: u64 = 0x8000000000;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x40200020400000);
+        let test_bits = Bitboard(0x8000000000);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x40200020400000));
     }
 
     #[test]
     fn empty_board_rigth() {
         let board = Board::new_empty();
 
-        let test_bits: u64 = 0x1000000;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x20400040200);
+        let test_bits = Bitboard(0x1000000);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x20400040200));
     }
 
     //Full board tests
@@ -149,98 +135,63 @@ mod knigth_tests {
     fn full_board_bottom_right_block() {
         let board = get_board_by_color(&Turn::Black);
 
-        let test_bits: u64 = 0x4;
+        let test_bits = Bitboard(0x4);
 
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0xa1100);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0xa1100));
     }
 
     #[test]
     fn full_board_bottom_left_block() {
         let board = get_board_by_color(&Turn::Black);
 
-        let test_bits: u64 = 0x20;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x508800);
+        let test_bits = Bitboard(0x20);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x508800));
     }
 
     #[test]
     fn full_board_left_block() {
         let board = get_board_by_color(&Turn::Black);
 
-        let test_bits: u64 = 0x800000;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x4020002040);
+        let test_bits = Bitboard(0x800000);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x4020002040));
     }
 
     #[test]
     fn full_board_rigth_block() {
         let board = get_board_by_color(&Turn::Black);
 
-        let test_bits: u64 = 0x10000;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x204000402);
+        let test_bits = Bitboard(0x10000);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x204000402));
     }
 
     #[test]
     fn full_board_top_block() {
         let board = get_board_by_color(&Turn::Black);
 
-        let test_bits: u64 = 0x800000000000000;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x20100000000000);
+        let test_bits = Bitboard(0x800000000000000);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x20100000000000));
     }
 
     #[test]
     fn full_board_middle_top_block() {
         let board = get_board_by_color(&Turn::Black);
 
-        let test_bits: u64 = 0x8000000000000;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x2200021400000000);
+        let test_bits = Bitboard(0x8000000000000);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x2200021400000000));
     }
     #[test]
     fn full_board_middle_bottom_block() {
         let board = get_board_by_color(&Turn::Black);
 
-        let test_bits: u64 = 0x800;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x4020022);
+        let test_bits = Bitboard(0x800);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x4020022));
     }
 
     //with enemy color block
@@ -248,95 +199,60 @@ mod knigth_tests {
     fn full_board_bottom_right_block_enemy() {
         let board = get_board_by_color(&Turn::White);
 
-        let test_bits: u64 = 0x4;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0xa1100);
+        let test_bits = Bitboard(0x4);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0xa1100));
     }
     #[test]
     fn full_board_bottom_left_block_enemy() {
         let board = get_board_by_color(&Turn::White);
 
-        let test_bits: u64 = 0x20;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x508800);
+        let test_bits = Bitboard(0x20);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x508800));
     }
 
     #[test]
     fn full_board_left_block_enemy() {
         let board = get_board_by_color(&Turn::White);
 
-        let test_bits: u64 = 0x800000;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x4020002040);
+        let test_bits = Bitboard(0x800000);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x4020002040));
     }
 
     #[test]
     fn full_board_rigth_block_enemy() {
         let board = get_board_by_color(&Turn::White);
 
-        let test_bits: u64 = 0x10000;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x204000402);
+        let test_bits = Bitboard(0x10000);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x204000402));
     }
 
     #[test]
     fn full_board_top_block_enemy() {
         let board = get_board_by_color(&Turn::White);
 
-        let test_bits: u64 = 0x800000000000000;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x22140000000000);
+        let test_bits = Bitboard(0x800000000000000);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x22140000000000));
     }
 
     #[test]
     fn full_board_middle_top_block_enemy() {
         let board = get_board_by_color(&Turn::White);
 
-        let test_bits: u64 = 0x8000000000000;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x2200221400000000);
+        let test_bits = Bitboard(0x8000000000000);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x2200221400000000));
     }
     #[test]
     fn full_board_middle_bottom_block_enemy() {
         let board = Board::new_empty();
 
-        let test_bits: u64 = 0x1000000;
-        let result: u64 = Movement::get_knigth_moves_bitboard(
-            test_bits,
-            Turn::Black,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
-        assert_eq!(result, 0x20400040200);
+        let test_bits = Bitboard(0x1000000);
+        let result = Movement::get_knigth_moves(test_bits, Turn::Black, board);
+        assert_eq!(result, Bitboard(0x20400040200));
     }
 }
@@ -0,0 +1,204 @@
+use std::sync::OnceLock;
+
+use crate::engine::board::{bitboard::bitboard::Bitboard, square::square::Square};
+
+const ROOK_DIRECTIONS: [(i8, i8); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const BISHOP_DIRECTIONS: [(i8, i8); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+const MAGIC_SEED: u64 = 0x6c8e9cf570932bd5;
+
+#[derive(Clone, Copy)]
+struct MagicEntry {
+    mask: u64,
+    magic: u64,
+    shift: u32,
+    offset: usize,
+}
+
+impl MagicEntry {
+    fn get_index(&self, occupancy: u64) -> usize {
+        return self.offset
+            + ((occupancy & self.mask).wrapping_mul(self.magic) >> self.shift) as usize;
+    }
+}
+
+struct MagicTables {
+    rook: [MagicEntry; 64],
+    bishop: [MagicEntry; 64],
+    attacks: Vec<u64>,
+}
+
+static TABLES: OnceLock<MagicTables> = OnceLock::new();
+
+//Fancy magic bitboards, the magics are searched once on first use
+pub struct Magic {}
+
+impl Magic {
+    pub fn get_rook_attacks(square: Square, occupancy: Bitboard) -> Bitboard {
+        let tables = Magic::get_tables();
+        return Bitboard(tables.attacks[tables.rook[square.index()].get_index(occupancy.0)]);
+    }
+
+    pub fn get_bishop_attacks(square: Square, occupancy: Bitboard) -> Bitboard {
+        let tables = Magic::get_tables();
+        return Bitboard(tables.attacks[tables.bishop[square.index()].get_index(occupancy.0)]);
+    }
+
+    pub fn get_queen_attacks(square: Square, occupancy: Bitboard) -> Bitboard {
+        return Magic::get_rook_attacks(square, occupancy)
+            | Magic::get_bishop_attacks(square, occupancy);
+    }
+
+    //Squares strictly between two squares on a shared line, empty when they are not aligned
+    pub fn get_between(from: Square, to: Square) -> Bitboard {
+        if Magic::get_rook_attacks(from, Bitboard::EMPTY).contains(to) {
+            return Magic::get_rook_attacks(from, to.bitboard())
+                & Magic::get_rook_attacks(to, from.bitboard());
+        }
+        if Magic::get_bishop_attacks(from, Bitboard::EMPTY).contains(to) {
+            return Magic::get_bishop_attacks(from, to.bitboard())
+                & Magic::get_bishop_attacks(to, from.bitboard());
+        }
+        return Bitboard::EMPTY;
+    }
+
+    //Slow ray walk, only used to fill the tables
+    pub fn get_sliding_attacks(
+        square: Square,
+        occupancy: Bitboard,
+        directions: &[(i8, i8); 4],
+    ) -> Bitboard {
+        let row = (square.index() / 8) as i8;
+        let column = (square.index() % 8) as i8;
+        let mut attacks = Bitboard::EMPTY;
+        for (row_step, column_step) in directions {
+            let mut target_row = row + row_step;
+            let mut target_column = column + column_step;
+            while (0..8).contains(&target_row) && (0..8).contains(&target_column) {
+                let target = Square::from_index((target_row * 8 + target_column) as usize);
+                attacks |= target.bitboard();
+                if occupancy.contains(target) {
+                    break;
+                }
+                target_row += row_step;
+                target_column += column_step;
+            }
+        }
+        return attacks;
+    }
+
+    fn get_tables() -> &'static MagicTables {
+        return TABLES.get_or_init(|| {
+            let mut seed = MAGIC_SEED;
+            let mut attacks: Vec<u64> = Vec::new();
+            let rook = Magic::find_magics(&ROOK_DIRECTIONS, &mut attacks, &mut seed);
+            let bishop = Magic::find_magics(&BISHOP_DIRECTIONS, &mut attacks, &mut seed);
+            return MagicTables {
+                rook,
+                bishop,
+                attacks,
+            };
+        });
+    }
+
+    //Squares whose occupancy changes the attacks, the last square of every ray never does
+    fn get_relevant_mask(square: usize, directions: &[(i8, i8); 4]) -> u64 {
+        let row = (square / 8) as i8;
+        let column = (square % 8) as i8;
+        let mut mask = 0;
+        for (row_step, column_step) in directions {
+            let mut target_row = row + row_step;
+            let mut target_column = column + column_step;
+            while (0..8).contains(&(target_row + row_step))
+                && (0..8).contains(&(target_column + column_step))
+            {
+                mask |= 1_u64 << (target_row * 8 + target_column);
+                target_row += row_step;
+                target_column += column_step;
+            }
+        }
+        return mask;
+    }
+
+    fn find_magics(
+        directions: &[(i8, i8); 4],
+        attacks: &mut Vec<u64>,
+        seed: &mut u64,
+    ) -> [MagicEntry; 64] {
+        let mut entries = [MagicEntry {
+            mask: 0,
+            magic: 0,
+            shift: 0,
+            offset: 0,
+        }; 64];
+
+        for (square, entry) in entries.iter_mut().enumerate() {
+            let mask = Magic::get_relevant_mask(square, directions);
+            let bits = mask.count_ones();
+
+            //Every subset of the mask with its attacks
+            let mut occupancies: Vec<u64> = Vec::new();
+            let mut references: Vec<u64> = Vec::new();
+            let mut subset: u64 = 0;
+            loop {
+                occupancies.push(subset);
+                references.push(
+                    Magic::get_sliding_attacks(
+                        Square::from_index(square),
+                        Bitboard(subset),
+                        directions,
+                    )
+                    .0,
+                );
+                subset = subset.wrapping_sub(mask) & mask;
+                if subset == 0 {
+                    break;
+                }
+            }
+
+            let size = 1 << bits;
+            let mut table: Vec<u64> = vec![0; size];
+            let mut used: Vec<bool> = vec![false; size];
+            loop {
+                let magic =
+                    Magic::next_random(seed) & Magic::next_random(seed) & Magic::next_random(seed);
+                //Magics that do not spread the mask over the top byte rarely work
+                if (mask.wrapping_mul(magic) & 0xff00000000000000).count_ones() < 6 {
+                    continue;
+                }
+
+                used.iter_mut().for_each(|x| *x = false);
+                let mut collision = false;
+                for (occupancy, reference) in occupancies.iter().zip(references.iter()) {
+                    let index = (occupancy.wrapping_mul(magic) >> (64 - bits)) as usize;
+                    if !used[index] {
+                        used[index] = true;
+                        table[index] = *reference;
+                    } else if table[index] != *reference {
+                        collision = true;
+                        break;
+                    }
+                }
+
+                if !collision {
+                    *entry = MagicEntry {
+                        mask,
+                        magic,
+                        shift: 64 - bits,
+                        offset: attacks.len(),
+                    };
+                    attacks.extend(table);
+                    break;
+                }
+            }
+        }
+        return entries;
+    }
+
+    fn next_random(seed: &mut u64) -> u64 {
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 7;
+        *seed ^= *seed << 17;
+        return *seed;
+    }
+}
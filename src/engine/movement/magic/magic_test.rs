@@ -0,0 +1,384 @@
+#[cfg(test)]
+mod magic_tests {
+    use crate::engine::{
+        board::{
+            bitboard::bitboard::Bitboard,
+            board::{Board, Turn},
+            square::square::Square,
+        },
+        movement::{
+            bishop::bishop::Bishop,
+            magic::magic::Magic,
+            movement::{
+                FULL_u64, Movement, COLUMN_H, EMPTY_U64, ROOK_FULL_COLUM_MOVEMENT_DEFINITION,
+                ROW_1, SINGLE_BYTE_U8,
+            },
+            queen::queen::Queen,
+            rook::rook::Rook,
+        },
+    };
+
+    const RANDOM_OCCUPANCIES: usize = 500;
+
+    //Ray code the tables replaced, used as the reference
+    fn rook_ray_moves(rook_bits: u64, color: Turn, board: Board) -> u64 {
+        let piece_index = Movement::get_piece_index(Bitboard(rook_bits));
+        if piece_index < 0 {
+            return 0;
+        }
+
+        let column = piece_index % 8;
+        let row = piece_index / 8;
+
+        let mut move_bits: u64 = EMPTY_U64;
+
+        let white_bitboard = board.getWhiteBitboard();
+        let black_bitboard = board.getBlackBitboard();
+
+        let enemy_blockers = Movement::enemy_blockers(&color, white_bitboard, black_bitboard).0;
+        let ally_bloquers =
+            Movement::ally_blockers(&color, white_bitboard, black_bitboard).0 & !rook_bits;
+        //UP MOVES
+
+        {
+            let mut up_colum = ROOK_FULL_COLUM_MOVEMENT_DEFINITION << piece_index;
+
+            let enemy_up_colum = up_colum & enemy_blockers;
+
+            if enemy_up_colum != 0 {
+                let lsb = Movement::lsb_pos(enemy_up_colum);
+                let mask = FULL_u64 >> (63 - lsb);
+                up_colum &= mask;
+            }
+
+            let ally_up_colum = up_colum & ally_bloquers;
+            if ally_up_colum != 0 {
+                let lsb = Movement::lsb_pos(ally_up_colum);
+                let mask = FULL_u64 >> (63 - (lsb - 1));
+                up_colum &= mask;
+            }
+            move_bits |= up_colum;
+        }
+
+        //DOWN MOVES
+        if row > ROW_1 {
+            let mut down_colum = ROOK_FULL_COLUM_MOVEMENT_DEFINITION >> (63 - piece_index + 1);
+
+            let enemy_down_colum = down_colum & enemy_blockers;
+
+            if enemy_down_colum != 0 {
+                let msb = Movement::msb_pos(enemy_down_colum);
+                let mask = FULL_u64 << msb;
+                down_colum &= mask;
+            }
+
+            let ally_down_colum = down_colum & ally_bloquers;
+            if ally_down_colum != 0 {
+                let msb = Movement::msb_pos(ally_down_colum);
+                let mask = FULL_u64 << (msb + 1);
+                down_colum &= mask;
+            }
+            move_bits |= down_colum;
+        }
+
+        //LEFT MOVES
+        {
+            let mut left_row = ((SINGLE_BYTE_U8 << column) as u64) << (row * 8);
+
+            let enemy_left_row = left_row & enemy_blockers;
+            if enemy_left_row != 0 {
+                let lsb = Movement::lsb_pos(enemy_left_row);
+                let mask = FULL_u64 >> (63 - lsb);
+                left_row &= mask;
+            }
+
+            let ally_down_colum = left_row & ally_bloquers;
+            if ally_down_colum != 0 {
+                let lsb = Movement::lsb_pos(ally_down_colum);
+                let mask = FULL_u64 >> (63 - (lsb - 1));
+                left_row &= mask;
+            }
+            move_bits |= left_row;
+        }
+
+        //RIGHT MOVES
+        if column > COLUMN_H {
+            let mut right_row = ((SINGLE_BYTE_U8 >> (8 - column)) as u64) << (row * 8);
+
+            let enemy_left_row = right_row & enemy_blockers;
+            if enemy_left_row != 0 {
+                let msb = Movement::msb_pos(enemy_left_row);
+                let mask = FULL_u64 << msb;
+                right_row &= mask;
+            }
+
+            let ally_down_colum = right_row & ally_bloquers;
+            if ally_down_colum != 0 {
+                let msb = Movement::msb_pos(ally_down_colum);
+                let mask = FULL_u64 << (msb + 1);
+                right_row &= mask;
+            }
+
+            move_bits |= right_row;
+        }
+        return move_bits & !rook_bits;
+    }
+
+    fn bishop_ray_moves(bishop_bits: u64, color: Turn, board: Board) -> u64 {
+        let piece_index = Movement::get_piece_index(Bitboard(bishop_bits));
+        let column = piece_index % 8;
+        let row = piece_index / 8;
+
+        let mut move_bits: u64 = EMPTY_U64;
+
+        let white_bitboard = board.getWhiteBitboard();
+        let black_bitboard = board.getBlackBitboard();
+
+        let enemy_blockers = Movement::enemy_blockers(&color, white_bitboard, black_bitboard).0;
+        let ally_bloquers = Movement::ally_blockers(&color, white_bitboard, black_bitboard).0;
+
+        //Board::print_board_moves_with_text("Rotated", W_BISHOP_UTF, 0x8041221400142241);
+
+        //Board::print_board_moves_with_text("Rotated", W_BISHOP_UTF, 0x8041221400142241 >> 9);
+
+        //UP LEFT
+        if piece_index != 63 {
+            let mut diagonal: u64 = 0;
+            let mut displacement_count = 1;
+            for row_bishop in 0..8 {
+                if row_bishop > row {
+                    let displacement = (row_bishop * 8) + (column + displacement_count);
+                    if displacement % 8 < column || displacement > 64 {
+                        break;
+                    }
+
+                    diagonal |= 1_u64 << displacement;
+                    displacement_count += 1;
+                }
+            }
+            let enemy_diagonal = diagonal & enemy_blockers;
+
+            if enemy_diagonal != 0 {
+                let lsb = Movement::lsb_pos(enemy_diagonal);
+
+                if lsb < 63 {
+                    let mask = FULL_u64 << (lsb + 1);
+                    diagonal &= !mask;
+                }
+            }
+
+            let ally_diagonal = diagonal & ally_bloquers;
+            if ally_diagonal != 0 {
+                let lsb = Movement::lsb_pos(ally_diagonal);
+                let mask = FULL_u64 << lsb;
+                diagonal &= !mask;
+            }
+            move_bits |= diagonal;
+        }
+
+        //DOWN RIGHT
+        if piece_index != 0 {
+            let mut diagonal: u64 = 0;
+            let base_displacement = column - row;
+            for row_bishop in 0..8 {
+                if row_bishop < row {
+                    let displacement = (row_bishop) * 8 + row_bishop + base_displacement;
+                    if displacement % 8 > column || displacement < 0 {
+                        continue;
+                    }
+
+                    diagonal |= 1_u64 << displacement;
+                }
+            }
+            let enemy_diagonal = diagonal & enemy_blockers;
+
+            if enemy_diagonal != 0 {
+                let msb = Movement::msb_pos(enemy_diagonal);
+                let mask = FULL_u64 << msb;
+                diagonal &= mask;
+            }
+
+            let ally_diagonal = diagonal & ally_bloquers;
+            if ally_diagonal != 0 {
+                let msb = Movement::msb_pos(ally_diagonal);
+                let mask = FULL_u64 >> (63 - msb);
+                diagonal &= !mask;
+            }
+            move_bits |= diagonal;
+        }
+
+        //UP RIGTH
+        if piece_index != 56 {
+            let mut diagonal: u64 = 0;
+            let mut displacement_count = 1;
+            for row_bishop in 0..8 {
+                if row_bishop > row {
+                    let displacement = (row_bishop) * 8 + column - displacement_count;
+                    if displacement % 8 > column || displacement < 0 {
+                        continue;
+                    }
+
+                    diagonal |= 1_u64 << displacement;
+                    displacement_count += 1;
+                }
+            }
+            let enemy_diagonal = diagonal & enemy_blockers;
+
+            if enemy_diagonal != 0 {
+                let lsb = Movement::lsb_pos(enemy_diagonal);
+                let mask = FULL_u64 << (lsb + 1);
+                diagonal &= !mask;
+            }
+
+            let ally_diagonal = diagonal & ally_bloquers;
+            if ally_diagonal != 0 {
+                let lsb = Movement::lsb_pos(ally_diagonal);
+                let mask = FULL_u64 << lsb;
+                diagonal &= !mask;
+            }
+            move_bits |= diagonal;
+        }
+
+        //DOWN LEFT
+        if piece_index != 7 {
+            let mut diagonal: u64 = 0;
+            for row_bishop in 0..8 {
+                if row_bishop < row {
+                    let displacement = (row_bishop) * 8 + column + (row - row_bishop);
+                    if displacement % 8 < column || displacement > 64 {
+                        continue;
+                    }
+
+                    diagonal |= 1_u64 << displacement;
+                }
+            }
+
+            let enemy_diagonal = diagonal & enemy_blockers;
+
+            if enemy_diagonal != 0 {
+                let msb = Movement::msb_pos(enemy_diagonal);
+                let mask = FULL_u64 >> (63 - msb + 1);
+
+                diagonal &= !mask;
+            }
+
+            let ally_diagonal = diagonal & ally_bloquers;
+            if ally_diagonal != 0 {
+                let msb = Movement::msb_pos(ally_diagonal);
+                let mask = FULL_u64 >> (63 - msb);
+
+                diagonal &= !mask;
+            }
+
+            move_bits |= diagonal;
+        }
+
+        return move_bits & !bishop_bits;
+    }
+
+    fn next_random(seed: &mut u64) -> u64 {
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 7;
+        *seed ^= *seed << 17;
+        return *seed;
+    }
+
+    //Random pieces of both colours around the slider, sparse and dense
+    fn random_board(square_bits: u64, seed: &mut u64) -> Board {
+        let mut board = Board::new_empty();
+        let mut ocupancy = next_random(seed);
+        for _ in 0..(*seed % 3) {
+            ocupancy &= next_random(seed);
+        }
+        ocupancy &= !square_bits;
+        let white = ocupancy & next_random(seed);
+        board.w_pawns = Bitboard(white);
+        board.b_pawns = Bitboard(ocupancy & !white);
+        return board;
+    }
+
+    #[test]
+    fn rook_tables_match_rays() {
+        let mut seed: u64 = 0x2545f4914f6cdd1d;
+        for square in 0..64 {
+            let square_bits = 1_u64 << square;
+            for _ in 0..RANDOM_OCCUPANCIES {
+                let mut board = random_board(square_bits, &mut seed);
+                for color in [Turn::White, Turn::Black] {
+                    match color {
+                        Turn::White => board.w_rooks = Bitboard(square_bits),
+                        Turn::Black => board.b_rooks = Bitboard(square_bits),
+                    }
+                    assert_eq!(
+                        Rook::get_moves(Bitboard(square_bits), color, board).0,
+                        rook_ray_moves(square_bits, color, board),
+                        "square {} ocupancy {:#x}",
+                        square,
+                        board.getOcupancy().0
+                    );
+                    board.w_rooks = Bitboard::EMPTY;
+                    board.b_rooks = Bitboard::EMPTY;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bishop_tables_match_rays() {
+        let mut seed: u64 = 0x9e3779b97f4a7c15;
+        for square in 0..64 {
+            let square_bits = 1_u64 << square;
+            for _ in 0..RANDOM_OCCUPANCIES {
+                let mut board = random_board(square_bits, &mut seed);
+                for color in [Turn::White, Turn::Black] {
+                    match color {
+                        Turn::White => board.w_bishops = Bitboard(square_bits),
+                        Turn::Black => board.b_bishops = Bitboard(square_bits),
+                    }
+                    assert_eq!(
+                        Bishop::get_moves(Bitboard(square_bits), color, board).0,
+                        bishop_ray_moves(square_bits, color, board),
+                        "square {} ocupancy {:#x}",
+                        square,
+                        board.getOcupancy().0
+                    );
+                    board.w_bishops = Bitboard::EMPTY;
+                    board.b_bishops = Bitboard::EMPTY;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn queen_is_rook_and_bishop() {
+        let mut seed: u64 = 0x5851f42d4c957f2d;
+        for square in 0..64 {
+            let square_bits = 1_u64 << square;
+            for _ in 0..100 {
+                let mut board = random_board(square_bits, &mut seed);
+                board.w_queen = Bitboard(square_bits);
+                assert_eq!(
+                    Queen::get_moves(Bitboard(square_bits), Turn::White, board).0,
+                    rook_ray_moves(square_bits, Turn::White, board)
+                        | bishop_ray_moves(square_bits, Turn::White, board)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn empty_board_attacks() {
+        let d4: Square = "d4".parse().unwrap();
+        let h1: Square = "h1".parse().unwrap();
+        assert_eq!(Magic::get_rook_attacks(d4, Bitboard::EMPTY).count(), 14);
+        assert_eq!(Magic::get_bishop_attacks(d4, Bitboard::EMPTY).count(), 13);
+        assert_eq!(
+            Magic::get_rook_attacks(h1, Bitboard::EMPTY),
+            Bitboard(0x1010101010101fe)
+        );
+        assert_eq!(
+            Magic::get_bishop_attacks(h1, Bitboard::EMPTY),
+            Bitboard(0x8040201008040200)
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub mod magic;
+mod magic_test;
@@ -1,6 +1,8 @@
+pub mod attacks;
 pub mod bishop;
 pub mod king;
 pub mod knight;
+pub mod magic;
 pub mod movement;
 pub mod pawn;
 pub mod queen;
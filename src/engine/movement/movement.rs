@@ -1,6 +1,8 @@
 use crate::engine::board::{
+    bitboard::bitboard::Bitboard,
     board::{Board, PieceType, Turn},
     position::position::{CastleOptions, LegalMove},
+    square::square::Square,
 };
 
 use super::{
@@ -44,63 +46,37 @@ impl Movement {
         return Movement {};
     }
 
-    pub fn get_piece_index(piece_bits: u64) -> i8 {
-        if piece_bits == 0 {
-            return NOT_FOUND;
-        }
-        let mut i = 1;
-        let mut pos = 0;
-
-        while (i & piece_bits) == 0 {
-            i = i << 1;
-            pos += 1;
+    pub fn get_piece_index(piece_bits: Bitboard) -> i8 {
+        match piece_bits.first() {
+            Some(square) => return square.index() as i8,
+            None => return NOT_FOUND,
         }
-        return pos;
     }
 
-    pub fn pieces_count(mut pieces_bitboard: u64) -> i8 {
-        if pieces_bitboard == 0 {
-            return 0;
-        }
-
-        let mut count = 0;
-
-        while pieces_bitboard != 0 {
-            let piece_pos = Movement::msb_pos(pieces_bitboard);
-            let piece_bits = (1 as u64) << piece_pos;
-            pieces_bitboard = pieces_bitboard & !piece_bits;
-            count += 1;
-        }
-
-        return count;
+    pub fn pieces_count(pieces_bitboard: Bitboard) -> i8 {
+        return pieces_bitboard.count() as i8;
     }
 
-    pub fn msb_pos(mut bits: u64) -> i8 {
-        let mut cnt: i8 = 0;
+    pub fn msb_pos(bits: u64) -> i8 {
         if bits == 0 {
             return NOT_FOUND;
         }
-        while bits > 0 {
-            cnt = cnt + 1;
-            bits = bits >> 1;
-        }
-        return cnt - 1;
+        return 63 - bits.leading_zeros() as i8;
     }
 
-    pub fn lsb_pos(mut bits: u64) -> i8 {
-        let mut cnt: i8 = 0;
-        if (bits == 0) {
+    pub fn lsb_pos(bits: u64) -> i8 {
+        if bits == 0 {
             return NOT_FOUND;
         }
-        while bits > 0 {
-            cnt = cnt + 1;
-            bits = bits << 1;
-        }
-        return 64 - cnt;
+        return bits.trailing_zeros() as i8;
     }
 
-    pub fn enemy_blockers(color: &Turn, white_bitboard: u64, black_bitboard: u64) -> u64 {
-        let mut result_move_bits: u64 = 0;
+    pub fn enemy_blockers(
+        color: &Turn,
+        white_bitboard: Bitboard,
+        black_bitboard: Bitboard,
+    ) -> Bitboard {
+        let mut result_move_bits = Bitboard::EMPTY;
         if matches!(color, Turn::Black) {
             result_move_bits = white_bitboard
         }
@@ -111,8 +87,12 @@ impl Movement {
         return result_move_bits;
     }
 
-    pub fn ally_blockers(color: &Turn, white_bitboard: u64, black_bitboard: u64) -> u64 {
-        let mut result_move_bits: u64 = 0;
+    pub fn ally_blockers(
+        color: &Turn,
+        white_bitboard: Bitboard,
+        black_bitboard: Bitboard,
+    ) -> Bitboard {
+        let mut result_move_bits = Bitboard::EMPTY;
         if matches!(color, Turn::Black) {
             result_move_bits = black_bitboard;
         }
@@ -131,450 +111,56 @@ impl Movement {
     }
 
     pub fn can_castle_king_side(board: Board, playing_as: Turn) -> bool {
-        match playing_as {
-            Turn::White => {
-                let attacts = Movement::extract_all_attacks_for_color(board, Turn::Black);
-
-                return !(board.has_w_king_side_castle || board.w_king_has_moved)
-                    && board.w_king & 0x8 > 0
-                    && board.w_rooks & 0x1 > 0
-                    && board.getOcupancy() & 0x6 == 0
-                    && attacts & 0xe == 0;
-            }
-            Turn::Black => {
-                let attacts = Movement::extract_all_attacks_for_color(board, Turn::White);
-
-                return !(board.has_b_king_side_castle || board.b_king_has_moved)
-                    && board.b_king & 0x800000000000000 > 0
-                    && board.b_rooks & 0x100000000000000 > 0
-                    && board.getOcupancy() & 0x600000000000000 == 0
-                    && attacts & 0xe00000000000000 == 0;
-            }
-        }
+        return board.can_castle_king_side(playing_as);
     }
 
     pub fn can_castle_queen_side(board: Board, playing_as: Turn) -> bool {
-        match playing_as {
-            Turn::White => {
-                let attacts = Movement::extract_all_attacks_for_color(board, Turn::Black);
-
-                return !(board.has_w_queen_side_castle || board.w_king_has_moved)
-                    && board.w_king & 0x8 > 0
-                    && board.w_rooks & 0x80 > 0
-                    && board.getOcupancy() & 0x70 == 0
-                    && attacts & 0x78 == 0;
-            }
-            Turn::Black => {
-                let attacts = Movement::extract_all_attacks_for_color(board, Turn::White);
-
-                return !(board.has_b_queen_side_castle || board.b_king_has_moved)
-                    && board.b_king & 0x800000000000000 > 0
-                    && board.b_rooks & 0x8000000000000000 > 0
-                    && board.getOcupancy() & 0x7000000000000000 == 0
-                    && attacts & 0x7800000000000000 == 0;
-            }
-        }
+        return board.can_castle_queen_side(playing_as);
     }
 
     //Returns true if can castle
     pub fn can_castle(board: Board, playing_as: Turn, movve: LegalMove) -> bool {
-        match playing_as {
-            Turn::White => {
-                let attacts = Movement::extract_all_attacks_for_color(board, Turn::Black);
-
-                match movve.castle {
-                    CastleOptions::KingSide => {
-                        return Movement::can_castle_king_side(board, playing_as);
-                    }
-                    CastleOptions::QueenSide => {
-                        return Movement::can_castle_queen_side(board, playing_as);
-                    }
-                    CastleOptions::None => {
-                        return false;
-                    }
-                }
-            }
-            Turn::Black => {
-                let attacts = Movement::extract_all_attacks_for_color(board, Turn::White);
-                match movve.castle {
-                    CastleOptions::KingSide => {
-                        return Movement::can_castle_king_side(board, playing_as);
-                    }
-                    CastleOptions::QueenSide => {
-                        return Movement::can_castle_queen_side(board, playing_as);
-                    }
-                    CastleOptions::None => {
-                        return false;
-                    }
-                }
-            }
+        if movve.castle == CastleOptions::None {
+            return false;
         }
+        return board.can_castle(playing_as, movve.castle);
     }
 
-    pub fn check_for_captures_at(check_for: &Turn, board: Board, at_bitboard: u64) -> bool {
-        let pawns: u64;
-        let knights: u64;
-        let bishops: u64;
-        let rooks: u64;
-        let queen: u64;
-        let king: u64;
-        match check_for {
-            Turn::White => {
-                pawns = Movement::extract_pieces_moves_from_bitboard(
-                    board.b_pawns,
-                    Turn::White,
-                    PieceType::WhitePawn,
-                    board,
-                );
-                knights = Movement::extract_pieces_moves_from_bitboard(
-                    board.b_knights,
-                    Turn::White,
-                    PieceType::WhiteKnight,
-                    board,
-                );
-                rooks = Movement::extract_pieces_moves_from_bitboard(
-                    board.b_rooks,
-                    Turn::White,
-                    PieceType::WhiteRook,
-                    board,
-                );
-                bishops = Movement::extract_pieces_moves_from_bitboard(
-                    board.b_bishops,
-                    Turn::White,
-                    PieceType::WhiteBishop,
-                    board,
-                );
-                queen = Movement::extract_pieces_moves_from_bitboard(
-                    board.b_queen,
-                    Turn::White,
-                    PieceType::WhiteQueen,
-                    board,
-                );
-                king = Movement::get_king_moves(board.w_king, Turn::White, board);
-
-                let bitboard_merge =
-                    (pawns | knights | rooks | bishops | queen | king) & at_bitboard;
-
-                return bitboard_merge != 0;
-            }
-            Turn::Black => {
-                pawns = Movement::extract_pieces_moves_from_bitboard(
-                    board.w_pawns,
-                    Turn::White,
-                    PieceType::BlackPawn,
-                    board,
-                );
-                knights = Movement::extract_pieces_moves_from_bitboard(
-                    board.w_knights,
-                    Turn::Black,
-                    PieceType::BlackKnight,
-                    board,
-                );
-                rooks = Movement::extract_pieces_moves_from_bitboard(
-                    board.w_rooks,
-                    Turn::Black,
-                    PieceType::BlackRook,
-                    board,
-                );
-                bishops = Movement::extract_pieces_moves_from_bitboard(
-                    board.w_bishops,
-                    Turn::Black,
-                    PieceType::BlackBishop,
-                    board,
-                );
-                queen = Movement::extract_pieces_moves_from_bitboard(
-                    board.w_queen,
-                    Turn::Black,
-                    PieceType::BlackQueen,
-                    board,
-                );
-
-                king = Movement::get_king_moves(board.w_king, Turn::Black, board);
-
-                let bitboard_merge =
-                    (pawns | knights | rooks | bishops | queen | king) & at_bitboard;
-
-                return bitboard_merge != 0;
-            }
-        }
-    }
-
-    pub fn check_for_checkmate(check_for: Turn, board: Board) -> bool {
-        match check_for {
-            Turn::White => {
-                return Movement::check_for_check(check_for, board)
-                    && (Movement::extract_all_attacks_for_color(board, Turn::White)
-                        & Movement::extract_all_attacks_for_color(board, Turn::Black))
-                        == 0
-                    && Movement::get_king_moves(board.w_king, check_for, board)
-                        & Movement::extract_all_attacks_for_color(board, Turn::Black)
-                        == 0;
-            }
-            Turn::Black => {
-                return Movement::check_for_check(check_for, board)
-                    && (Movement::extract_all_attacks_for_color(board, Turn::White)
-                        & Movement::extract_all_attacks_for_color(board, Turn::Black))
-                        == 0
-                    && Movement::get_king_moves(board.w_king, check_for, board)
-                        & Movement::extract_all_attacks_for_color(board, Turn::White)
-                        != 0;
-            }
-        }
+    //True if the enemy of check_for attacks any of the given squares
+    pub fn check_for_captures_at(check_for: &Turn, board: Board, at_bitboard: Bitboard) -> bool {
+        let enemy = Movement::get_oposite_color(*check_for);
+        return at_bitboard
+            .into_iter()
+            .any(|square| board.is_square_attacked(square, enemy));
     }
 
-    pub fn get_color_attacks(check_for: Turn, board: Board) -> u64 {
-        let pawns: u64;
-        let knights: u64;
-        let bishops: u64;
-        let rooks: u64;
-        let queen: u64;
-        let king: u64;
-        match check_for {
-            Turn::Black => {
-                pawns = Movement::extract_pieces_moves_from_bitboard(
-                    board.b_pawns,
-                    Turn::Black,
-                    PieceType::BlackPawn,
-                    board,
-                );
-                knights = Movement::extract_pieces_moves_from_bitboard(
-                    board.b_knights,
-                    Turn::Black,
-                    PieceType::BlackKnight,
-                    board,
-                );
-                rooks = Movement::extract_pieces_moves_from_bitboard(
-                    board.b_rooks,
-                    Turn::Black,
-                    PieceType::BlackRook,
-                    board,
-                );
-                bishops = Movement::extract_pieces_moves_from_bitboard(
-                    board.b_bishops,
-                    Turn::Black,
-                    PieceType::BlackBishop,
-                    board,
-                );
-                queen = Movement::extract_pieces_moves_from_bitboard(
-                    board.b_queen,
-                    Turn::Black,
-                    PieceType::BlackQueen,
-                    board,
-                );
-                king = Movement::get_king_moves(board.b_king, Turn::Black, board);
-
-                let bitboard_merge = pawns | knights | rooks | bishops | queen | king;
-
-                return bitboard_merge;
-            }
-            Turn::White => {
-                pawns = Movement::extract_pieces_moves_from_bitboard(
-                    board.w_pawns,
-                    Turn::White,
-                    PieceType::WhitePawn,
-                    board,
-                );
-                knights = Movement::extract_pieces_moves_from_bitboard(
-                    board.w_knights,
-                    Turn::White,
-                    PieceType::WhiteKnight,
-                    board,
-                );
-                rooks = Movement::extract_pieces_moves_from_bitboard(
-                    board.w_rooks,
-                    Turn::White,
-                    PieceType::WhiteRook,
-                    board,
-                );
-                bishops = Movement::extract_pieces_moves_from_bitboard(
-                    board.w_bishops,
-                    Turn::White,
-                    PieceType::WhiteBishop,
-                    board,
-                );
-                queen = Movement::extract_pieces_moves_from_bitboard(
-                    board.w_queen,
-                    Turn::White,
-                    PieceType::WhiteQueen,
-                    board,
-                );
-                king = Movement::get_king_moves(board.b_king, Turn::Black, board);
-
-                let bitboard_merge = pawns | knights | rooks | bishops | queen | king;
-
-                return bitboard_merge;
+    //Every square attacked by a colour, own pieces included
+    pub fn get_color_attacks(check_for: Turn, board: Board) -> Bitboard {
+        let mut attacks = Bitboard::EMPTY;
+        for index in 0..64 {
+            let square = Square::from_index(index);
+            if board.is_square_attacked(square, check_for) {
+                attacks = attacks.with(square);
             }
         }
+        return attacks;
     }
 
     pub fn check_for_check(check_for: Turn, board: Board) -> bool {
-        let pawns: u64;
-        let knights: u64;
-        let bishops: u64;
-        let rooks: u64;
-        let queen: u64;
-        if matches!(check_for, Turn::White) {
-            pawns = Movement::extract_pieces_moves_from_bitboard(
-                board.b_pawns,
-                Turn::Black,
-                PieceType::BlackPawn,
-                board,
-            );
-            knights = Movement::extract_pieces_moves_from_bitboard(
-                board.b_knights,
-                Turn::Black,
-                PieceType::BlackKnight,
-                board,
-            );
-            rooks = Movement::extract_pieces_moves_from_bitboard(
-                board.b_rooks,
-                Turn::Black,
-                PieceType::BlackRook,
-                board,
-            );
-            bishops = Movement::extract_pieces_moves_from_bitboard(
-                board.b_bishops,
-                Turn::Black,
-                PieceType::BlackBishop,
-                board,
-            );
-            queen = Movement::extract_pieces_moves_from_bitboard(
-                board.b_queen,
-                Turn::Black,
-                PieceType::BlackQueen,
-                board,
-            );
-
-            let bitboard_merge = (pawns | knights | rooks | bishops | queen) & board.w_king;
-
-            return bitboard_merge != 0;
-        }
-        if matches!(check_for, Turn::Black) {
-            pawns = Movement::extract_pieces_moves_from_bitboard(
-                board.w_pawns,
-                Turn::White,
-                PieceType::WhitePawn,
-                board,
-            );
-            knights = Movement::extract_pieces_moves_from_bitboard(
-                board.w_knights,
-                Turn::White,
-                PieceType::WhiteKnight,
-                board,
-            );
-            rooks = Movement::extract_pieces_moves_from_bitboard(
-                board.w_rooks,
-                Turn::White,
-                PieceType::WhiteRook,
-                board,
-            );
-            bishops = Movement::extract_pieces_moves_from_bitboard(
-                board.w_bishops,
-                Turn::White,
-                PieceType::WhiteBishop,
-                board,
-            );
-            queen = Movement::extract_pieces_moves_from_bitboard(
-                board.w_queen,
-                Turn::White,
-                PieceType::WhiteQueen,
-                board,
-            );
-
-            let bitboard_merge = (pawns | knights | rooks | bishops | queen) & board.b_king;
-
-            return bitboard_merge != 0;
-        }
-        return false;
+        return board.is_in_check(check_for);
     }
 
-    pub fn check_for_check_at(check_for: &Turn, board: Board, at_bitboard: u64) -> bool {
-        let pawns: u64;
-        let knights: u64;
-        let bishops: u64;
-        let rooks: u64;
-        let queen: u64;
-        if matches!(check_for, Turn::White) {
-            pawns = Movement::extract_pieces_moves_from_bitboard(
-                board.b_pawns,
-                Turn::Black,
-                PieceType::BlackPawn,
-                board,
-            );
-            knights = Movement::extract_pieces_moves_from_bitboard(
-                board.b_knights,
-                Turn::Black,
-                PieceType::BlackKnight,
-                board,
-            );
-            rooks = Movement::extract_pieces_moves_from_bitboard(
-                board.b_rooks,
-                Turn::Black,
-                PieceType::BlackRook,
-                board,
-            );
-            bishops = Movement::extract_pieces_moves_from_bitboard(
-                board.b_bishops,
-                Turn::Black,
-                PieceType::BlackBishop,
-                board,
-            );
-            queen = Movement::extract_pieces_moves_from_bitboard(
-                board.b_queen,
-                Turn::Black,
-                PieceType::BlackQueen,
-                board,
-            );
-
-            let bitboard_merge = (pawns | knights | rooks | bishops | queen) & at_bitboard;
-
-            return bitboard_merge != 0;
-        }
-        if matches!(check_for, Turn::Black) {
-            pawns = Movement::extract_pieces_moves_from_bitboard(
-                board.w_pawns,
-                Turn::White,
-                PieceType::WhitePawn,
-                board,
-            );
-            knights = Movement::extract_pieces_moves_from_bitboard(
-                board.w_knights,
-                Turn::White,
-                PieceType::WhiteKnight,
-                board,
-            );
-            rooks = Movement::extract_pieces_moves_from_bitboard(
-                board.w_rooks,
-                Turn::White,
-                PieceType::WhiteRook,
-                board,
-            );
-            bishops = Movement::extract_pieces_moves_from_bitboard(
-                board.w_bishops,
-                Turn::White,
-                PieceType::WhiteBishop,
-                board,
-            );
-            queen = Movement::extract_pieces_moves_from_bitboard(
-                board.w_queen,
-                Turn::White,
-                PieceType::WhiteQueen,
-                board,
-            );
-
-            let bitboard_merge = (pawns | knights | rooks | bishops | queen) & at_bitboard;
-
-            return bitboard_merge != 0;
-        }
-        return false;
+    pub fn check_for_check_at(check_for: &Turn, board: Board, at_bitboard: Bitboard) -> bool {
+        return Movement::check_for_captures_at(check_for, board, at_bitboard);
     }
 
     fn extract_piece_moves_from_bitboard(
-        piece_bits: u64,
+        piece_square: Square,
         color: Turn,
         piece_type: PieceType,
         board: Board,
-    ) -> u64 {
+    ) -> Bitboard {
+        let piece_bits = piece_square.bitboard();
         match piece_type {
             PieceType::WhiteBishop => {
                 return Movement::get_bishop_moves(piece_bits, color, board);
@@ -590,153 +176,61 @@ impl Movement {
             PieceType::BlackQueen => return Movement::get_queen_moves(piece_bits, color, board),
             PieceType::BlackPawn => return Movement::get_pawn_moves(piece_bits, color, board),
             PieceType::BlackKing => return Movement::get_king_moves(piece_bits, color, board),
-            _ => 0,
+            _ => Bitboard::EMPTY,
         }
     }
 
     pub fn extract_pieces_moves_from_bitboard(
-        mut pieces_bits: u64,
+        pieces_bits: Bitboard,
         color: Turn,
         piece_type: PieceType,
         board: Board,
-    ) -> u64 {
-        if pieces_bits == 0 {
-            return 0;
-        } else {
-            let mut move_bits = 0;
-            while pieces_bits > 0 {
-                let piece_pos = Movement::msb_pos(pieces_bits);
-                let piece_bits = (1 as u64) << piece_pos;
-                move_bits |= Movement::extract_piece_moves_from_bitboard(
-                    piece_bits, color, piece_type, board,
-                );
-                pieces_bits = pieces_bits & !piece_bits;
-            }
-
-            return move_bits;
+    ) -> Bitboard {
+        let mut move_bits = Bitboard::EMPTY;
+        for square in pieces_bits {
+            move_bits |=
+                Movement::extract_piece_moves_from_bitboard(square, color, piece_type, board);
         }
+        return move_bits;
     }
 
-    pub fn extract_all_attacks_for_color(board: Board, color: Turn) -> u64 {
-        match color {
-            Turn::White => {
-                let mut white_attacks = Movement::extract_pieces_moves_from_bitboard(
-                    board.w_pawns,
-                    color,
-                    PieceType::WhitePawn,
-                    board,
-                );
-
-                white_attacks |= Movement::extract_pieces_moves_from_bitboard(
-                    board.w_rooks,
-                    color,
-                    PieceType::WhiteRook,
-                    board,
-                );
-
-                white_attacks |= Movement::extract_pieces_moves_from_bitboard(
-                    board.w_bishops,
-                    color,
-                    PieceType::WhiteBishop,
-                    board,
-                );
-                white_attacks |= Movement::extract_pieces_moves_from_bitboard(
-                    board.w_knights,
-                    color,
-                    PieceType::WhiteKnight,
-                    board,
-                );
-                white_attacks |= Movement::extract_pieces_moves_from_bitboard(
-                    board.w_queen,
-                    color,
-                    PieceType::WhiteQueen,
-                    board,
-                );
-
-                white_attacks |= Movement::extract_pieces_moves_from_bitboard(
-                    board.w_king,
-                    color,
-                    PieceType::WhiteKing,
-                    board,
-                );
-
-                return white_attacks;
-            }
-            Turn::Black => {
-                let mut black_attacks = Movement::extract_pieces_moves_from_bitboard(
-                    board.b_pawns,
-                    color,
-                    PieceType::BlackPawn,
-                    board,
-                );
-
-                black_attacks |= Movement::extract_pieces_moves_from_bitboard(
-                    board.b_rooks,
-                    color,
-                    PieceType::BlackRook,
-                    board,
-                );
-
-                black_attacks |= Movement::extract_pieces_moves_from_bitboard(
-                    board.b_bishops,
-                    color,
-                    PieceType::BlackBishop,
-                    board,
-                );
-                black_attacks |= Movement::extract_pieces_moves_from_bitboard(
-                    board.b_knights,
-                    color,
-                    PieceType::BlackKnight,
-                    board,
-                );
-                black_attacks |= Movement::extract_pieces_moves_from_bitboard(
-                    board.b_queen,
-                    color,
-                    PieceType::BlackQueen,
-                    board,
-                );
-
-                black_attacks |= Movement::extract_pieces_moves_from_bitboard(
-                    board.b_king,
-                    color,
-                    PieceType::BlackKing,
-                    board,
-                );
-
-                return black_attacks;
-            }
-        }
+    pub fn extract_all_attacks_for_color(board: Board, color: Turn) -> Bitboard {
+        return Movement::get_color_attacks(color, board);
     }
 
-    pub fn get_knigth_moves(knight_bits: u64, color: Turn, board: Board) -> u64 {
+    pub fn get_knigth_moves(knight_bits: Bitboard, color: Turn, board: Board) -> Bitboard {
         return Knight::get_moves(knight_bits, color, board);
     }
 
-    pub fn get_pawn_moves(pawn_bits: u64, color: Turn, board: Board) -> u64 {
+    pub fn get_pawn_moves(pawn_bits: Bitboard, color: Turn, board: Board) -> Bitboard {
         return Pawn::get_moves(pawn_bits, color, board);
     }
 
-    pub fn is_enpassant(piece_bits: u64, en_passant_pos: u64) -> bool {
-        return (piece_bits << 1 & en_passant_pos != 0) || (piece_bits >> 1 & en_passant_pos != 0);
+    pub fn is_enpassant(piece_bits: Bitboard, en_passant_pos: Bitboard) -> bool {
+        return !((piece_bits.east() | piece_bits.west()) & en_passant_pos).is_empty();
     }
 
-    pub fn get_pawn_moves_enpassant(pawn_bits: u64, enpassant_bits: u64, color: Turn) -> u64 {
+    pub fn get_pawn_moves_enpassant(
+        pawn_bits: Bitboard,
+        enpassant_bits: Bitboard,
+        color: Turn,
+    ) -> Bitboard {
         return Pawn::get_moves_enpassant(pawn_bits, enpassant_bits, color);
     }
 
-    pub fn get_rook_moves(rook_bits: u64, color: Turn, board: Board) -> u64 {
+    pub fn get_rook_moves(rook_bits: Bitboard, color: Turn, board: Board) -> Bitboard {
         return Rook::get_moves(rook_bits, color, board);
     }
 
-    pub fn get_bishop_moves(bishop_bits: u64, color: Turn, board: Board) -> u64 {
+    pub fn get_bishop_moves(bishop_bits: Bitboard, color: Turn, board: Board) -> Bitboard {
         return Bishop::get_moves(bishop_bits, color, board);
     }
 
-    pub fn get_queen_moves(queen_bits: u64, color: Turn, board: Board) -> u64 {
+    pub fn get_queen_moves(queen_bits: Bitboard, color: Turn, board: Board) -> Bitboard {
         return Queen::get_moves(queen_bits, color, board);
     }
 
-    pub fn get_king_moves(king_bits: u64, color: Turn, board: Board) -> u64 {
+    pub fn get_king_moves(king_bits: Bitboard, color: Turn, board: Board) -> Bitboard {
         return King::get_moves(king_bits, color, board);
     }
 }
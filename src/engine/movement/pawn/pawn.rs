@@ -1,81 +1,78 @@
 use crate::engine::{
-    board::board::{Board, Turn},
-    movement::movement::{Movement, ROW_2, ROW_4, ROW_5, ROW_7, SINGLE_BYTE},
+    board::{
+        bitboard::bitboard::Bitboard,
+        board::{Board, Turn},
+    },
+    movement::{
+        attacks::attacks::Attacks,
+        movement::{ROW_2, ROW_4, ROW_5, ROW_7},
+    },
 };
 
 pub struct Pawn {}
 //rook movement
 
 impl Pawn {
-    pub fn get_moves(pawn_bits: u64, color: Turn, board: Board) -> u64 {
+    //Pushes and captures of a single pawn
+    pub fn get_moves(pawn_bits: Bitboard, color: Turn, board: Board) -> Bitboard {
+        let square = match pawn_bits.first() {
+            Some(square) => square,
+            None => return Bitboard::EMPTY,
+        };
         let white_bitboard = board.getWhiteBitboard();
         let black_bitboard = board.getBlackBitboard();
+        let empty = !(white_bitboard | black_bitboard);
+        let row = square.rank() as i8;
 
-        let piece_index = Movement::get_piece_index(pawn_bits);
-        let row: i8 = piece_index / 8;
-        let column: i8 = piece_index % 8;
-
-        let mut move_bits = 0;
-        if matches!(color, Turn::Black) {
-            let blocking_piece = pawn_bits >> 8 & (white_bitboard | black_bitboard);
-            if blocking_piece == 0 {
-                move_bits |= pawn_bits >> 8;
-            }
-
-            if row == ROW_7 {
-                let start_blocking_piece =
-                    (pawn_bits >> 8 | pawn_bits >> 16) & (white_bitboard | black_bitboard);
-                if start_blocking_piece == 0 {
-                    move_bits |= pawn_bits >> 16;
+        match color {
+            Turn::Black => {
+                let single_push = pawn_bits.south() & empty;
+                let mut move_bits = single_push;
+                if row == ROW_7 {
+                    move_bits |= single_push.south() & empty;
                 }
+                return move_bits | Attacks::get_pawn_attacks(square, color) & white_bitboard;
             }
-            move_bits |=
-                (pawn_bits >> 9 | pawn_bits >> 7) & (white_bitboard & SINGLE_BYTE << (row - 1) * 8);
-        }
-
-        if matches!(color, Turn::White) {
-            let blocking_piece = pawn_bits << 8 & (white_bitboard | black_bitboard);
-            if blocking_piece == 0 {
-                move_bits |= pawn_bits << 8;
-            }
-
-            if row == ROW_2 {
-                let start_blocking_piece =
-                    (pawn_bits << 8 | pawn_bits << 16) & (white_bitboard | black_bitboard);
-                if start_blocking_piece == 0 {
-                    move_bits |= pawn_bits << 16;
+            Turn::White => {
+                let single_push = pawn_bits.north() & empty;
+                let mut move_bits = single_push;
+                if row == ROW_2 {
+                    move_bits |= single_push.north() & empty;
                 }
+                return move_bits | Attacks::get_pawn_attacks(square, color) & black_bitboard;
             }
-            move_bits |=
-                (pawn_bits << 9 | pawn_bits << 7) & (black_bitboard & SINGLE_BYTE << (row + 1) * 8);
         }
-
-        return move_bits;
     }
 
-    pub fn get_moves_enpassant(pawn_bits: u64, unpassant_bits: u64, color: Turn) -> u64 {
-        let piece_index = Movement::get_piece_index(pawn_bits);
-        let row: i8 = piece_index / 8;
-        let pawn_mask = pawn_bits << 1 | pawn_bits >> 1;
+    //Squares attacked diagonally, whether or not there is a piece to capture
+    pub fn get_attacks(pawn_bits: Bitboard, color: Turn) -> Bitboard {
+        let mut attack_bits = Bitboard::EMPTY;
+        for square in pawn_bits {
+            attack_bits |= Attacks::get_pawn_attacks(square, color);
+        }
+        return attack_bits;
+    }
 
-        let mut move_bits = 0;
-        if matches!(color, Turn::Black) {
-            if row != ROW_4 {
-                return move_bits;
-            }
-            if pawn_mask & unpassant_bits != 0 {
-                move_bits |= unpassant_bits >> 8;
-            }
+    //unpassant_bits is the pawn that just made a double push
+    pub fn get_moves_enpassant(
+        pawn_bits: Bitboard,
+        unpassant_bits: Bitboard,
+        color: Turn,
+    ) -> Bitboard {
+        let square = match pawn_bits.first() {
+            Some(square) => square,
+            None => return Bitboard::EMPTY,
+        };
+        let row = square.rank() as i8;
+        let pawn_mask = pawn_bits.east() | pawn_bits.west();
+        if (pawn_mask & unpassant_bits).is_empty() {
+            return Bitboard::EMPTY;
         }
 
-        if matches!(color, Turn::White) {
-            if row != ROW_5 {
-                return move_bits;
-            }
-            if pawn_mask & unpassant_bits != 0 {
-                move_bits |= unpassant_bits << 8;
-            }
+        match color {
+            Turn::Black if row == ROW_4 => return unpassant_bits.south(),
+            Turn::White if row == ROW_5 => return unpassant_bits.north(),
+            _ => return Bitboard::EMPTY,
         }
-        return move_bits;
     }
 }
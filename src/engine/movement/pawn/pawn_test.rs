@@ -1,193 +1,219 @@
 #[cfg(test)]
 mod pawn_tests {
     use crate::engine::{
-        board::board::{Board, Turn},
+        board::{
+            bitboard::bitboard::Bitboard,
+            board::{Board, Turn},
+        },
         movement::movement::Movement,
     };
 
+    const B_ROOKS: Bitboard = Bitboard(0x8100000000000081);
+    const B_KNIGHTS: Bitboard = Bitboard(0x42000000004200);
+    const B_BISHOPS: Bitboard = Bitboard(0x240000240000);
+    const B_QUEEN: Bitboard = Bitboard(0x800000000);
+    const B_KING: Bitboard = Bitboard(0x0);
+    const B_PAWNS: Bitboard = Bitboard(0x18000000);
+
+    const W_ROOKS: Bitboard = Bitboard(0x8100000000000081);
+    const W_KNIGHTS: Bitboard = Bitboard(0x42000000004200);
+    const W_BISHOPS: Bitboard = Bitboard(0x240000240000);
+    const W_QUEEN: Bitboard = Bitboard(0x800000000);
+    const W_KING: Bitboard = Bitboard(0x0);
+    const W_PAWNS: Bitboard = Bitboard(0x18000000);
+
+    //Kingless fixture, so it can not go through the FEN loader
     fn get_board_by_color(color: &Turn) -> Board {
+        let mut board = Board::new_empty();
         match color {
             Turn::White => {
-                Board::new_from_fenn_notation("R6R/1N4N1/2B2B2/4Q3/3PP3/2B2B2/1N4N1/R6R b - - 0 1")
+                board.w_rooks = W_ROOKS;
+                board.w_knights = W_KNIGHTS;
+                board.w_bishops = W_BISHOPS;
+                board.w_queen = W_QUEEN;
+                board.w_king = W_KING;
+                board.w_pawns = W_PAWNS;
             }
             Turn::Black => {
-                Board::new_from_fenn_notation("R6R/1N4N1/2B2B2/4Q3/3PP3/2B2B2/1N4N1/R6R b - - 0 1")
+                board.b_rooks = B_ROOKS;
+                board.b_knights = B_KNIGHTS;
+                board.b_bishops = B_BISHOPS;
+                board.b_queen = B_QUEEN;
+                board.b_king = B_KING;
+                board.b_pawns = B_PAWNS;
             }
         }
+        board.set_turn(Turn::Black);
+        return board;
     }
 
-    fn do_move(board: Board, test_bits: u64) -> u64 {
-        let result: u64 = Movement::get_pawn_moves_bitboard(
-            test_bits,
-            Turn::White,
-            board.getWhiteBitboard(),
-            board.getBlackBitboard(),
-        );
+    fn do_move(board: Board, test_bits: Bitboard) -> Bitboard {
+        let result = Movement::get_pawn_moves(test_bits, Turn::White, board);
         return result;
     }
 
     //Empty board tests
     #[test]
     fn empty_board_bottom_right() {
-        let test_bits: u64 = 0x1;
+        let test_bits = Bitboard(0x1);
         let board = Board::new_empty();
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x100);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0x100));
     }
 
     #[test]
     fn empty_board_first_rank_rigth() {
-        let test_bits: u64 = 0x100;
+        let test_bits = Bitboard(0x100);
         let board = Board::new_empty();
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x1010000);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0x1010000));
     }
 
     #[test]
     fn empty_board_first_rank_left() {
-        let test_bits: u64 = 0x8000;
+        let test_bits = Bitboard(0x8000);
         let board = Board::new_empty();
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x80800000);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0x80800000));
     }
 
     #[test]
     fn empty_board_middle_rank_middle() {
-        let test_bits: u64 = 0x10000000;
+        let test_bits = Bitboard(0x10000000);
         let board = Board::new_empty();
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x1000000000);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0x1000000000));
     }
 
     #[test]
     fn empty_board_middle_rank_right() {
-        let test_bits: u64 = 0x100000000;
+        let test_bits = Bitboard(0x100000000);
         let board = Board::new_empty();
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x10000000000);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0x10000000000));
     }
 
     #[test]
     fn empty_board_middle_rank_left() {
-        let test_bits: u64 = 0x8000000000;
+        let test_bits = Bitboard(0x8000000000);
         let board = Board::new_empty();
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x800000000000);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0x800000000000));
     }
 
     #[test]
     fn empty_board_top_rank_left() {
-        let test_bits: u64 = 0x8000000000000000;
+        let test_bits = Bitboard(0x8000000000000000);
         let board = Board::new_empty();
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x0);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0x0));
     }
 
     #[test]
     fn empty_board_top_rank_right() {
-        let test_bits: u64 = 0x100000000000000;
+        let test_bits = Bitboard(0x100000000000000);
         let board = Board::new_empty();
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x0);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0x0));
     }
 
     #[test]
     fn empty_board_top_rank_middle() {
-        let test_bits: u64 = 0x1000000000000000;
+        let test_bits = Bitboard(0x1000000000000000);
         let board = Board::new_empty();
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x0);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0x0));
     }
 
     //Full board
     //with ally
     #[test]
     fn full_board_first_rank_rigth() {
-        let test_bits: u64 = 0x400;
+        let test_bits = Bitboard(0x400);
         let board = get_board_by_color(&Turn::White);
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x0);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0x0));
     }
 
     #[test]
     fn full_board_first_rank_middle() {
-        let test_bits: u64 = 0x800;
+        let test_bits = Bitboard(0x800);
         let board = get_board_by_color(&Turn::White);
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x80000);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0x80000));
     }
 
     #[test]
     fn full_board_first_rank_left() {
-        let test_bits: u64 = 0x2000;
+        let test_bits = Bitboard(0x2000);
         let board = get_board_by_color(&Turn::White);
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x0);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0x0));
     }
 
     #[test]
     fn full_board_middle_rank_left() {
-        let test_bits: u64 = 0x2000000000;
+        let test_bits = Bitboard(0x2000000000);
         let board = get_board_by_color(&Turn::White);
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x0);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0x0));
     }
 
     #[test]
     fn full_board_middle_rank_right() {
-        let test_bits: u64 = 0x400000000;
+        let test_bits = Bitboard(0x400000000);
         let board = get_board_by_color(&Turn::White);
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x0);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0x0));
     }
 
     //with enemy
     #[test]
     fn full_board_first_rank_rigth_enemy() {
-        let test_bits: u64 = 0x800;
+        let test_bits = Bitboard(0x800);
         let board = get_board_by_color(&Turn::Black);
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x80000);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0xc0000));
     }
 
     #[test]
     fn full_board_first_rank_left_enemy() {
-        let test_bits: u64 = 0x1000;
+        let test_bits = Bitboard(0x1000);
         let board = get_board_by_color(&Turn::Black);
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x100000);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0x300000));
     }
 
     #[test]
     fn full_board_middle_rank_left_enemy() {
-        let test_bits: u64 = 0x100000;
+        let test_bits = Bitboard(0x100000);
         let board = get_board_by_color(&Turn::Black);
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x8000000);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0x8000000));
     }
     #[test]
     fn full_board_middle_rank_right_enemy() {
-        let test_bits: u64 = 0x80000;
+        let test_bits = Bitboard(0x80000);
         let board = get_board_by_color(&Turn::Black);
 
-        let result: u64 = do_move(board, test_bits);
-        assert_eq!(result, 0x10000000);
+        let result = do_move(board, test_bits);
+        assert_eq!(result, Bitboard(0x10000000));
     }
 }
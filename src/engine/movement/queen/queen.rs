@@ -1,15 +1,25 @@
-use crate::engine::board::{
-    self,
-    board::{Board, Turn},
+use crate::engine::{
+    board::{
+        bitboard::bitboard::Bitboard,
+        board::{Board, Turn},
+    },
+    movement::{magic::magic::Magic, movement::Movement},
 };
 
 pub struct Queen {}
 //rook movement
 
 impl Queen {
-    pub fn get_moves(queen_bits: u64, color: Turn, board: Board) -> u64 {
-        let move_bits: u64 = board.pub_get_rook_moves_bitboard(queen_bits, color)
-            | board.pub_get_bishop_moves_bitboard(queen_bits, color);
-        return move_bits & !queen_bits;
+    pub fn get_moves(queen_bits: Bitboard, color: Turn, board: Board) -> Bitboard {
+        let white_bitboard = board.getWhiteBitboard();
+        let black_bitboard = board.getBlackBitboard();
+        let ally_bloquers = Movement::ally_blockers(&color, white_bitboard, black_bitboard);
+        let ocupancy = white_bitboard | black_bitboard;
+
+        let mut move_bits = Bitboard::EMPTY;
+        for square in queen_bits {
+            move_bits |= Magic::get_queen_attacks(square, ocupancy);
+        }
+        return move_bits & !ally_bloquers;
     }
 }
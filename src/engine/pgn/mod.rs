@@ -0,0 +1,2 @@
+pub mod pgn;
+mod pgn_test;
@@ -0,0 +1,895 @@
+use std::{fmt, io::BufRead, time::Duration};
+
+use crate::engine::{
+    board::{
+        board::{Board, Turn},
+        fenn::fenn::FenError,
+        moves::moves::Move,
+        san::san::SanError,
+    },
+    game::result::result::GameResult,
+    movement::movement::Movement,
+};
+
+pub struct PgnGame {
+    pub tags: Vec<(String, String)>,
+    pub start: Board,
+    //Comments ahead of the first move
+    pub comments: Vec<String>,
+    pub moves: Vec<PgnMove>,
+    //None for * or a missing result
+    pub result: Option<GameResult>,
+}
+
+pub struct PgnMove {
+    pub san: String,
+    pub legal: Move,
+    pub nags: Vec<u8>,
+    //Comments after the move, with [%clk] and [%eval] taken out
+    pub comments: Vec<String>,
+    pub clock: Option<Duration>,
+    pub eval: Option<PgnEval>,
+    //Lines played instead of this move, from the position before it
+    pub variations: Vec<Vec<PgnMove>>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PgnEval {
+    Pawns(f64),
+    MateIn(i32),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PgnErrorKind {
+    ReadError(String),
+    UnterminatedTag,
+    BadTag,
+    UnterminatedComment,
+    UnexpectedToken(String),
+    InvalidFen(FenError),
+    IllegalMove(SanError),
+    UnbalancedVariation,
+    UnterminatedVariation,
+}
+
+//Line and column are 1 based and count from the start of the input
+#[derive(Debug, PartialEq)]
+pub struct PgnError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: PgnErrorKind,
+}
+
+impl fmt::Display for PgnErrorKind {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PgnErrorKind::ReadError(error) => write!(fmt, "read error: {}", error),
+            PgnErrorKind::UnterminatedTag => write!(fmt, "unterminated tag"),
+            PgnErrorKind::BadTag => write!(fmt, "malformed tag"),
+            PgnErrorKind::UnterminatedComment => write!(fmt, "unterminated comment"),
+            PgnErrorKind::UnexpectedToken(token) => write!(fmt, "unexpected '{}'", token),
+            PgnErrorKind::InvalidFen(error) => write!(fmt, "invalid FEN tag: {}", error),
+            PgnErrorKind::IllegalMove(error) => write!(fmt, "{}", error),
+            PgnErrorKind::UnbalancedVariation => write!(fmt, "')' without a variation"),
+            PgnErrorKind::UnterminatedVariation => write!(fmt, "variation is not closed"),
+        }
+    }
+}
+
+impl fmt::Display for PgnError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}:{}: {}", self.line, self.column, self.kind)
+    }
+}
+
+impl std::error::Error for PgnError {}
+
+impl PgnGame {
+    pub fn get_tag(&self, name: &str) -> Option<&str> {
+        return self
+            .tags
+            .iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value.as_str());
+    }
+
+    //Position after the main line
+    pub fn get_final_board(&self) -> Board {
+        let mut board = self.start;
+        for pgn_move in &self.moves {
+            board.make_move(pgn_move.legal, board.get_turn());
+        }
+        return board;
+    }
+
+    //Export format, tags first and movetext wrapped at 80 columns
+    pub fn to_pgn(&self) -> String {
+        let mut pgn = String::new();
+        for (name, value) in &self.tags {
+            let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+            pgn.push_str(&format!("[{} \"{}\"]\n", name, value));
+        }
+        if !self.tags.is_empty() {
+            pgn.push('\n');
+        }
+
+        let mut tokens: Vec<String> = Vec::new();
+        for comment in &self.comments {
+            tokens.push(format!("{{{}}}", comment));
+        }
+        let turn = self.start.get_turn();
+        PGN::write_moves(&self.moves, self.start.full_move_count, turn, &mut tokens);
+        match self.result {
+            Some(result) => tokens.push(result.to_string()),
+            None => tokens.push(String::from("*")),
+        }
+
+        let mut line_length = 0;
+        for token in tokens {
+            if line_length > 0 && line_length + 1 + token.len() > 80 {
+                pgn.push('\n');
+                line_length = 0;
+            } else if line_length > 0 {
+                pgn.push(' ');
+                line_length += 1;
+            }
+            line_length += token.len();
+            pgn.push_str(&token);
+        }
+        pgn.push('\n');
+        return pgn;
+    }
+}
+
+impl fmt::Display for PgnEval {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PgnEval::Pawns(pawns) => write!(fmt, "{}", pawns),
+            PgnEval::MateIn(moves) => write!(fmt, "#{}", moves),
+        }
+    }
+}
+
+//Reads one game at a time, a broken game is reported and the next one is read as usual.
+//A game with unreadable text is dropped and reported at the first bad byte
+pub struct PgnReader<R: BufRead> {
+    reader: R,
+    line_number: usize,
+    //First tag line of the next game, read while looking for the end of the previous one
+    next_line: Option<(String, Option<PgnError>)>,
+    finished: bool,
+}
+
+impl<R: BufRead> PgnReader<R> {
+    pub fn new(reader: R) -> PgnReader<R> {
+        return PgnReader {
+            reader,
+            line_number: 0,
+            next_line: None,
+            finished: false,
+        };
+    }
+
+    //Tracks {} comments over the line, ; comments end with it
+    fn is_comment_open(line: &str, mut in_comment: bool) -> bool {
+        for c in line.chars() {
+            match c {
+                '}' if in_comment => in_comment = false,
+                ';' if !in_comment => return false,
+                '{' if !in_comment => in_comment = true,
+                _ => {}
+            }
+        }
+        return in_comment;
+    }
+
+    //Invalid UTF-8 is replaced so the game can still be split off, the error marks the bad byte
+    fn decode_line(bytes: Vec<u8>, line_number: usize) -> (String, Option<PgnError>) {
+        let error = match String::from_utf8(bytes) {
+            Ok(line) => return (line, None),
+            Err(error) => error,
+        };
+        let valid_up_to = error.utf8_error().valid_up_to();
+        let bytes = error.into_bytes();
+        let read_error = PgnError {
+            line: line_number,
+            column: String::from_utf8_lossy(&bytes[..valid_up_to])
+                .chars()
+                .count()
+                + 1,
+            kind: PgnErrorKind::ReadError(String::from("invalid UTF-8")),
+        };
+        return (
+            String::from_utf8_lossy(&bytes).into_owned(),
+            Some(read_error),
+        );
+    }
+}
+
+impl<R: BufRead> Iterator for PgnReader<R> {
+    type Item = Result<PgnGame, PgnError>;
+
+    //A game ends where a tag line follows movetext
+    fn next(&mut self) -> Option<Result<PgnGame, PgnError>> {
+        let mut text = String::new();
+        let mut first_line = self.line_number + 1;
+        let mut in_comment = false;
+        let mut seen_movetext = false;
+        let mut read_error: Option<PgnError> = None;
+        if let Some((line, line_error)) = self.next_line.take() {
+            first_line = self.line_number;
+            text.push_str(&line);
+            read_error = line_error;
+        }
+
+        while !self.finished {
+            let mut bytes: Vec<u8> = Vec::new();
+            match self.reader.read_until(b'\n', &mut bytes) {
+                Ok(0) => self.finished = true,
+                Ok(_) => {
+                    self.line_number += 1;
+                    let (line, line_error) = PgnReader::<R>::decode_line(bytes, self.line_number);
+                    let trimmed = line.trim_start();
+                    if !in_comment && seen_movetext && trimmed.starts_with('[') {
+                        self.next_line = Some((line, line_error));
+                        break;
+                    }
+                    if in_comment || !(trimmed.is_empty() || trimmed.starts_with('[')) {
+                        seen_movetext = true;
+                    }
+                    //Braces in tag values are not comments
+                    if in_comment || !trimmed.starts_with('[') {
+                        in_comment = PgnReader::<R>::is_comment_open(&line, in_comment);
+                    }
+                    if read_error.is_none() {
+                        read_error = line_error;
+                    }
+                    if text.trim().is_empty() && trimmed.is_empty() {
+                        first_line = self.line_number + 1;
+                        continue;
+                    }
+                    text.push_str(&line);
+                }
+                //Nothing can be read after this, the partial game is reported where it stopped
+                Err(error) => {
+                    self.finished = true;
+                    let partial = String::from_utf8_lossy(&bytes);
+                    if read_error.is_none() {
+                        read_error = Some(PgnError {
+                            line: self.line_number + 1,
+                            column: partial.chars().count() + 1,
+                            kind: PgnErrorKind::ReadError(error.to_string()),
+                        });
+                    }
+                }
+            }
+        }
+
+        if let Some(read_error) = read_error {
+            return Some(Err(read_error));
+        }
+        if text.trim().is_empty() {
+            return None;
+        }
+        return Some(PGN::parse_game(&text, first_line));
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum TokenKind {
+    Tag(String, String),
+    Comment(String),
+    Nag(u8),
+    OpenVariation,
+    CloseVariation,
+    Result(Option<GameResult>),
+    Move(String),
+}
+
+impl TokenKind {
+    fn to_text(&self) -> String {
+        match self {
+            TokenKind::Tag(name, _) => return format!("[{}", name),
+            TokenKind::Comment(_) => return String::from("{"),
+            TokenKind::Nag(nag) => return format!("${}", nag),
+            TokenKind::OpenVariation => return String::from("("),
+            TokenKind::CloseVariation => return String::from(")"),
+            TokenKind::Result(Some(GameResult::WhiteWins)) => return String::from("1-0"),
+            TokenKind::Result(Some(GameResult::BlackWins)) => return String::from("0-1"),
+            TokenKind::Result(Some(GameResult::Draw)) => return String::from("1/2-1/2"),
+            TokenKind::Result(None) => return String::from("*"),
+            TokenKind::Move(san) => return san.to_string(),
+        }
+    }
+}
+
+struct Token {
+    kind: TokenKind,
+    line: usize,
+    column: usize,
+}
+
+struct Tokenizer {
+    chars: Vec<char>,
+    index: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Tokenizer {
+    fn peek(&self) -> Option<char> {
+        return self.chars.get(self.index).copied();
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.index += 1;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        return Some(c);
+    }
+
+    fn error(line: usize, column: usize, kind: PgnErrorKind) -> PgnError {
+        return PgnError { line, column, kind };
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_whitespace()) {
+            self.advance();
+        }
+    }
+
+    //[Name "value"], quotes and backslashes in the value are escaped with a backslash
+    fn read_tag(&mut self, line: usize, column: usize) -> Result<TokenKind, PgnError> {
+        self.advance();
+        self.skip_whitespace();
+        let mut name = String::new();
+        while let Some(c) = self
+            .peek()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+        {
+            name.push(c);
+            self.advance();
+        }
+        self.skip_whitespace();
+        if name.is_empty() || self.advance() != Some('"') {
+            return Err(Tokenizer::error(line, column, PgnErrorKind::BadTag));
+        }
+
+        let mut value = String::new();
+        loop {
+            match self.advance() {
+                Some('"') => break,
+                Some('\\') => match self.advance() {
+                    Some(escaped) => value.push(escaped),
+                    None => {
+                        return Err(Tokenizer::error(
+                            line,
+                            column,
+                            PgnErrorKind::UnterminatedTag,
+                        ))
+                    }
+                },
+                Some('\n') | None => {
+                    return Err(Tokenizer::error(
+                        line,
+                        column,
+                        PgnErrorKind::UnterminatedTag,
+                    ))
+                }
+                Some(c) => value.push(c),
+            }
+        }
+        self.skip_whitespace();
+        match self.advance() {
+            Some(']') => return Ok(TokenKind::Tag(name, value)),
+            None => {
+                return Err(Tokenizer::error(
+                    line,
+                    column,
+                    PgnErrorKind::UnterminatedTag,
+                ))
+            }
+            Some(_) => return Err(Tokenizer::error(line, column, PgnErrorKind::BadTag)),
+        }
+    }
+
+    fn get_suffix_nag(suffix: &str) -> Option<u8> {
+        match suffix {
+            "!" => return Some(1),
+            "?" => return Some(2),
+            "!!" => return Some(3),
+            "??" => return Some(4),
+            "!?" => return Some(5),
+            "?!" => return Some(6),
+            _ => return None,
+        }
+    }
+
+    //Move numbers are dropped, suffix annotations become NAGs
+    fn read_symbol(&mut self, tokens: &mut Vec<Token>) -> Result<(), PgnError> {
+        let (line, column) = (self.line, self.column);
+        let mut word = String::new();
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || "{}();[$".contains(c) {
+                break;
+            }
+            word.push(c);
+            self.advance();
+        }
+
+        let result = match word.as_str() {
+            "1-0" => Some(Some(GameResult::WhiteWins)),
+            "0-1" => Some(Some(GameResult::BlackWins)),
+            "1/2-1/2" => Some(Some(GameResult::Draw)),
+            "*" => Some(None),
+            _ => None,
+        };
+        if let Some(result) = result {
+            tokens.push(Token {
+                kind: TokenKind::Result(result),
+                line,
+                column,
+            });
+            return Ok(());
+        }
+
+        let digits = word.chars().take_while(|c| c.is_ascii_digit()).count();
+        let mut san = word.as_str();
+        if digits == word.len() || word[digits..].starts_with('.') {
+            san = word[digits..].trim_start_matches('.');
+        }
+        if san.is_empty() {
+            return Ok(());
+        }
+        let column = column + word.len() - san.len();
+
+        let suffix_start = san.trim_end_matches(['!', '?']).len();
+        let (san, suffix) = san.split_at(suffix_start);
+        if san.is_empty() {
+            return Err(Tokenizer::error(
+                line,
+                column,
+                PgnErrorKind::UnexpectedToken(word.to_string()),
+            ));
+        }
+        tokens.push(Token {
+            kind: TokenKind::Move(san.to_string()),
+            line,
+            column,
+        });
+        if !suffix.is_empty() {
+            match Tokenizer::get_suffix_nag(suffix) {
+                Some(nag) => tokens.push(Token {
+                    kind: TokenKind::Nag(nag),
+                    line,
+                    column: column + san.len(),
+                }),
+                None => {
+                    return Err(Tokenizer::error(
+                        line,
+                        column + san.len(),
+                        PgnErrorKind::UnexpectedToken(suffix.to_string()),
+                    ))
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    fn tokenize(text: &str, first_line: usize) -> Result<Vec<Token>, PgnError> {
+        let mut tokenizer = Tokenizer {
+            chars: text.chars().collect(),
+            index: 0,
+            line: first_line,
+            column: 1,
+        };
+        let mut tokens: Vec<Token> = Vec::new();
+        loop {
+            tokenizer.skip_whitespace();
+            let (line, column) = (tokenizer.line, tokenizer.column);
+            let kind = match tokenizer.peek() {
+                None => return Ok(tokens),
+                //Escaped lines are meant for other programs
+                Some('%') if column == 1 => {
+                    while tokenizer.peek().is_some_and(|c| c != '\n') {
+                        tokenizer.advance();
+                    }
+                    continue;
+                }
+                Some('[') => tokenizer.read_tag(line, column)?,
+                Some('{') => {
+                    tokenizer.advance();
+                    let mut comment = String::new();
+                    loop {
+                        match tokenizer.advance() {
+                            Some('}') => break,
+                            Some(c) => comment.push(c),
+                            None => {
+                                return Err(Tokenizer::error(
+                                    line,
+                                    column,
+                                    PgnErrorKind::UnterminatedComment,
+                                ))
+                            }
+                        }
+                    }
+                    TokenKind::Comment(comment)
+                }
+                Some(';') => {
+                    tokenizer.advance();
+                    let mut comment = String::new();
+                    while let Some(c) = tokenizer.peek().filter(|c| *c != '\n') {
+                        comment.push(c);
+                        tokenizer.advance();
+                    }
+                    TokenKind::Comment(comment)
+                }
+                Some('(') => {
+                    tokenizer.advance();
+                    TokenKind::OpenVariation
+                }
+                Some(')') => {
+                    tokenizer.advance();
+                    TokenKind::CloseVariation
+                }
+                Some('$') => {
+                    tokenizer.advance();
+                    let mut digits = String::new();
+                    while let Some(c) = tokenizer.peek().filter(|c| c.is_ascii_digit()) {
+                        digits.push(c);
+                        tokenizer.advance();
+                    }
+                    match digits.parse::<u8>() {
+                        Ok(nag) => TokenKind::Nag(nag),
+                        Err(_) => {
+                            return Err(Tokenizer::error(
+                                line,
+                                column,
+                                PgnErrorKind::UnexpectedToken(format!("${}", digits)),
+                            ))
+                        }
+                    }
+                }
+                Some(']') => {
+                    return Err(Tokenizer::error(
+                        line,
+                        column,
+                        PgnErrorKind::UnexpectedToken(String::from("]")),
+                    ))
+                }
+                Some(_) => {
+                    tokenizer.read_symbol(&mut tokens)?;
+                    continue;
+                }
+            };
+            tokens.push(Token { kind, line, column });
+        }
+    }
+}
+
+pub struct PGN {}
+
+impl PGN {
+    fn parse_game(text: &str, first_line: usize) -> Result<PgnGame, PgnError> {
+        let tokens = Tokenizer::tokenize(text, first_line)?;
+        let mut index = 0;
+
+        let mut tags: Vec<(String, String)> = Vec::new();
+        let mut start = Board::new();
+        while let Some(token) = tokens.get(index) {
+            let (name, value) = match &token.kind {
+                TokenKind::Tag(name, value) => (name, value),
+                _ => break,
+            };
+            if name == "FEN" {
+                start = match Board::from_fen(value) {
+                    Ok(board) => board,
+                    Err(error) => {
+                        return Err(Tokenizer::error(
+                            token.line,
+                            token.column,
+                            PgnErrorKind::InvalidFen(error),
+                        ))
+                    }
+                };
+            }
+            tags.push((name.to_string(), value.to_string()));
+            index += 1;
+        }
+
+        let mut game = PgnGame {
+            tags,
+            start,
+            comments: Vec::new(),
+            moves: Vec::new(),
+            result: None,
+        };
+        let mut comments: Vec<String> = Vec::new();
+        game.moves = PGN::parse_line(&tokens, &mut index, start, &mut comments, false)?;
+        game.comments = comments;
+
+        if let Some(Token {
+            kind: TokenKind::Result(result),
+            ..
+        }) = tokens.get(index)
+        {
+            game.result = *result;
+            index += 1;
+        }
+        //Only comments may follow the result
+        for token in &tokens[index..] {
+            if let TokenKind::Comment(_) = token.kind {
+                continue;
+            }
+            return Err(Tokenizer::error(
+                token.line,
+                token.column,
+                PgnErrorKind::UnexpectedToken(token.kind.to_text()),
+            ));
+        }
+        return Ok(game);
+    }
+
+    //Reads moves up to the result, or the closing bracket of a variation
+    fn parse_line(
+        tokens: &[Token],
+        index: &mut usize,
+        start: Board,
+        leading_comments: &mut Vec<String>,
+        is_variation: bool,
+    ) -> Result<Vec<PgnMove>, PgnError> {
+        let mut moves: Vec<PgnMove> = Vec::new();
+        let mut board = start;
+        let mut before = start;
+
+        while let Some(token) = tokens.get(*index) {
+            let unexpected = |text: String| {
+                return Tokenizer::error(
+                    token.line,
+                    token.column,
+                    PgnErrorKind::UnexpectedToken(text),
+                );
+            };
+            match &token.kind {
+                TokenKind::Move(san) => {
+                    let legal = match board.parse_san(san) {
+                        Ok(legal) => legal,
+                        Err(error) => {
+                            return Err(Tokenizer::error(
+                                token.line,
+                                token.column,
+                                PgnErrorKind::IllegalMove(error),
+                            ))
+                        }
+                    };
+                    before = board;
+                    board.make_move(legal, board.get_turn());
+                    moves.push(PgnMove {
+                        san: san.to_string(),
+                        legal,
+                        nags: Vec::new(),
+                        comments: Vec::new(),
+                        clock: None,
+                        eval: None,
+                        variations: Vec::new(),
+                    });
+                }
+                TokenKind::Nag(nag) => match moves.last_mut() {
+                    Some(last) => last.nags.push(*nag),
+                    None => return Err(unexpected(token.kind.to_text())),
+                },
+                TokenKind::Comment(text) => {
+                    let (comment, clock, eval) = PGN::parse_comment(text);
+                    match moves.last_mut() {
+                        Some(last) => {
+                            last.clock = clock.or(last.clock);
+                            last.eval = eval.or(last.eval);
+                            if !comment.is_empty() {
+                                last.comments.push(comment);
+                            }
+                        }
+                        None if !comment.is_empty() => leading_comments.push(comment),
+                        None => {}
+                    }
+                }
+                TokenKind::OpenVariation => {
+                    let (line, column) = (token.line, token.column);
+                    *index += 1;
+                    let mut variation_comments: Vec<String> = Vec::new();
+                    let mut variation =
+                        PGN::parse_line(tokens, index, before, &mut variation_comments, true)?;
+                    let closed = match tokens.get(*index) {
+                        Some(token) => token.kind == TokenKind::CloseVariation,
+                        None => false,
+                    };
+                    if !closed {
+                        return Err(Tokenizer::error(
+                            line,
+                            column,
+                            PgnErrorKind::UnterminatedVariation,
+                        ));
+                    }
+                    let last = match moves.last_mut() {
+                        Some(last) => last,
+                        None => {
+                            return Err(Tokenizer::error(
+                                line,
+                                column,
+                                PgnErrorKind::UnexpectedToken(String::from("(")),
+                            ))
+                        }
+                    };
+                    //Comments ahead of the first move stay with it
+                    if let Some(first) = variation.first_mut() {
+                        variation_comments.append(&mut first.comments);
+                        first.comments = variation_comments;
+                    }
+                    last.variations.push(variation);
+                }
+                TokenKind::CloseVariation if is_variation => return Ok(moves),
+                TokenKind::CloseVariation => {
+                    return Err(Tokenizer::error(
+                        token.line,
+                        token.column,
+                        PgnErrorKind::UnbalancedVariation,
+                    ))
+                }
+                TokenKind::Result(_) if is_variation => {
+                    return Err(Tokenizer::error(
+                        token.line,
+                        token.column,
+                        PgnErrorKind::UnterminatedVariation,
+                    ))
+                }
+                TokenKind::Result(_) => return Ok(moves),
+                TokenKind::Tag(_, _) => return Err(unexpected(token.kind.to_text())),
+            }
+            *index += 1;
+        }
+        return Ok(moves);
+    }
+
+    //Takes the [%clk] and [%eval] commands out of a comment
+    fn parse_comment(text: &str) -> (String, Option<Duration>, Option<PgnEval>) {
+        let mut comment = String::new();
+        let mut clock = None;
+        let mut eval = None;
+        let mut rest = text;
+        while let Some(start) = rest.find("[%") {
+            let end = match rest[start..].find(']') {
+                Some(end) => start + end,
+                None => break,
+            };
+            let mut command = rest[start + 2..end].split_whitespace();
+            let parsed = match (command.next(), command.next()) {
+                (Some("clk"), Some(value)) => {
+                    PGN::parse_clock(value).map(|value| clock = Some(value))
+                }
+                (Some("eval"), Some(value)) => {
+                    PGN::parse_eval(value).map(|value| eval = Some(value))
+                }
+                _ => None,
+            };
+            comment.push_str(&rest[..start]);
+            if parsed.is_none() {
+                comment.push_str(&rest[start..=end]);
+            }
+            rest = &rest[end + 1..];
+        }
+        comment.push_str(rest);
+        let comment = comment.split_whitespace().collect::<Vec<&str>>().join(" ");
+        return (comment, clock, eval);
+    }
+
+    //Move numbers come back after comments and variations, as the export format asks
+    fn write_moves(
+        moves: &[PgnMove],
+        mut full_move: u16,
+        mut turn: Turn,
+        tokens: &mut Vec<String>,
+    ) {
+        let mut needs_number = true;
+        for pgn_move in moves {
+            match turn {
+                Turn::White => tokens.push(format!("{}.", full_move)),
+                Turn::Black if needs_number => tokens.push(format!("{}...", full_move)),
+                Turn::Black => {}
+            }
+            tokens.push(pgn_move.san.to_string());
+            needs_number = false;
+            for nag in &pgn_move.nags {
+                tokens.push(format!("${}", nag));
+            }
+
+            let mut commands: Vec<String> = Vec::new();
+            if let Some(eval) = pgn_move.eval {
+                commands.push(format!("[%eval {}]", eval));
+            }
+            if let Some(clock) = pgn_move.clock {
+                commands.push(format!("[%clk {}]", PGN::format_clock(clock)));
+            }
+            let mut comments = pgn_move.comments.iter();
+            if !commands.is_empty() {
+                let mut comment = commands.join(" ");
+                if let Some(text) = comments.next() {
+                    comment = format!("{} {}", comment, text);
+                }
+                tokens.push(format!("{{{}}}", comment));
+            }
+            for comment in comments {
+                tokens.push(format!("{{{}}}", comment));
+            }
+            if !commands.is_empty() || !pgn_move.comments.is_empty() {
+                needs_number = true;
+            }
+
+            for variation in &pgn_move.variations {
+                let mut variation_tokens: Vec<String> = Vec::new();
+                PGN::write_moves(variation, full_move, turn, &mut variation_tokens);
+                match variation_tokens.is_empty() {
+                    true => variation_tokens.push(String::from("()")),
+                    false => {
+                        variation_tokens[0].insert(0, '(');
+                        let last = variation_tokens.len() - 1;
+                        variation_tokens[last].push(')');
+                    }
+                }
+                tokens.append(&mut variation_tokens);
+                needs_number = true;
+            }
+
+            if turn == Turn::Black {
+                full_move += 1;
+            }
+            turn = Movement::get_oposite_color(turn);
+        }
+    }
+
+    fn format_clock(clock: Duration) -> String {
+        let seconds = clock.as_secs();
+        let mut text = format!(
+            "{}:{:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        );
+        let millis = clock.subsec_millis();
+        if millis > 0 {
+            text.push_str(format!(".{:03}", millis).trim_end_matches('0'));
+        }
+        return text;
+    }
+
+    //h:mm:ss with optional fractions of a second
+    fn parse_clock(value: &str) -> Option<Duration> {
+        let mut seconds = 0.0;
+        for part in value.split(':') {
+            if !part.chars().all(|c| c.is_ascii_digit() || c == '.') {
+                return None;
+            }
+            seconds = seconds * 60.0 + part.parse::<f64>().ok()?;
+        }
+        //Rejects negative, infinite and out of range values
+        return Duration::try_from_secs_f64(seconds).ok();
+    }
+
+    //Pawns from white's point of view, or #n for a mate, an appended ,depth is ignored
+    fn parse_eval(value: &str) -> Option<PgnEval> {
+        let value = value.split(',').next()?;
+        match value.strip_prefix('#') {
+            Some(mate) => return mate.parse().ok().map(PgnEval::MateIn),
+            None => {
+                return value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|pawns| pawns.is_finite())
+                    .map(PgnEval::Pawns)
+            }
+        }
+    }
+}
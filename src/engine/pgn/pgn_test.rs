@@ -0,0 +1,267 @@
+#[cfg(test)]
+mod pgn_tests {
+    use std::{
+        io::{self, BufReader, Read},
+        time::Duration,
+    };
+
+    use crate::engine::{
+        board::san::san::SanError,
+        game::result::result::GameResult,
+        pgn::pgn::{PgnError, PgnErrorKind, PgnEval, PgnGame, PgnReader},
+    };
+
+    fn read_games(text: &str) -> Vec<Result<PgnGame, PgnError>> {
+        return PgnReader::new(text.as_bytes()).collect();
+    }
+
+    const GAMES: &str = r#"[Event "Casual"]
+[White "Player \"One\""]
+[Black "Player Two"]
+[Result "1-0"]
+
+1. e4 e5 2. Nf3 Nc6 3. Bc4 Nf6?! 4. Ng5 d5 5. exd5 Na5 6. Bb5+ c6 7. dxc6 bxc6
+8. Be2 h6 9. Nf3 e4 10. Ne5 Bd6 11. d4 exd3 12. Nxd3 Qc7 13. b3 O-O 1-0
+
+[Event "Second"]
+[Result "1/2-1/2"]
+
+1.d4 d5 2.c4 c6 1/2-1/2
+"#;
+
+    #[test]
+    fn reads_tags_moves_and_results() {
+        let games = read_games(GAMES);
+        assert_eq!(games.len(), 2);
+
+        let first = games[0].as_ref().unwrap();
+        assert_eq!(first.get_tag("Event"), Some("Casual"));
+        assert_eq!(first.get_tag("White"), Some("Player \"One\""));
+        assert_eq!(first.moves.len(), 26);
+        assert_eq!(first.moves[5].san, "Nf6");
+        assert_eq!(first.moves[5].nags, vec![6]);
+        assert_eq!(first.moves[25].legal.to_uci(false), "e8g8");
+        assert_eq!(first.result, Some(GameResult::WhiteWins));
+
+        let second = games[1].as_ref().unwrap();
+        assert_eq!(second.moves.len(), 4);
+        assert_eq!(second.result, Some(GameResult::Draw));
+        assert_eq!(
+            second.get_final_board().to_fen(),
+            "rnbqkbnr/pp2pppp/2p5/3p4/2PP4/8/PP2PPPP/RNBQKBNR w KQkq - 0 3"
+        );
+    }
+
+    #[test]
+    fn braces_in_tag_values_do_not_open_comments() {
+        let text =
+            "[Event \"{x\"]\n[Annotator \"a;b\"]\n\n1. e4 *\n\n[Event \"Second\"]\n\n1. d4 *\n\n\
+                    [Event \"Third\"]\n\n1. c4 *\n";
+        let games = read_games(text);
+        assert_eq!(games.len(), 3);
+        assert_eq!(games[0].as_ref().unwrap().get_tag("Event"), Some("{x"));
+        assert_eq!(games[0].as_ref().unwrap().get_tag("Annotator"), Some("a;b"));
+        assert_eq!(games[2].as_ref().unwrap().get_tag("Event"), Some("Third"));
+    }
+
+    #[test]
+    fn variations_comments_and_nags() {
+        let text = "{Opening} 1. e4 $1 {best by test} (1. d4 d5 (1... Nf6 2. c4) 2. c4) \
+                    (1. c4 ; English\n e5) 1... c5 ({A variation comment} 1... e5 2. Nf3) *";
+        let games = read_games(text);
+        let game = games[0].as_ref().unwrap();
+        assert_eq!(game.comments, vec!["Opening"]);
+        assert_eq!(game.result, None);
+        assert_eq!(game.moves.len(), 2);
+
+        let e4 = &game.moves[0];
+        assert_eq!(e4.nags, vec![1]);
+        assert_eq!(e4.comments, vec!["best by test"]);
+        assert_eq!(e4.variations.len(), 2);
+        let queens_pawn = &e4.variations[0];
+        assert_eq!(queens_pawn.len(), 3);
+        assert_eq!(queens_pawn[1].variations[0].len(), 2);
+        assert_eq!(queens_pawn[1].variations[0][1].san, "c4");
+        assert_eq!(e4.variations[1][0].comments, vec!["English"]);
+
+        let c5 = &game.moves[1];
+        assert_eq!(c5.variations[0][0].san, "e5");
+        assert_eq!(c5.variations[0][0].comments, vec!["A variation comment"]);
+    }
+
+    #[test]
+    fn clock_and_eval_annotations() {
+        let text = "1. e4 { [%eval 0.17] [%clk 0:03:00] } 1... e5 { good [%clk 0:02:59.5] [%eval #-3,20] } \
+                    2. Nf3 { [%csl Ga1] } *";
+        let games = read_games(text);
+        let game = games[0].as_ref().unwrap();
+        assert_eq!(game.moves[0].eval, Some(PgnEval::Pawns(0.17)));
+        assert_eq!(game.moves[0].clock, Some(Duration::from_secs(180)));
+        assert!(game.moves[0].comments.is_empty());
+        assert_eq!(game.moves[1].eval, Some(PgnEval::MateIn(-3)));
+        assert_eq!(game.moves[1].clock, Some(Duration::from_millis(179500)));
+        assert_eq!(game.moves[1].comments, vec!["good"]);
+        assert_eq!(game.moves[2].comments, vec!["[%csl Ga1]"]);
+        assert_eq!(game.moves[2].clock, None);
+    }
+
+    #[test]
+    fn out_of_range_annotations_stay_in_the_comment() {
+        let text = "1. e4 { [%clk inf] } 1... e5 { [%clk 99999999999999999999999] } \
+                    2. Nf3 { [%clk -0:00:01] [%eval nan] } *\n\n[Event \"Next\"]\n\n1. d4 *\n";
+        let games = read_games(text);
+        assert_eq!(games.len(), 2);
+        let game = games[0].as_ref().unwrap();
+        assert_eq!(game.moves[0].clock, None);
+        assert_eq!(game.moves[0].comments, vec!["[%clk inf]"]);
+        assert_eq!(game.moves[1].clock, None);
+        assert_eq!(
+            game.moves[1].comments,
+            vec!["[%clk 99999999999999999999999]"]
+        );
+        assert_eq!(game.moves[2].clock, None);
+        assert_eq!(game.moves[2].eval, None);
+        assert_eq!(game.moves[2].comments, vec!["[%clk -0:00:01] [%eval nan]"]);
+        assert_eq!(games[1].as_ref().unwrap().moves.len(), 1);
+    }
+
+    #[test]
+    fn setup_from_fen_tag() {
+        let text = "[SetUp \"1\"]\n[FEN \"7k/8/8/5Q2/8/8/8/K7 w - - 0 1\"]\n\n1. Qg6 0-1\n";
+        let games = read_games(text);
+        let game = games[0].as_ref().unwrap();
+        assert_eq!(game.start.to_fen(), "7k/8/8/5Q2/8/8/8/K7 w - - 0 1");
+        assert_eq!(game.moves[0].legal.to_uci(false), "f5g6");
+        assert_eq!(game.result, Some(GameResult::BlackWins));
+
+        let games = read_games("[FEN \"8/8/8/8/8/8/8/8 w - - 0 1\"]\n\n*\n");
+        let error = games[0].as_ref().err().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert!(matches!(error.kind, PgnErrorKind::InvalidFen(_)));
+    }
+
+    #[test]
+    fn errors_point_at_the_input_and_reading_goes_on() {
+        let text = "[Event \"Broken\"]\n\n1. e4 e5\n2. Ke3 Nc6 *\n\n[Event \"Fine\"]\n\n1. d4 *\n";
+        let games: Vec<_> = PgnReader::new(text.as_bytes()).collect();
+        assert_eq!(games.len(), 2);
+        let error = games[0].as_ref().err().unwrap();
+        assert_eq!((error.line, error.column), (4, 4));
+        assert_eq!(
+            error.kind,
+            PgnErrorKind::IllegalMove(SanError::IllegalMove(String::from("Ke3")))
+        );
+        assert_eq!(error.to_string(), format!("4:4: {}", error.kind));
+        let fine = games[1].as_ref().unwrap();
+        assert_eq!(fine.get_tag("Event"), Some("Fine"));
+        assert_eq!(fine.moves.len(), 1);
+    }
+
+    #[test]
+    fn malformed_input() {
+        let kind = |text: &str| {
+            let games = read_games(text);
+            let error = games[0].as_ref().err().unwrap();
+            return (error.line, error.column, format!("{:?}", error.kind));
+        };
+        assert_eq!(
+            kind("1. e4 e5) *"),
+            (1, 9, format!("{:?}", PgnErrorKind::UnbalancedVariation))
+        );
+        assert_eq!(
+            kind("1. e4 (1. d4 d5 *"),
+            (1, 17, format!("{:?}", PgnErrorKind::UnterminatedVariation))
+        );
+        assert_eq!(
+            kind("1. e4 {never closed\n"),
+            (1, 7, format!("{:?}", PgnErrorKind::UnterminatedComment))
+        );
+        assert_eq!(
+            kind("[Event \"open\n1. e4 *"),
+            (1, 1, format!("{:?}", PgnErrorKind::UnterminatedTag))
+        );
+        assert_eq!(
+            kind("[Event open]\n1. e4 *"),
+            (1, 1, format!("{:?}", PgnErrorKind::BadTag))
+        );
+        assert_eq!(
+            kind("(1. d4) 1. e4 *"),
+            (
+                1,
+                1,
+                format!("{:?}", PgnErrorKind::UnexpectedToken(String::from("(")))
+            )
+        );
+        assert_eq!(
+            kind("1. e4 * {fine} e5"),
+            (
+                1,
+                16,
+                format!("{:?}", PgnErrorKind::UnexpectedToken(String::from("e5")))
+            )
+        );
+    }
+
+    struct FailingReader {}
+
+    impl Read for FailingReader {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            return Err(io::Error::new(io::ErrorKind::Other, "disk gone"));
+        }
+    }
+
+    #[test]
+    fn read_errors_point_at_the_input() {
+        let bytes = b"[Event \"A\"]\n\n1. e4 {caf\xff} e5 *\n\n[Event \"B\"]\n\n1. d4 *\n";
+        let games: Vec<_> = PgnReader::new(&bytes[..]).collect();
+        assert_eq!(games.len(), 2);
+        let error = games[0].as_ref().err().unwrap();
+        assert_eq!((error.line, error.column), (3, 11));
+        assert_eq!(
+            error.kind,
+            PgnErrorKind::ReadError(String::from("invalid UTF-8"))
+        );
+        assert_eq!(games[1].as_ref().unwrap().get_tag("Event"), Some("B"));
+
+        //The partial game is dropped and reported where reading stopped
+        let text = "[Event \"A\"]\n\n1. e4 e5 *\n\n[Event \"B\"]\n\n1. e4 e5 2. N";
+        let reader = BufReader::new(text.as_bytes().chain(FailingReader {}));
+        let games: Vec<_> = PgnReader::new(reader).collect();
+        assert_eq!(games.len(), 2);
+        assert!(games[0].is_ok());
+        let error = games[1].as_ref().err().unwrap();
+        assert_eq!((error.line, error.column), (7, 14));
+        assert!(matches!(error.kind, PgnErrorKind::ReadError(_)));
+    }
+
+    #[test]
+    fn export_round_trip() {
+        let text = "[Event \"Quote \\\" and \\\\\"]\n\n{Opening} 1. e4 $1 {best by test} (1. d4 d5 (1... Nf6 2. c4) 2. c4) \
+                    1... c5 {[%eval -0.3] [%clk 0:02:59.5] fine} (1... e5 2. Nf3) 2. Nf3 1-0\n";
+        let game = read_games(text).remove(0).unwrap();
+        let pgn = game.to_pgn();
+        assert_eq!(
+            pgn,
+            "[Event \"Quote \\\" and \\\\\"]\n\n{Opening} 1. e4 $1 {best by test} (1. d4 d5 (1... Nf6 2. c4) \
+             2. c4) 1... c5\n{[%eval -0.3] [%clk 0:02:59.5] fine} (1... e5 2. Nf3) 2. Nf3 1-0\n"
+        );
+        let again = read_games(&pgn).remove(0).unwrap();
+        assert_eq!(again.to_pgn(), pgn);
+        assert_eq!(again.get_tag("Event"), Some("Quote \" and \\"));
+
+        for game in read_games(GAMES) {
+            let pgn = game.unwrap().to_pgn();
+            assert!(pgn.lines().all(|line| line.len() <= 80), "{}", pgn);
+            assert_eq!(read_games(&pgn).remove(0).unwrap().to_pgn(), pgn);
+        }
+
+        //Games from a FEN start with its move number
+        let fen =
+            "[FEN \"rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1\"]\n\n1... e5 *\n";
+        assert!(read_games(fen)
+            .remove(0)
+            .unwrap()
+            .to_pgn()
+            .ends_with("\n\n1... e5 *\n"));
+    }
+}
@@ -1,6 +1,7 @@
-use crate::engine::{
-    board::board::{Board, PieceType},
-    movement::movement::{Movement, NOT_FOUND},
+use crate::engine::board::{
+    bitboard::bitboard::Bitboard,
+    board::{Board, PieceType},
+    square::square::Square,
 };
 
 const BACKGROUND_COLOR_WHITE: &str = "\x1b[38;5;231;48;5;22m";
@@ -44,40 +45,31 @@ impl Printer {
     }
     pub(self) fn get_print_param_array_for_piece(
         piece: PieceType,
-        piece_board: u64,
+        piece_board: Bitboard,
         board_array: [&'static str; 64],
     ) -> [&'static str; 64] {
         let mut board_arr = board_array;
-        let mut board_aux = piece_board;
-        for _ in 0..64 {
-            let pos = Movement::lsb_pos(board_aux);
-            if pos != NOT_FOUND {
-                board_arr[pos as usize] = Self::get_piece_str_for_piece_type(piece);
-                board_aux = if pos == 63 {
-                    return board_arr;
-                } else {
-                    board_aux >> pos + 1 << pos + 1
-                };
-            }
+        for square in piece_board {
+            board_arr[square.index()] = Self::get_piece_str_for_piece_type(piece);
         }
         return board_arr;
     }
 
     pub fn print_bitboards(
         board_name: &str,
-        b_pawn_board: u64,
-        b_knigth_board: u64,
-        b_bishop_board: u64,
-        b_rook_board: u64,
-        b_queen_board: u64,
-        b_king_board: u64,
+        b_pawn_board: Bitboard,
+        b_knigth_board: Bitboard,
+        b_bishop_board: Bitboard,
+        b_rook_board: Bitboard,
+        b_queen_board: Bitboard,
+        b_king_board: Bitboard,
 
-        w_pawn_board: u64,
-        w_knigth_board: u64,
-        w_bishop_board: u64,
-        w_rook_board: u64,
-        w_queen_board: u64,
-        w_king_board: u64,
+        w_pawn_board: Bitboard,
+        w_knigth_board: Bitboard,
+        w_bishop_board: Bitboard,
+        w_rook_board: Bitboard,
+        w_queen_board: Bitboard,
+        w_king_board: Bitboard,
     ) {
         let mut board_arr: [&str; 64] = [""; 64];
         //Black pawns
@@ -131,62 +123,11 @@ impl Printer {
 
     pub fn print_board(board_name: &str, board: Board) {
         let mut board_arr: [&str; 64] = [""; 64];
-        //Black pawns
-        board_arr = Printer::get_print_param_array_for_piece(
-            PieceType::BlackPawn,
-            board.b_pawns,
-            board_arr,
-        );
-        board_arr = Printer::get_print_param_array_for_piece(
-            PieceType::BlackKnight,
-            board.b_knights,
-            board_arr,
-        );
-        board_arr = Printer::get_print_param_array_for_piece(
-            PieceType::BlackBishop,
-            board.b_bishops,
-            board_arr,
-        );
-        board_arr = Printer::get_print_param_array_for_piece(
-            PieceType::BlackRook,
-            board.b_rooks,
-            board_arr,
-        );
-        board_arr = Printer::get_print_param_array_for_piece(
-            PieceType::BlackQueen,
-            board.b_queen,
-            board_arr,
-        );
-        board_arr =
-            Printer::get_print_param_array_for_piece(PieceType::BlackKing, board.b_king, board_arr);
-
-        board_arr = Printer::get_print_param_array_for_piece(
-            PieceType::WhitePawn,
-            board.w_pawns,
-            board_arr,
-        );
-        board_arr = Printer::get_print_param_array_for_piece(
-            PieceType::WhiteKnight,
-            board.w_knights,
-            board_arr,
-        );
-        board_arr = Printer::get_print_param_array_for_piece(
-            PieceType::WhiteBishop,
-            board.w_bishops,
-            board_arr,
-        );
-        board_arr = Printer::get_print_param_array_for_piece(
-            PieceType::WhiteRook,
-            board.w_rooks,
-            board_arr,
-        );
-        board_arr = Printer::get_print_param_array_for_piece(
-            PieceType::WhiteQueen,
-            board.w_queen,
-            board_arr,
-        );
-        board_arr =
-            Printer::get_print_param_array_for_piece(PieceType::WhiteKing, board.w_king, board_arr);
+        for (index, piece) in board_arr.iter_mut().enumerate() {
+            if let Some(piece_type) = board.piece_at(Square::from_index(index)) {
+                *piece = Printer::get_piece_str_for_piece_type(piece_type);
+            }
+        }
         board_arr.reverse();
 
         Printer::print(board_name, board_arr);
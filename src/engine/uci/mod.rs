@@ -0,0 +1,2 @@
+pub mod uci;
+mod uci_test;
@@ -0,0 +1,322 @@
+use std::{
+    io::{stdin, BufRead},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use crate::engine::{
+    board::{
+        board::{Board, Turn},
+        moves::moves::Move,
+    },
+    game::analyzer::analyzer::{Analyzer, EvalMove, MATE, SEARCH_DEPTH},
+    movement::movement::Movement,
+};
+
+const ENGINE_NAME: &str = "chessbot";
+const ENGINE_AUTHOR: &str = "guilleastr";
+
+const MAX_DEPTH: i8 = 64;
+const MAX_SCORE_CP: f64 = 30000.0;
+//Scores this close to MATE are mates, no search reaches that many plies
+const MAX_MATE_PLIES: f64 = 1000.0;
+//Fraction of the remaining clock spent on a single move
+const MOVES_TO_GO: u64 = 30;
+
+pub struct Uci {
+    board: Board,
+    depth: i8,
+    chess960: bool,
+    //Cleared by a rejected position command, go is refused until a valid one arrives
+    has_position: bool,
+    search: Option<JoinHandle<()>>,
+}
+
+impl Uci {
+    pub fn new() -> Uci {
+        return Uci {
+            board: Board::new(),
+            depth: SEARCH_DEPTH,
+            chess960: false,
+            has_position: true,
+            search: None,
+        };
+    }
+
+    pub fn run(&mut self) {
+        let input = stdin();
+        for line in input.lock().lines() {
+            let command = match line {
+                Ok(command) => command,
+                Err(_) => break,
+            };
+            if !self.handle_command(&command) {
+                break;
+            }
+        }
+        self.stop_search();
+    }
+
+    pub fn get_board(&self) -> Board {
+        return self.board;
+    }
+
+    pub fn has_position(&self) -> bool {
+        return self.has_position;
+    }
+
+    pub fn get_depth(&self) -> i8 {
+        return self.depth;
+    }
+
+    //Clamped before narrowing so large values do not wrap
+    pub fn clamp_depth(depth: i64) -> i8 {
+        return depth.clamp(1, MAX_DEPTH as i64) as i8;
+    }
+
+    //Returns false once the engine has to quit
+    pub fn handle_command(&mut self, command: &str) -> bool {
+        let args: Vec<&str> = command.split_whitespace().collect();
+        if args.is_empty() {
+            return true;
+        }
+
+        match args[0] {
+            "uci" => {
+                println!("id name {}", ENGINE_NAME);
+                println!("id author {}", ENGINE_AUTHOR);
+                println!(
+                    "option name Depth type spin default {} min 1 max {}",
+                    SEARCH_DEPTH, MAX_DEPTH
+                );
+                println!("option name UCI_Chess960 type check default false");
+                println!("uciok");
+            }
+            "isready" => println!("readyok"),
+            "ucinewgame" => {
+                self.stop_search();
+                self.board = Board::new();
+                self.board.chess960 = self.chess960;
+                self.has_position = true;
+            }
+            "position" => {
+                self.stop_search();
+                self.has_position = self.set_position(&args[1..]);
+                if !self.has_position {
+                    println!("info string invalid position {}", args[1..].join(" "));
+                }
+            }
+            "go" if args.get(1) == Some(&"perft") => {
+                self.stop_search();
+                self.perft(&args[2..]);
+            }
+            "go" => {
+                self.stop_search();
+                self.go(&args[1..]);
+            }
+            "stop" => self.stop_search(),
+            "setoption" => self.set_option(&args[1..]),
+            "quit" => {
+                self.stop_search();
+                return false;
+            }
+            _ => {}
+        }
+        return true;
+    }
+
+    //position startpos|fen <fen> [moves <move>...]
+    pub fn set_position(&mut self, args: &[&str]) -> bool {
+        let moves_index = args.iter().position(|&x| x == "moves");
+        let position_args = match moves_index {
+            Some(index) => &args[..index],
+            None => args,
+        };
+
+        let mut board = match position_args.first() {
+            Some(&"startpos") => Board::new(),
+            Some(&"fen") => match Board::from_fen(&position_args[1..].join(" ")) {
+                Ok(board) => board,
+                Err(_) => return false,
+            },
+            _ => return false,
+        };
+        //Castles are read and written as king takes rook in Chess960 mode
+        board.chess960 |= self.chess960;
+
+        if let Some(index) = moves_index {
+            for notation in &args[index + 1..] {
+                let turn = board.get_turn();
+                match Move::from_long_algebraic(notation, &board, turn) {
+                    Some(legal_move) => {
+                        board.do_move(legal_move, turn);
+                        board.set_turn(Movement::get_oposite_color(turn));
+                    }
+                    None => return false,
+                }
+            }
+        }
+
+        self.board = board;
+        return true;
+    }
+
+    fn set_option(&mut self, args: &[&str]) {
+        //setoption name <id> value <x>
+        if args.len() < 4 || args[0] != "name" || args[2] != "value" {
+            return;
+        }
+        if args[1].eq_ignore_ascii_case("depth") {
+            if let Ok(depth) = args[3].parse::<i64>() {
+                self.depth = Uci::clamp_depth(depth);
+            }
+        }
+        if args[1].eq_ignore_ascii_case("uci_chess960") {
+            if let Ok(chess960) = args[3].to_lowercase().parse::<bool>() {
+                self.chess960 = chess960;
+                self.board.chess960 = chess960;
+            }
+        }
+    }
+
+    fn go(&mut self, args: &[&str]) {
+        if !self.has_position {
+            println!("info string no valid position, send position first");
+            println!("bestmove 0000");
+            return;
+        }
+        let mut depth = self.depth;
+        let mut move_time: Option<u64> = None;
+        let turn = self.board.get_turn();
+
+        let mut index = 0;
+        while index < args.len() {
+            let value = args.get(index + 1).and_then(|x| x.parse::<i64>().ok());
+            match (args[index], value) {
+                ("depth", Some(value)) => depth = Uci::clamp_depth(value),
+                ("movetime", Some(value)) => move_time = Some(value.max(0) as u64),
+                ("wtime", Some(value)) if turn == Turn::White => {
+                    move_time = Some(value.max(0) as u64 / MOVES_TO_GO);
+                    depth = MAX_DEPTH;
+                }
+                ("btime", Some(value)) if turn == Turn::Black => {
+                    move_time = Some(value.max(0) as u64 / MOVES_TO_GO);
+                    depth = MAX_DEPTH;
+                }
+                ("infinite", _) => depth = MAX_DEPTH,
+                _ => {}
+            }
+            index += 1;
+        }
+
+        Analyzer::reset_search();
+        let finished = Arc::new(AtomicBool::new(false));
+
+        if let Some(move_time) = move_time {
+            let timer_finished = Arc::clone(&finished);
+            thread::spawn(move || {
+                let deadline = Instant::now() + Duration::from_millis(move_time);
+                while Instant::now() < deadline {
+                    if timer_finished.load(Ordering::SeqCst) {
+                        return;
+                    }
+                    thread::sleep(Duration::from_millis(5));
+                }
+                if !timer_finished.load(Ordering::SeqCst) {
+                    Analyzer::stop();
+                }
+            });
+        }
+
+        let board = self.board;
+        self.search = Some(thread::spawn(move || {
+            Uci::search(board, turn, depth);
+            finished.store(true, Ordering::SeqCst);
+        }));
+    }
+
+    //go perft <depth>, prints the node count of every root move
+    fn perft(&self, args: &[&str]) {
+        let depth = match args.first().and_then(|x| x.parse::<u8>().ok()) {
+            Some(depth) => depth,
+            None => return,
+        };
+        let start = Instant::now();
+        let mut nodes = 0;
+        for (legal_move, move_nodes) in self.board.divide(depth) {
+            println!("{}: {}", legal_move.to_uci(self.board.chess960), move_nodes);
+            nodes += move_nodes;
+        }
+        println!();
+        println!("Nodes searched: {}", nodes);
+        println!("Time: {}ms", start.elapsed().as_millis());
+    }
+
+    fn stop_search(&mut self) {
+        if let Some(search) = self.search.take() {
+            Analyzer::stop();
+            let _ = search.join();
+        }
+    }
+
+    //Iterative deepening, keeping the last fully searched depth
+    fn search(board: Board, turn: Turn, max_depth: i8) {
+        let legal_moves = board.get_moves(turn);
+        if legal_moves.is_empty() {
+            println!("bestmove 0000");
+            return;
+        }
+
+        let start = Instant::now();
+        let mut best: Option<EvalMove> = None;
+        for depth in 1..=max_depth {
+            let result =
+                Analyzer::minimax(board, turn, turn, depth, f64::NEG_INFINITY, f64::INFINITY);
+            if Analyzer::is_stopped() && best.is_some() {
+                break;
+            }
+
+            let best_move = result.legal.to_uci(board.chess960);
+            println!(
+                "info depth {} score {} nodes {} time {} pv {}",
+                depth,
+                Uci::get_score(result.eval),
+                Analyzer::get_searched_nodes(),
+                start.elapsed().as_millis(),
+                best_move
+            );
+            best = Some(result);
+
+            if Analyzer::is_stopped() {
+                break;
+            }
+        }
+
+        let best_move = match best {
+            Some(eval_move) if legal_moves.contains(&eval_move.legal) => eval_move.legal,
+            _ => legal_moves[0],
+        };
+        println!("bestmove {}", best_move.to_uci(board.chess960));
+    }
+
+    //Analyzer scores are in pawns from the side to move's point of view, MATE - n is a
+    //mate n plies away
+    pub fn get_score(eval: f64) -> String {
+        if eval.abs() > MATE - MAX_MATE_PLIES {
+            let moves = ((MATE - eval.abs()).round() as i64 + 1) / 2;
+            match eval > 0.0 {
+                true => return format!("mate {}", moves),
+                false => return format!("mate -{}", moves),
+            }
+        }
+        return format!("cp {}", Uci::score_to_cp(eval));
+    }
+
+    fn score_to_cp(eval: f64) -> i64 {
+        return (eval * 100.0).clamp(-MAX_SCORE_CP, MAX_SCORE_CP) as i64;
+    }
+}
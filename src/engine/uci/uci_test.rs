@@ -0,0 +1,214 @@
+#[cfg(test)]
+mod uci_tests {
+    use crate::engine::{
+        board::{
+            bitboard::bitboard::Bitboard,
+            board::{Board, Turn},
+            position::position::{CastleOptions, LegalMove, Position, PromotionOptions},
+        },
+        game::analyzer::analyzer::{Analyzer, MATE},
+        uci::uci::Uci,
+    };
+
+    #[test]
+    fn position_from_algebraic() {
+        let position = Position::from_algebraic("e2").unwrap();
+        assert_eq!(position.colum, 3);
+        assert_eq!(position.row, 1);
+        assert_eq!(position.to_algebraic(), "e2");
+
+        assert!(Position::from_algebraic("i2").is_none());
+        assert!(Position::from_algebraic("a9").is_none());
+        assert!(Position::from_algebraic("a").is_none());
+    }
+
+    #[test]
+    fn long_algebraic_pawn_push() {
+        let board = Board::new();
+        let legal_move = LegalMove::from_long_algebraic("e2e4", &board, Turn::White).unwrap();
+
+        assert!(legal_move.from == Position::from_algebraic("e2").unwrap());
+        assert!(legal_move.to == Position::from_algebraic("e4").unwrap());
+        assert_eq!(legal_move.to_long_algebraic(&board, Turn::White), "e2e4");
+    }
+
+    #[test]
+    fn long_algebraic_rejects_illegal_move() {
+        let board = Board::new();
+        assert!(LegalMove::from_long_algebraic("e2e5", &board, Turn::White).is_none());
+        assert!(LegalMove::from_long_algebraic("e7e5", &board, Turn::White).is_none());
+    }
+
+    #[test]
+    fn long_algebraic_castle() {
+        let board = Board::new_from_fenn_notation("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1");
+
+        let king_side = LegalMove::from_long_algebraic("e1g1", &board, Turn::White).unwrap();
+        assert!(king_side.castle == CastleOptions::KingSide);
+
+        let queen_side = LegalMove::from_long_algebraic("e8c8", &board, Turn::Black).unwrap();
+        assert!(queen_side.castle == CastleOptions::QueenSide);
+        assert_eq!(queen_side.to_long_algebraic(&board, Turn::Black), "e8c8");
+    }
+
+    #[test]
+    fn long_algebraic_promotion() {
+        let board = Board::new_from_fenn_notation("8/4P3/8/8/8/8/8/k6K w - - 0 1");
+
+        let promotion = LegalMove::from_long_algebraic("e7e8q", &board, Turn::White).unwrap();
+        assert_eq!(promotion.to_long_algebraic(&board, Turn::White), "e7e8q");
+    }
+
+    #[test]
+    fn long_algebraic_underpromotion() {
+        let board = Board::new_from_fenn_notation("8/4P3/8/8/8/8/8/k6K w - - 0 1");
+
+        let promotions: Vec<String> = board
+            .get_moves(Turn::White)
+            .iter()
+            .filter(|x| x.is_promotion())
+            .map(|x| x.to_long_algebraic())
+            .collect();
+        assert_eq!(promotions, vec!["e7e8q", "e7e8r", "e7e8b", "e7e8n"]);
+
+        let knight = LegalMove::from_long_algebraic("e7e8n", &board, Turn::White).unwrap();
+        assert!(knight.promotion == PromotionOptions::Knight);
+    }
+
+    #[test]
+    fn position_with_underpromotion() {
+        let mut uci = Uci::new();
+        let fen = "8/4P3/8/8/8/8/3p4/k6K w - - 0 1";
+        let args: Vec<&str> = ["fen"]
+            .into_iter()
+            .chain(fen.split_whitespace())
+            .chain(["moves", "e7e8n", "d2d1r"])
+            .collect();
+        assert!(uci.set_position(&args));
+
+        let board = uci.get_board();
+        assert_eq!(board.w_knights, Bitboard(0x800000000000000));
+        assert!(board.w_queen.is_empty());
+        assert!(board.w_pawns.is_empty());
+        assert_eq!(board.b_rooks, Bitboard(0x10));
+        assert!(board.b_queen.is_empty());
+        assert!(board.b_pawns.is_empty());
+    }
+
+    #[test]
+    fn position_startpos_with_moves() {
+        let mut uci = Uci::new();
+        assert!(uci.set_position(&["startpos", "moves", "e2e4", "e7e5", "g1f3"]));
+
+        let board = uci.get_board();
+        assert!(board.get_turn() == Turn::Black);
+        assert_eq!(board.w_knights, Bitboard(0x40040));
+        assert_eq!(board.b_pawns, Bitboard(0xf7000800000000));
+    }
+
+    #[test]
+    fn position_fen() {
+        let mut uci = Uci::new();
+        let fen = "4k3/8/8/8/8/8/8/4K2R b K - 0 1";
+        let args: Vec<&str> = ["fen"].into_iter().chain(fen.split_whitespace()).collect();
+        assert!(uci.set_position(&args));
+
+        let board = uci.get_board();
+        assert!(board.get_turn() == Turn::Black);
+        assert_eq!(board.w_rooks, Bitboard(0x1));
+    }
+
+    #[test]
+    fn position_rejects_illegal_moves() {
+        let mut uci = Uci::new();
+        assert!(!uci.set_position(&["startpos", "moves", "e2e5"]));
+    }
+
+    #[test]
+    fn position_rejects_invalid_setup() {
+        let mut uci = Uci::new();
+        //Black is in check with white to move
+        let fen = "4k3/8/8/8/8/8/8/4RK2 w - - 0 1";
+        let args: Vec<&str> = ["fen"].into_iter().chain(fen.split_whitespace()).collect();
+        assert!(!uci.set_position(&args));
+        assert!(uci.get_board() == Board::new());
+    }
+
+    #[test]
+    fn chess960_castles_as_king_takes_rook() {
+        let mut uci = Uci::new();
+        assert!(!uci.set_position(&["startpos", "moves", "e2e4", "e7e5", "e1h1"]));
+
+        uci.handle_command("setoption name UCI_Chess960 value true");
+        let fen = "1r4kr/8/8/8/8/8/8/1R4KR w HBhb - 0 1";
+        let args: Vec<&str> = ["fen"]
+            .into_iter()
+            .chain(fen.split_whitespace())
+            .chain(["moves", "g1b1"])
+            .collect();
+        assert!(uci.set_position(&args));
+
+        let board = uci.get_board();
+        assert!(board.chess960);
+        assert_eq!(
+            board.to_shredder_fen(),
+            "1r4kr/8/8/8/8/8/8/2KR3R b hb - 1 1"
+        );
+    }
+
+    #[test]
+    fn depth_is_clamped_before_narrowing() {
+        assert_eq!(Uci::clamp_depth(200), 64);
+        assert_eq!(Uci::clamp_depth(-5), 1);
+        assert_eq!(Uci::clamp_depth(6), 6);
+
+        let mut uci = Uci::new();
+        uci.handle_command("setoption name Depth value 300");
+        assert_eq!(uci.get_depth(), 64);
+    }
+
+    #[test]
+    fn mates_are_reported_in_moves() {
+        assert_eq!(Uci::get_score(1.5), "cp 150");
+        assert_eq!(Uci::get_score(-1000.0), "cp -30000");
+        assert_eq!(Uci::get_score(MATE - 1.0), "mate 1");
+        assert_eq!(Uci::get_score(MATE - 5.0), "mate 3");
+        assert_eq!(Uci::get_score(-(MATE - 2.0)), "mate -1");
+        assert_eq!(Uci::get_score(-(MATE - 4.0)), "mate -2");
+
+        let eval = |fen: &str, depth: i8| {
+            let board = Board::new_from_fenn_notation(fen);
+            let turn = board.get_turn();
+            Analyzer::reset_search();
+            return Analyzer::minimax(board, turn, turn, depth, f64::NEG_INFINITY, f64::INFINITY)
+                .eval;
+        };
+        //The count stays the same however deep the search goes
+        let mate_in_one = "k7/8/1K6/8/8/8/8/6Q1 w - - 0 1";
+        assert_eq!(Uci::get_score(eval(mate_in_one, 1)), "mate 1");
+        assert_eq!(Uci::get_score(eval(mate_in_one, 3)), "mate 1");
+        let mated_in_one = "k7/8/1K6/8/8/8/8/7Q b - - 0 1";
+        assert_eq!(Uci::get_score(eval(mated_in_one, 2)), "mate -1");
+        assert_eq!(Uci::get_score(eval(mated_in_one, 3)), "mate -1");
+    }
+
+    #[test]
+    fn go_is_refused_after_an_invalid_position() {
+        let mut uci = Uci::new();
+        assert!(uci.has_position());
+        uci.handle_command("position startpos moves e2e4");
+        assert!(uci.has_position());
+
+        uci.handle_command("position startpos moves e2e5");
+        assert!(!uci.has_position());
+        uci.handle_command("go depth 1");
+        uci.handle_command("position fen 8/8/8/8/8/8/8/8 w - - 0 1");
+        assert!(!uci.has_position());
+
+        uci.handle_command("position startpos");
+        assert!(uci.has_position());
+        uci.handle_command("position fen not a fen");
+        uci.handle_command("ucinewgame");
+        assert!(uci.has_position());
+    }
+}
@@ -1,14 +1,42 @@
-use engine::game::game::Game;
+use engine::{
+    game::{
+        game::{Game, PlayerTypes},
+        result::result::GameStatus,
+    },
+    pgn::pgn::PgnReader,
+    uci::uci::Uci,
+};
 
 use std::{
+    env,
+    fs::File,
+    io::BufReader,
     thread,
-    time::{self, Instant},
+    time::{self, Instant, SystemTime, UNIX_EPOCH},
 };
 
 mod engine;
 
 fn main() {
-    play_game();
+    match env::args().nth(1).as_deref() {
+        Some("uci") => Uci::new().run(),
+        Some("pgn") => match env::args().nth(2) {
+            Some(path) => read_pgn(&path),
+            None => println!("usage: chessbot pgn <file>"),
+        },
+        Some("960") => match get_960_seed(env::args().nth(2)) {
+            Some(seed) => play_game(Game::setup_960(seed, PlayerTypes::HUMAN, PlayerTypes::AI)),
+            None => println!("usage: chessbot 960 [seed]"),
+        },
+        _ => {
+            let fen_board = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+            play_game(Game::setup_from_fenn(
+                fen_board,
+                PlayerTypes::HUMAN,
+                PlayerTypes::AI,
+            ))
+        }
+    }
 
     //test()
 }
@@ -33,31 +61,63 @@ fn test() {
     println!("Extract {}mc", duration.as_micros());
 
     println!("{}", moves.len());
-
-    let start = Instant::now();
-
-    game.get_board_copy()
-        .legalize_moves(moves, engine::board::board::Turn::White);
-
-    let duration = start.elapsed();
-
-    println!("Legalize {}mc", duration.as_micros());
 }
 
-fn play_game() {
-    let fen_board = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+//Checks every game in the file and writes it back in export format, a summary of each
+//game and every error go to stderr
+fn read_pgn(path: &str) {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(error) => {
+            eprintln!("{}: {}", path, error);
+            return;
+        }
+    };
+    for game in PgnReader::new(BufReader::new(file)) {
+        match game {
+            Ok(game) => {
+                eprintln!(
+                    "{} - {}: {} plies, final position {}",
+                    game.get_tag("White").unwrap_or("?"),
+                    game.get_tag("Black").unwrap_or("?"),
+                    game.moves.len(),
+                    game.get_final_board().to_fen()
+                );
+                println!("{}", game.to_pgn());
+            }
+            Err(error) => eprintln!("{}:{}", path, error),
+        }
+    }
+}
 
-    let mut game = Game::setup_from_fenn(
-        fen_board,
-        engine::game::game::PlayerTypes::HUMAN,
-        engine::game::game::PlayerTypes::AI,
-    );
+//Without a seed every run starts from a different position
+fn get_960_seed(seed: Option<String>) -> Option<u64> {
+    match seed {
+        Some(seed) => return seed.parse().ok(),
+        None => {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default();
+            return Some(now.as_nanos() as u64);
+        }
+    }
+}
 
+fn play_game(mut game: Game) {
     loop {
         let start = Instant::now();
-        game.take_turn();
+        let status = game.take_turn();
         let duration = start.elapsed();
         //println!("{}milli", duration.as_millis());
+        match status {
+            GameStatus::Finished(result, reason) => {
+                game.get_board().print_board_self("Board");
+                println!("{} by {}", result, reason);
+                break;
+            }
+            GameStatus::DrawClaimable(reason) => println!("Draw by {} can be claimed", reason),
+            GameStatus::Ongoing => {}
+        }
         thread::sleep(time::Duration::from_millis(500));
     }
 }